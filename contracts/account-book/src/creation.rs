@@ -49,6 +49,17 @@ fn check_xudt_cell(cell_data: &AccountBookCellData) -> Result<(), Error> {
     Ok(())
 }
 
+// Other AccountBooks of the cluster trust it, only the auther creates it
+fn check_auther(cell_data: &AccountBookCellData) -> Result<(), Error> {
+    let auther_id: Hash = cell_data.auther_id().into();
+    if auther_id != load_cell_lock_hash(0, Source::Input)? {
+        log::error!("AccountBook creation must be authorized by the auther");
+        return Err(Error::CheckScript);
+    }
+
+    Ok(())
+}
+
 fn check_bounds() -> Result<(), Error> {
     let ret = load_cell_lock_hash(1, Source::Input);
     if ret != Err(SysError::IndexOutOfBound) {
@@ -87,6 +98,56 @@ fn check_cell_data(
         return Err(Error::AccountBook);
     }
 
+    let co_authors = cell_data.co_authors();
+    if !co_authors.is_empty() {
+        let mut total_weight = 0u64;
+        let mut lock_hashes: alloc::vec::Vec<Hash> = alloc::vec::Vec::new();
+        for co_author in co_authors.into_iter() {
+            let weight: u8 = co_author.weight().into();
+            total_weight += weight as u64;
+            let lock_hash: Hash = co_author.lock_script_hash().into();
+            if lock_hashes.contains(&lock_hash) {
+                log::error!("Duplicate co-author in the account book");
+                return Err(Error::AccountBook);
+            }
+            lock_hashes.push(lock_hash);
+        }
+        if total_weight == 0 {
+            log::error!("The total weight of co-authors is 0");
+            return Err(Error::AccountBook);
+        }
+    }
+
+    if !cell_data.retired_withdrawn_udt().is_empty() {
+        log::error!("Initially, retired_withdrawn_udt must be empty");
+        return Err(Error::AccountBook);
+    }
+    if !cell_data.minted_withdrawn_udt().is_empty() {
+        log::error!("Initially, minted_withdrawn_udt must be empty");
+        return Err(Error::AccountBook);
+    }
+
+    if let Some(level_rule) = cell_data.level_rule().to_opt() {
+        let ranges = level_rule.ranges();
+        if ranges.is_empty() {
+            log::error!("The level_rule has no ranges");
+            return Err(Error::AccountBook);
+        }
+        for range in ranges.into_iter() {
+            let (min, max): (u8, u8) = (range.min().into(), range.max().into());
+            if min > max {
+                log::error!("Level range is incorrect: [{}, {}]", min, max);
+                return Err(Error::AccountBook);
+            }
+        }
+    }
+
+    let fee_ratio: u8 = cell_data.withdrawal_fee_ratio().into();
+    if fee_ratio > 100 {
+        log::error!("withdrawal_fee_ratio({}) is greater than 100", fee_ratio);
+        return Err(Error::AccountBook);
+    }
+
     // Check SMT
     let smt_root_hash: Hash = cell_data.smt_root_hash().into();
     let proof = AccountBookProof::new(witness_data.proof().raw_data().to_vec());
@@ -94,7 +155,7 @@ fn check_cell_data(
         log::error!("smt_root_hash is not default value");
         return Err(Error::AccountBook);
     }
-    let ret = proof.verify(smt_root_hash, 0, 0, (SmtKey::Auther, None))?;
+    let ret = proof.verify(smt_root_hash, 0, 0, 0, (SmtKey::Auther, None))?;
     if !ret {
         log::error!("Verify smt failed");
         return Err(Error::AccountBook);
@@ -113,6 +174,7 @@ pub fn creation(witness_data: AccountBookData) -> Result<(), Error> {
     // account book
     // change (if needed)
     check_bounds()?;
+    check_auther(&cell_data)?;
     check_xudt_cell(&cell_data)?;
     check_cell_data(&witness_data, &cell_data)?;
     Ok(())
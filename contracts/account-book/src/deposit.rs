@@ -0,0 +1,66 @@
+use super::Error;
+use ckb_std::{ckb_types::prelude::Unpack, log};
+use types::{AccountBookCellData, AccountBookData};
+use utils::{checked_add, AccountBookProof, Hash, UDTInfo};
+
+// External income (not from Spore sales), shared through the profit distribution ratios
+pub fn deposit(
+    witness_data: AccountBookData,
+    cell_data: AccountBookCellData,
+    old_smt_hash: Hash,
+) -> Result<(), Error> {
+    let deposited: u128 = witness_data
+        .deposited_udt()
+        .to_opt()
+        .ok_or_else(|| {
+            log::error!("The deposited_udt is None");
+            Error::AccountBook
+        })?
+        .unpack();
+    if deposited == 0 {
+        log::error!("Deposit amount cannot be 0");
+        return Err(Error::CheckXUDT);
+    }
+
+    let udt_info = UDTInfo::new(cell_data.xudt_script_hash().into())?;
+    let (old_amount, new_amount) = super::check_input_type_proxy_lock(&cell_data, &udt_info)?;
+    if checked_add(old_amount, deposited)? != new_amount {
+        log::error!(
+            "In and Out Error: input: {}, output: {}, deposited: {}",
+            old_amount,
+            new_amount,
+            deposited
+        );
+        return Err(Error::CheckXUDT);
+    }
+
+    let old_total_income: u128 = witness_data.total_income_udt().unpack();
+    let new_total_income = checked_add(old_total_income, deposited)?;
+    let total_withdrawn: u128 = witness_data.total_withdrawn_udt().unpack();
+
+    let proof = AccountBookProof::new(witness_data.proof().unpack());
+    if !proof.verify_old_leaves(
+        old_smt_hash,
+        old_total_income,
+        old_amount,
+        total_withdrawn,
+        &[],
+    )? {
+        log::error!("Verify Input SMT failed");
+        return Err(Error::Smt);
+    }
+
+    let new_smt_hash: Hash = cell_data.smt_root_hash().into();
+    if !proof.verify_leaves(
+        new_smt_hash,
+        new_total_income,
+        new_amount,
+        total_withdrawn,
+        &[],
+    )? {
+        log::error!("Verify Output SMT failed");
+        return Err(Error::Smt);
+    }
+
+    Ok(())
+}
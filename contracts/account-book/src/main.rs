@@ -18,10 +18,14 @@ use ckb_std::{
     log,
 };
 pub use types::error::SilentBerryError as Error;
-use types::AccountBookCellData;
+use types::{AccountBookCellData, AccountBookData, Uint128Vec};
 use utils::{get_indexs, load_lock_code_hash, load_type_code_hash, Hash, UDTInfo};
 
 mod creation;
+mod deposit;
+mod merge;
+mod register;
+mod retire;
 mod selling;
 mod withdrawal;
 
@@ -51,19 +55,41 @@ fn the_only(source: Source) -> Result<(), Error> {
     }
 }
 
-fn verify_cell_data(old: &AccountBookCellData, new: &AccountBookCellData) -> Result<(), Error> {
-    let old: AccountBookCellData = old
+fn verify_cell_data(
+    old: &AccountBookCellData,
+    new: &AccountBookCellData,
+    transition: &Transition,
+) -> Result<(), Error> {
+    let mut old = old
         .clone()
         .as_builder()
         .smt_root_hash(Default::default())
-        .buyer_count(0u32.pack())
-        .build();
-    let new = new
+        .buyer_count(0u32.pack());
+    let mut new = new
         .clone()
         .as_builder()
         .smt_root_hash(Default::default())
-        .buyer_count(0u32.pack())
-        .build();
+        .buyer_count(0u32.pack());
+    if matches!(
+        transition,
+        Transition::Retire | Transition::Merge | Transition::Upgrade | Transition::Register
+    ) {
+        // Checked in retire, merge, upgrade and register
+        old = old
+            .profit_distribution_number(Default::default())
+            .retired_withdrawn_udt(Default::default())
+            .minted_withdrawn_udt(Default::default());
+        new = new
+            .profit_distribution_number(Default::default())
+            .retired_withdrawn_udt(Default::default())
+            .minted_withdrawn_udt(Default::default());
+    }
+    if matches!(transition, Transition::ReserveMint) {
+        // Checked in reserve_mint
+        old = old.reserved_count(Default::default());
+        new = new.reserved_count(Default::default());
+    }
+    let (old, new) = (old.build(), new.build());
 
     if old.as_slice() != new.as_slice() {
         log::error!("Modification of CellData is not allowed");
@@ -72,15 +98,30 @@ fn verify_cell_data(old: &AccountBookCellData, new: &AccountBookCellData) -> Res
 
     Ok(())
 }
-fn load_verified_cell_data() -> Result<(AccountBookCellData, Hash, bool), Error> {
+enum Transition {
+    Selling,
+    Withdrawal,
+    Sweep,
+    Retire,
+    Merge,
+    Upgrade,
+    Deposit,
+    ReserveMint,
+    Register,
+}
+
+fn load_verified_cell_data(
+    witness_data: &AccountBookData,
+) -> Result<(AccountBookCellData, Hash, Transition), Error> {
     let old_data = utils::load_account_bool_cell_data(0, Source::GroupInput)?;
     let new_data = utils::load_account_bool_cell_data(0, Source::GroupOutput)?;
-    verify_cell_data(&old_data, &new_data)?;
+    let transition = get_transition(&new_data, witness_data)?;
+    verify_cell_data(&old_data, &new_data, &transition)?;
 
     let old_buyer_count: u32 = old_data.buyer_count().unpack();
     let new_buyer_count: u32 = new_data.buyer_count().unpack();
-    let is_selling = is_selling(&new_data)?;
-    if is_selling && old_buyer_count + 1 != new_buyer_count {
+    let is_selling = matches!(transition, Transition::Selling | Transition::ReserveMint);
+    if is_selling && old_buyer_count.checked_add(1) != Some(new_buyer_count) {
         log::error!(
             "CellData buyer count incorrect: {}, {}, is_selling: {}",
             old_buyer_count,
@@ -89,14 +130,17 @@ fn load_verified_cell_data() -> Result<(AccountBookCellData, Hash, bool), Error>
         );
         return Err(Error::AccountBookModified);
     } else if !is_selling && old_buyer_count != new_buyer_count {
-        log::error!("Withdrawal does not allow update buyer_count");
+        log::error!("Only selling is allowed to update buyer_count");
         return Err(Error::AccountBookModified);
     }
 
-    Ok((new_data, old_data.smt_root_hash().into(), is_selling))
+    Ok((new_data, old_data.smt_root_hash().into(), transition))
 }
 
-fn is_selling(new_cell_data: &AccountBookCellData) -> Result<bool, Error> {
+fn get_transition(
+    new_cell_data: &AccountBookCellData,
+    witness_data: &AccountBookData,
+) -> Result<Transition, Error> {
     let dob_selling_code_hash: Hash = new_cell_data.dob_selling_code_hash().into();
     if !get_indexs(
         load_lock_code_hash,
@@ -105,22 +149,44 @@ fn is_selling(new_cell_data: &AccountBookCellData) -> Result<bool, Error> {
     )
     .is_empty()
     {
-        Ok(true)
-    } else {
-        let withdrawal_code_hash: Hash = new_cell_data.withdrawal_intent_code_hash().into();
-        if !get_indexs(
-            load_type_code_hash,
-            |h| withdrawal_code_hash == h,
-            Source::Input,
-        )
-        .is_empty()
-        {
-            Ok(false)
-        } else {
-            log::error!("WithdrawalIntent Script not found in Inputs");
-            Err(Error::CheckScript)
-        }
+        return Ok(Transition::Selling);
+    }
+
+    let withdrawal_code_hash: Hash = new_cell_data.withdrawal_intent_code_hash().into();
+    if !get_indexs(
+        load_type_code_hash,
+        |h| withdrawal_code_hash == h,
+        Source::Input,
+    )
+    .is_empty()
+    {
+        return Ok(Transition::Withdrawal);
+    }
+
+    if witness_data.swept_spore().is_some() {
+        return Ok(Transition::Sweep);
+    }
+    if witness_data.retired_spore().is_some() {
+        return Ok(Transition::Retire);
+    }
+    if witness_data.merged_spores().is_some() {
+        return Ok(Transition::Merge);
+    }
+    if witness_data.upgraded_spore().is_some() {
+        return Ok(Transition::Upgrade);
     }
+    if witness_data.deposited_udt().is_some() {
+        return Ok(Transition::Deposit);
+    }
+    if witness_data.reserved_spore().is_some() {
+        return Ok(Transition::ReserveMint);
+    }
+    if witness_data.registered_spore().is_some() {
+        return Ok(Transition::Register);
+    }
+
+    log::error!("Unknown AccountBook transition: no DOBSelling or WithdrawalIntent in Inputs");
+    Err(Error::CheckScript)
 }
 fn check_input_type_proxy_lock(
     cell_data: &AccountBookCellData,
@@ -221,6 +287,41 @@ fn get_ratios(cell_data: &AccountBookCellData, level: u8) -> Result<Vec<u8>, Err
     Ok(ratios)
 }
 
+fn get_retired_withdrawn(cell_data: &AccountBookCellData, spore_level: u8) -> Result<u128, Error> {
+    get_level_udt(cell_data, &cell_data.retired_withdrawn_udt(), spore_level)
+}
+
+fn get_minted_withdrawn(cell_data: &AccountBookCellData, spore_level: u8) -> Result<u128, Error> {
+    get_level_udt(cell_data, &cell_data.minted_withdrawn_udt(), spore_level)
+}
+
+fn get_level_udt(
+    cell_data: &AccountBookCellData,
+    udts: &Uint128Vec,
+    spore_level: u8,
+) -> Result<u128, Error> {
+    if udts.is_empty() {
+        return Ok(0);
+    }
+
+    let level: u8 = cell_data.level().into();
+    if udts.len() != level as usize {
+        log::error!(
+            "The per level udt in the account book is wrong, it needs: {}, actual: {}",
+            level,
+            udts.len()
+        );
+        return Err(Error::AccountBook);
+    }
+    Ok(udts
+        .get(spore_level as usize)
+        .ok_or_else(|| {
+            log::error!("Spore level({}) out of range", spore_level);
+            Error::Spore
+        })?
+        .unpack())
+}
+
 fn program_entry2() -> Result<(), Error> {
     ckb_std::type_id::check_type_id(0)?;
     let witness_data = utils::load_account_book_data(0, Source::GroupOutput)?;
@@ -231,11 +332,17 @@ fn program_entry2() -> Result<(), Error> {
         the_only(Source::GroupInput)?;
         the_only(Source::GroupOutput)?;
 
-        let (cell_data, old_smt_hash, is_selling) = load_verified_cell_data()?;
-        if is_selling {
-            selling::selling(witness_data, cell_data, old_smt_hash)
-        } else {
-            withdrawal::withdrawal(witness_data, cell_data, old_smt_hash)
+        let (cell_data, old_smt_hash, transition) = load_verified_cell_data(&witness_data)?;
+        match transition {
+            Transition::Selling => selling::selling(witness_data, cell_data, old_smt_hash),
+            Transition::Withdrawal => withdrawal::withdrawal(witness_data, cell_data, old_smt_hash),
+            Transition::Sweep => withdrawal::sweep(witness_data, cell_data, old_smt_hash),
+            Transition::Retire => retire::retire(witness_data, cell_data, old_smt_hash),
+            Transition::Merge => merge::merge(witness_data, cell_data, old_smt_hash),
+            Transition::Upgrade => merge::upgrade(witness_data, cell_data, old_smt_hash),
+            Transition::Deposit => deposit::deposit(witness_data, cell_data, old_smt_hash),
+            Transition::ReserveMint => selling::reserve_mint(witness_data, cell_data, old_smt_hash),
+            Transition::Register => register::register(witness_data, cell_data, old_smt_hash),
         }
     }
 }
@@ -0,0 +1,298 @@
+use super::Error;
+use alloc::vec::Vec;
+use ckb_std::{ckb_constants::Source, ckb_types::prelude::Unpack, log};
+use types::{AccountBookCellData, AccountBookData, MergeSporeInfo};
+use utils::{checked_add, AccountBookProof, Hash, SmtKey, UDTInfo};
+
+// What each Spore of the level can withdraw so far, nothing if the level is empty
+pub fn get_minted_credited(
+    old_cell_data: &AccountBookCellData,
+    ratios: &[u8],
+    minted_level: u8,
+    total_income: u128,
+) -> Result<u128, Error> {
+    let nums = old_cell_data.profit_distribution_number().raw_data();
+    if nums.get(minted_level as usize).is_some_and(|num| *num != 0) {
+        crate::withdrawal::get_spore_total_withdrawn(
+            old_cell_data,
+            ratios,
+            minted_level,
+            total_income,
+        )
+    } else {
+        Ok(0)
+    }
+}
+
+// Burn Spores of level N and mint one of level N-1, paying `price` into the AccountBook
+fn reforge(
+    witness_data: &AccountBookData,
+    cell_data: &AccountBookCellData,
+    old_smt_hash: Hash,
+    merge_info: MergeSporeInfo,
+    merge_count: u8,
+    price: u128,
+) -> Result<(), Error> {
+    let burned_spores = merge_info.burned_spores();
+    let burned_withdrawn: Vec<Option<u128>> = merge_info
+        .burned_withdrawn_udt()
+        .into_iter()
+        .map(|v| v.to_opt().map(|v| v.unpack()))
+        .collect();
+    if burned_spores.len() != merge_count as usize || burned_withdrawn.len() != burned_spores.len()
+    {
+        log::error!(
+            "{} Spores need to be merged, burned: {}, withdrawn: {}",
+            merge_count,
+            burned_spores.len(),
+            burned_withdrawn.len()
+        );
+        return Err(Error::TxStructure);
+    }
+
+    // Check Spores
+    let minted_spore = merge_info.minted_spore();
+    let minted_level: u8 = minted_spore.spore_level().into();
+    let burned_level = minted_level.checked_add(1).ok_or(Error::Spore)?;
+    let account_book_level: u8 = cell_data.level().into();
+    if account_book_level <= burned_level {
+        log::error!(
+            "This Spore({}) is not eligible for profit sharing",
+            burned_level
+        );
+        return Err(Error::Spore);
+    }
+    let mut burned_ids: Vec<Hash> = Vec::new();
+    for (spore_info, withdrawn) in burned_spores.clone().into_iter().zip(&burned_withdrawn) {
+        let spore_level: u8 = spore_info.spore_level().into();
+        if spore_level != burned_level {
+            log::error!(
+                "Spores of level {} are merged into level {}, got: {}",
+                burned_level,
+                minted_level,
+                spore_level
+            );
+            return Err(Error::Spore);
+        }
+        let spore_code_hash: Hash = spore_info.spore_code_hash().into();
+        if spore_code_hash != minted_spore.spore_code_hash() {
+            log::error!("The burned and minted Spores have different code hash");
+            return Err(Error::Spore);
+        }
+        let spore_id: Hash = spore_info.spore_id().into();
+        if burned_ids.contains(&spore_id) {
+            log::error!("Duplicate burned Spore");
+            return Err(Error::Spore);
+        }
+
+        crate::withdrawal::check_spore(cell_data, &spore_info, Source::Input)?;
+        crate::retire::check_burned(&spore_info)?;
+        if withdrawn.is_none() {
+            crate::register::check_origin(witness_data, cell_data, &spore_info)?;
+        }
+        burned_ids.push(spore_id);
+    }
+    let minted_spore_data =
+        crate::withdrawal::check_spore(cell_data, &minted_spore, Source::Output)?;
+    crate::withdrawal::check_new_spore(&minted_spore.spore_id().into())?;
+    let dna_hash = utils::get_spore_dna_hash(&minted_spore_data)?;
+    crate::selling::check_approved_dna(witness_data, cell_data, &dna_hash)?;
+
+    // Settle the pending earnings of the burned Spores
+    let old_cell_data = utils::load_account_bool_cell_data(0, Source::GroupInput)?;
+    let ratios = crate::get_ratios(cell_data, account_book_level)?;
+    let (old_total_income, new_total_income): (u128, u128) = {
+        let total: u128 = witness_data.total_income_udt().unpack();
+        (total, checked_add(total, price)?)
+    };
+    let total_withdrawn = crate::withdrawal::get_spore_total_withdrawn(
+        &old_cell_data,
+        &ratios,
+        burned_level,
+        old_total_income,
+    )?;
+    let mut settled_udt = 0u128;
+    let mut retired_udt = 0u128;
+    for withdrawn in &burned_withdrawn {
+        if *withdrawn == Some(utils::SMT_VALUE_RETIRED) {
+            log::error!("This Spore has been retired");
+            return Err(Error::Spore);
+        }
+        let withdrawn = withdrawn.unwrap_or(0);
+        settled_udt = checked_add(settled_udt, total_withdrawn.saturating_sub(withdrawn))?;
+        retired_udt = checked_add(retired_udt, total_withdrawn.max(withdrawn))?;
+    }
+    let minted_credited =
+        get_minted_credited(&old_cell_data, &ratios, minted_level, new_total_income)?;
+    crate::retire::check_distribution(
+        &old_cell_data,
+        cell_data,
+        (burned_level, merge_count, retired_udt),
+        Some((minted_level, minted_credited)),
+    )?;
+
+    let udt_info = UDTInfo::new(cell_data.xudt_script_hash().into())?;
+    let (old_total_udt, new_total_udt) = super::check_input_type_proxy_lock(cell_data, &udt_info)?;
+    let output_udt = if settled_udt != 0 {
+        crate::withdrawal::get_output_udt_by_lock(
+            &udt_info,
+            merge_info.xudt_lock_script_hash().into(),
+        )?
+    } else {
+        0
+    };
+    if checked_add(old_total_udt, price)? != checked_add(new_total_udt, settled_udt)?
+        || output_udt != settled_udt
+    {
+        log::error!(
+            "Error in calculation of merge: total udt: old({}) new({}), price: {}, settled: {}, output: {}",
+            old_total_udt,
+            new_total_udt,
+            price,
+            settled_udt,
+            output_udt
+        );
+        return Err(Error::AccountBook);
+    }
+
+    let old_total_withdrawn: u128 = witness_data.total_withdrawn_udt().unpack();
+    let new_total_withdrawn = checked_add(old_total_withdrawn, settled_udt)?;
+
+    // The minted Spore only shares the income from now on
+    let minted_withdrawn = crate::withdrawal::get_spore_total_withdrawn(
+        cell_data,
+        &ratios,
+        minted_level,
+        new_total_income,
+    )?;
+
+    // SMT, other AccountBooks register the minted Spore by its Minted leaf,
+    // and retire the burned Spores by their Burned leaves.
+    // The DNA of the minted Spore is taken like a sold one
+    let minted_id: Hash = minted_spore.spore_id().into();
+    let minted_key = SmtKey::Buyer(minted_id.clone());
+    let mut old_leaves: Vec<(SmtKey, Option<u128>)> = burned_ids
+        .iter()
+        .zip(&burned_withdrawn)
+        .map(|(id, withdrawn)| (SmtKey::Buyer(id.clone()), *withdrawn))
+        .collect();
+    old_leaves.push((minted_key.clone(), None));
+    old_leaves.push((SmtKey::Minted(minted_id.clone()), None));
+    old_leaves.push((SmtKey::Dna(dna_hash.clone()), None));
+    old_leaves.extend(
+        burned_ids
+            .iter()
+            .map(|id| (SmtKey::Burned(id.clone()), None)),
+    );
+    let mut new_leaves: Vec<(SmtKey, Option<u128>)> = burned_ids
+        .iter()
+        .map(|id| (SmtKey::Buyer(id.clone()), Some(utils::SMT_VALUE_RETIRED)))
+        .collect();
+    new_leaves.extend(
+        burned_ids
+            .into_iter()
+            .map(|id| (SmtKey::Burned(id), Some(burned_level as u128))),
+    );
+    new_leaves.push((minted_key, Some(minted_withdrawn)));
+    new_leaves.push((SmtKey::Minted(minted_id), Some(minted_level as u128)));
+    new_leaves.push((SmtKey::Dna(dna_hash), Some(0)));
+
+    let proof = AccountBookProof::new(witness_data.proof().unpack());
+    if !proof.verify_old_leaves(
+        old_smt_hash,
+        old_total_income,
+        old_total_udt,
+        old_total_withdrawn,
+        &old_leaves,
+    )? {
+        log::error!("Verify old SMT failed, the DNA of the minted Spore may have been sold");
+        return Err(Error::Smt);
+    }
+
+    let new_smt_hash = cell_data.smt_root_hash().into();
+    if !proof.verify_leaves(
+        new_smt_hash,
+        new_total_income,
+        new_total_udt,
+        new_total_withdrawn,
+        &new_leaves,
+    )? {
+        log::error!("Verify new SMT failed");
+        return Err(Error::Smt);
+    }
+
+    Ok(())
+}
+
+pub fn merge(
+    witness_data: AccountBookData,
+    cell_data: AccountBookCellData,
+    old_smt_hash: Hash,
+) -> Result<(), Error> {
+    let merge_info = witness_data.merged_spores().to_opt().ok_or_else(|| {
+        log::error!("Merged Spores not found in witness");
+        Error::ParseWitness
+    })?;
+
+    let merge_count: u8 = cell_data.merge_spore_count().into();
+    if merge_count == 0 {
+        log::error!("Merging Spores is disabled in this AccountBook");
+        return Err(Error::AccountBook);
+    }
+
+    reforge(
+        &witness_data,
+        &cell_data,
+        old_smt_hash,
+        merge_info,
+        merge_count,
+        0,
+    )
+}
+
+pub fn upgrade(
+    witness_data: AccountBookData,
+    cell_data: AccountBookCellData,
+    old_smt_hash: Hash,
+) -> Result<(), Error> {
+    let upgrade_info = witness_data.upgraded_spore().to_opt().ok_or_else(|| {
+        log::error!("Upgraded Spore not found in witness");
+        Error::ParseWitness
+    })?;
+
+    let level: u8 = cell_data.level().into();
+    let prices: Vec<u128> = cell_data
+        .level_prices()
+        .into_iter()
+        .map(|v| v.unpack())
+        .collect();
+    if prices.len() != level as usize {
+        log::error!(
+            "Upgrading Spores is disabled in this AccountBook, level_prices: {:?}",
+            prices
+        );
+        return Err(Error::AccountBook);
+    }
+
+    // Pay the difference
+    let minted_level: u8 = upgrade_info.minted_spore().spore_level().into();
+    let price = match (
+        prices.get(minted_level as usize),
+        prices.get(minted_level as usize + 1),
+    ) {
+        (Some(new_price), Some(old_price)) if new_price > old_price => new_price - old_price,
+        _ => {
+            log::error!("Spore cannot be upgraded to level {}", minted_level);
+            return Err(Error::Spore);
+        }
+    };
+
+    reforge(
+        &witness_data,
+        &cell_data,
+        old_smt_hash,
+        upgrade_info,
+        1,
+        price,
+    )
+}
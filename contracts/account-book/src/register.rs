@@ -0,0 +1,218 @@
+use super::Error;
+use ckb_std::{
+    ckb_constants::Source,
+    ckb_types::prelude::{Entity, Unpack},
+    high_level::{load_cell_type, load_cell_type_hash, load_script, load_script_hash, QueryIter},
+    log,
+};
+use types::{AccountBookCellData, AccountBookData, WithdrawalSporeInfo};
+use utils::{AccountBookProof, Hash, SmtKey};
+
+// Another AccountBook of the cluster, created by the same auther with the same level rule
+fn load_sibling(
+    cell_data: &AccountBookCellData,
+    script_hash: &Hash,
+) -> Result<AccountBookCellData, Error> {
+    let self_script_hash: Hash = load_script_hash()?.into();
+    if *script_hash == self_script_hash {
+        log::error!("The sibling AccountBook cannot be itself");
+        return Err(Error::AccountBook);
+    }
+
+    let index = QueryIter::new(load_cell_type_hash, Source::CellDep)
+        .position(|hash| hash.is_some_and(|h| *script_hash == h))
+        .ok_or_else(|| {
+            log::error!("Sibling AccountBook not found in CellDeps");
+            Error::TxStructure
+        })?;
+    let self_script = load_script()?;
+    let script = load_cell_type(index, Source::CellDep)?.ok_or_else(|| {
+        log::error!("Unknow Error: load cell type (CellDep)");
+        Error::Unknow
+    })?;
+    if script.code_hash().as_slice() != self_script.code_hash().as_slice()
+        || script.hash_type().as_slice() != self_script.hash_type().as_slice()
+    {
+        log::error!("The sibling is not an AccountBook");
+        return Err(Error::AccountBook);
+    }
+
+    let sibling = utils::load_account_bool_cell_data(index, Source::CellDep)?;
+    if sibling.cluster_id().as_slice() != cell_data.cluster_id().as_slice()
+        || sibling.auther_id().as_slice() != cell_data.auther_id().as_slice()
+        || sibling.level_rule().as_slice() != cell_data.level_rule().as_slice()
+    {
+        log::error!("The sibling AccountBook belongs to another cluster or auther");
+        return Err(Error::AccountBook);
+    }
+
+    Ok(sibling)
+}
+
+// Any sibling (of `level` if given) in the witness holds these leaves,
+// proofs of other leaves are skipped
+fn verify_sibling_leaves(
+    witness_data: &AccountBookData,
+    cell_data: &AccountBookCellData,
+    level: Option<u8>,
+    leaves: &[(SmtKey, Option<u128>)],
+) -> Result<bool, Error> {
+    for sibling_proof in witness_data.sibling_proofs().into_iter() {
+        let sibling = load_sibling(cell_data, &sibling_proof.account_book_script_hash().into())?;
+        if level.is_some_and(|l| l != u8::from(sibling.level())) {
+            continue;
+        }
+
+        let proof = AccountBookProof::new(sibling_proof.proof().unpack());
+        if matches!(
+            proof.verify_sibling(sibling.smt_root_hash().into(), leaves),
+            Ok(true)
+        ) {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
+// The profit_distribution_number only counts Spores sold (or reserved) by the AccountBook of
+// their level, Spores minted by merging or upgrading elsewhere are counted once registered.
+// So a Spore without a leaf here has to prove it was sold.
+pub fn check_origin(
+    witness_data: &AccountBookData,
+    cell_data: &AccountBookCellData,
+    spore_info: &WithdrawalSporeInfo,
+) -> Result<(), Error> {
+    let spore_level: u8 = spore_info.spore_level().into();
+    if !verify_sibling_leaves(
+        witness_data,
+        cell_data,
+        Some(spore_level),
+        &[(SmtKey::Buyer(spore_info.spore_id().into()), Some(0))],
+    )? {
+        log::error!(
+            "The Spore was not sold by the AccountBook of level {}",
+            spore_level
+        );
+        return Err(Error::Spore);
+    }
+
+    Ok(())
+}
+
+// The Spore was burned in another AccountBook of the cluster, it can no longer be in this transaction
+pub fn check_burned_elsewhere(
+    witness_data: &AccountBookData,
+    cell_data: &AccountBookCellData,
+    spore_info: &WithdrawalSporeInfo,
+) -> Result<(), Error> {
+    let spore_level: u8 = spore_info.spore_level().into();
+    if !verify_sibling_leaves(
+        witness_data,
+        cell_data,
+        None,
+        &[(
+            SmtKey::Burned(spore_info.spore_id().into()),
+            Some(spore_level as u128),
+        )],
+    )? {
+        log::error!("The Spore was not burned by another AccountBook of the cluster");
+        return Err(Error::Spore);
+    }
+
+    Ok(())
+}
+
+// A Spore minted by another AccountBook of the cluster joins this one,
+// credited like a Spore minted here so it only shares the income from now on
+pub fn register(
+    witness_data: AccountBookData,
+    cell_data: AccountBookCellData,
+    old_smt_hash: Hash,
+) -> Result<(), Error> {
+    let spore_info = witness_data.registered_spore().to_opt().ok_or_else(|| {
+        log::error!("Registered Spore not found in witness");
+        Error::ParseWitness
+    })?;
+    let spore_level: u8 = spore_info.spore_level().into();
+    let account_book_level: u8 = cell_data.level().into();
+    if account_book_level <= spore_level {
+        log::error!(
+            "This Spore({}) is not eligible for profit sharing",
+            spore_level
+        );
+        return Err(Error::Spore);
+    }
+    crate::withdrawal::check_spore(&cell_data, &spore_info, Source::CellDep)?;
+
+    let spore_id: Hash = spore_info.spore_id().into();
+    if !verify_sibling_leaves(
+        &witness_data,
+        &cell_data,
+        None,
+        &[(SmtKey::Minted(spore_id.clone()), Some(spore_level as u128))],
+    )? {
+        log::error!("The Spore was not minted by another AccountBook of the cluster");
+        return Err(Error::Spore);
+    }
+
+    // Nothing is burned
+    let old_cell_data = utils::load_account_bool_cell_data(0, Source::GroupInput)?;
+    let ratios = crate::get_ratios(&cell_data, account_book_level)?;
+    let total_income: u128 = witness_data.total_income_udt().unpack();
+    let credited =
+        crate::merge::get_minted_credited(&old_cell_data, &ratios, spore_level, total_income)?;
+    crate::retire::check_distribution(
+        &old_cell_data,
+        &cell_data,
+        (spore_level, 0, 0),
+        Some((spore_level, credited)),
+    )?;
+    let withdrawn = crate::withdrawal::get_spore_total_withdrawn(
+        &cell_data,
+        &ratios,
+        spore_level,
+        total_income,
+    )?;
+
+    // The treasury does not change
+    let udt_info = utils::UDTInfo::new(cell_data.xudt_script_hash().into())?;
+    let (old_total_udt, new_total_udt) = super::check_input_type_proxy_lock(&cell_data, &udt_info)?;
+    if old_total_udt != new_total_udt {
+        log::error!(
+            "Register does not allow update udt: old({}), new({})",
+            old_total_udt,
+            new_total_udt
+        );
+        return Err(Error::CheckXUDT);
+    }
+
+    // SMT
+    let total_withdrawn: u128 = witness_data.total_withdrawn_udt().unpack();
+    let smt_key = SmtKey::Buyer(spore_id);
+    let proof = AccountBookProof::new(witness_data.proof().unpack());
+    if !proof.verify_old(
+        old_smt_hash,
+        total_income,
+        old_total_udt,
+        total_withdrawn,
+        (smt_key.clone(), None),
+    )? {
+        log::error!("Verify old SMT failed, the Spore may have been registered");
+        return Err(Error::Smt);
+    }
+
+    let new_smt_hash = cell_data.smt_root_hash().into();
+    if !proof.verify(
+        new_smt_hash,
+        total_income,
+        new_total_udt,
+        total_withdrawn,
+        (smt_key, Some(withdrawn)),
+    )? {
+        log::error!("Verify new SMT failed");
+        return Err(Error::Smt);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,202 @@
+use super::Error;
+use ckb_std::{
+    ckb_constants::Source,
+    ckb_types::prelude::{Builder, Entity, Pack, Unpack},
+    high_level::{load_cell_type, QueryIter},
+    log,
+};
+use types::{AccountBookCellData, AccountBookData, Uint128Vec, WithdrawalSporeInfo};
+use utils::{AccountBookProof, Hash, SmtKey};
+
+pub fn check_burned(spore_info: &WithdrawalSporeInfo) -> Result<(), Error> {
+    let spore_code_hash: Hash = spore_info.spore_code_hash().into();
+    let spore_id: Hash = spore_info.spore_id().into();
+    if QueryIter::new(load_cell_type, Source::Output).any(|script| {
+        script.is_some_and(|s| {
+            spore_code_hash == s.code_hash() && spore_id == s.args().raw_data().as_ref()
+        })
+    }) {
+        log::error!("The retired Spore must be burned");
+        return Err(Error::Spore);
+    }
+
+    Ok(())
+}
+
+// Burned Spores leave their level, a minted Spore (if any) joins another one.
+// The minted Spore is credited as withdrawn what each Spore of its level can withdraw so far,
+// so it only shares the income from now on.
+pub fn check_distribution(
+    old_cell_data: &AccountBookCellData,
+    new_cell_data: &AccountBookCellData,
+    burned: (u8, u8, u128),
+    minted: Option<(u8, u128)>,
+) -> Result<(), Error> {
+    let level: u8 = new_cell_data.level().into();
+    let (spore_level, count, withdrawn) = burned;
+
+    let mut nums = old_cell_data
+        .profit_distribution_number()
+        .raw_data()
+        .to_vec();
+    if nums.len() != level as usize || nums[spore_level as usize] < count {
+        log::error!(
+            "The profit_distribution_num in the account book is wrong: {:?}",
+            nums
+        );
+        return Err(Error::AccountBook);
+    }
+    nums[spore_level as usize] -= count;
+    if let Some((minted_level, _)) = minted {
+        let num = &mut nums[minted_level as usize];
+        *num = num.checked_add(1).ok_or_else(|| {
+            log::error!("Too many Spores in level {}", minted_level);
+            Error::AccountBookOverflow
+        })?;
+    }
+    if new_cell_data
+        .profit_distribution_number()
+        .raw_data()
+        .as_ref()
+        != nums.as_slice()
+    {
+        log::error!(
+            "The profit_distribution_num is incorrect, expected: {:?}",
+            nums
+        );
+        return Err(Error::AccountBookModified);
+    }
+
+    let mut retired: alloc::vec::Vec<u128> = (0..level)
+        .map(|l| crate::get_retired_withdrawn(old_cell_data, l))
+        .collect::<Result<_, Error>>()?;
+    retired[spore_level as usize] = utils::checked_add(retired[spore_level as usize], withdrawn)?;
+    let retired = Uint128Vec::new_builder()
+        .extend(retired.into_iter().map(|v| v.pack()))
+        .build();
+    if new_cell_data.retired_withdrawn_udt().as_slice() != retired.as_slice() {
+        log::error!("The retired_withdrawn_udt is incorrect");
+        return Err(Error::AccountBookModified);
+    }
+
+    let minted = match minted {
+        Some((minted_level, credited)) => {
+            let mut minted: alloc::vec::Vec<u128> = (0..level)
+                .map(|l| crate::get_minted_withdrawn(old_cell_data, l))
+                .collect::<Result<_, Error>>()?;
+            minted[minted_level as usize] =
+                utils::checked_add(minted[minted_level as usize], credited)?;
+            Uint128Vec::new_builder()
+                .extend(minted.into_iter().map(|v| v.pack()))
+                .build()
+        }
+        None => old_cell_data.minted_withdrawn_udt(),
+    };
+    if new_cell_data.minted_withdrawn_udt().as_slice() != minted.as_slice() {
+        log::error!("The minted_withdrawn_udt is incorrect");
+        return Err(Error::AccountBookModified);
+    }
+
+    Ok(())
+}
+
+pub fn retire(
+    witness_data: AccountBookData,
+    cell_data: AccountBookCellData,
+    old_smt_hash: Hash,
+) -> Result<(), Error> {
+    let spore_info = witness_data.retired_spore().to_opt().ok_or_else(|| {
+        log::error!("Retired Spore not found in witness");
+        Error::ParseWitness
+    })?;
+    let spore_level: u8 = spore_info.spore_level().into();
+    let account_book_level: u8 = cell_data.level().into();
+    if account_book_level <= spore_level {
+        log::error!(
+            "This Spore({}) is not eligible for profit sharing",
+            spore_level
+        );
+        return Err(Error::Spore);
+    }
+
+    // The Spore is consumed in this transaction and not recreated,
+    // or it was burned by another AccountBook of the cluster, which recorded the burn
+    let spore_code_hash: Hash = spore_info.spore_code_hash().into();
+    let spore_id: Hash = spore_info.spore_id().into();
+    let burned_here = QueryIter::new(load_cell_type, Source::Input).any(|script| {
+        script.is_some_and(|s| {
+            spore_code_hash == s.code_hash() && spore_id == s.args().raw_data().as_ref()
+        })
+    });
+    if burned_here {
+        crate::withdrawal::check_spore(&cell_data, &spore_info, Source::Input)?;
+        check_burned(&spore_info)?;
+    } else {
+        crate::register::check_burned_elsewhere(&witness_data, &cell_data, &spore_info)?;
+    }
+
+    let withdrawn: Option<u128> = witness_data.withdrawn_udt().to_opt().map(|v| v.unpack());
+    match withdrawn {
+        Some(utils::SMT_VALUE_RETIRED) => {
+            log::error!("This Spore has been retired");
+            return Err(Error::Spore);
+        }
+        Some(_) => {}
+        None => crate::register::check_origin(&witness_data, &cell_data, &spore_info)?,
+    }
+    let old_cell_data = utils::load_account_bool_cell_data(0, Source::GroupInput)?;
+    check_distribution(
+        &old_cell_data,
+        &cell_data,
+        (spore_level, 1, withdrawn.unwrap_or(0)),
+        None,
+    )?;
+
+    // The treasury does not change
+    let udt_info = utils::UDTInfo::new(cell_data.xudt_script_hash().into())?;
+    let (old_total_udt, new_total_udt) = super::check_input_type_proxy_lock(&cell_data, &udt_info)?;
+    if old_total_udt != new_total_udt {
+        log::error!(
+            "Retire does not allow update udt: old({}), new({})",
+            old_total_udt,
+            new_total_udt
+        );
+        return Err(Error::CheckXUDT);
+    }
+
+    // SMT, other AccountBooks retire the burned Spore by its Burned leaf
+    let total_income: u128 = witness_data.total_income_udt().unpack();
+    let total_withdrawn: u128 = witness_data.total_withdrawn_udt().unpack();
+    let smt_key = SmtKey::Buyer(spore_id.clone());
+    let mut old_leaves = alloc::vec![(smt_key.clone(), withdrawn)];
+    let mut new_leaves = alloc::vec![(smt_key, Some(utils::SMT_VALUE_RETIRED))];
+    if burned_here {
+        old_leaves.push((SmtKey::Burned(spore_id.clone()), None));
+        new_leaves.push((SmtKey::Burned(spore_id), Some(spore_level as u128)));
+    }
+    let proof = AccountBookProof::new(witness_data.proof().unpack());
+    if !proof.verify_old_leaves(
+        old_smt_hash,
+        total_income,
+        old_total_udt,
+        total_withdrawn,
+        &old_leaves,
+    )? {
+        log::error!("Verify old SMT failed");
+        return Err(Error::Smt);
+    }
+
+    let new_smt_hash = cell_data.smt_root_hash().into();
+    if !proof.verify_leaves(
+        new_smt_hash,
+        total_income,
+        new_total_udt,
+        total_withdrawn,
+        &new_leaves,
+    )? {
+        log::error!("Verify new SMT failed");
+        return Err(Error::Smt);
+    }
+
+    Ok(())
+}
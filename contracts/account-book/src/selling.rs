@@ -1,8 +1,11 @@
 use super::Error;
+use alloc::vec;
 use ckb_std::{
     ckb_constants::Source,
     ckb_types::prelude::{Entity, Reader, Unpack},
-    high_level::{load_cell_data, load_cell_type, QueryIter},
+    high_level::{
+        load_cell_data, load_cell_lock_hash, load_cell_type, load_script_hash, QueryIter,
+    },
     log,
 };
 use spore_types::spore::SporeData;
@@ -32,12 +35,117 @@ fn load_spore(source: Source) -> Result<(SporeData, Hash), Error> {
     }
 }
 
+// Only DNA in the creator's approved set can be minted into the collection
+pub fn check_approved_dna(
+    witness_data: &AccountBookData,
+    cell_data: &AccountBookCellData,
+    dna_hash: &Hash,
+) -> Result<(), Error> {
+    let root: Hash = cell_data.approved_dna_root().into();
+    if root == [0u8; 32] {
+        return Ok(());
+    }
+
+    let proof = utils::AccountBookProof::new(witness_data.approved_dna_proof().unpack());
+    if !proof.verify_approved_dna(root, dna_hash.clone())? {
+        log::error!("The DNA of Spore is not approved");
+        return Err(Error::Spore);
+    }
+    Ok(())
+}
+
+// Returns (discount, nonce) of the coupon attached to the BuyIntent
+fn load_coupon(cell_data: &AccountBookCellData) -> Result<Option<(u128, Hash)>, Error> {
+    let buy_intent_code_hash: Hash = cell_data.buy_intent_code_hash().into();
+    let indexs = utils::get_indexs(
+        utils::load_type_code_hash,
+        |h| buy_intent_code_hash == h,
+        Source::Input,
+    );
+    if indexs.len() != 1 {
+        // A coupon discounts the only BuyIntent of the transaction, with several it cannot apply
+        for index in &indexs {
+            let buy_intent_data = utils::load_buy_intent_data(*index, Source::Input)?;
+            let discount: u128 = buy_intent_data.coupon().discount().unpack();
+            if discount != 0 {
+                log::error!(
+                    "A BuyIntent with a coupon must be the only one in Inputs, found: {}",
+                    indexs.len()
+                );
+                return Err(Error::Coupon);
+            }
+        }
+        return Ok(None);
+    }
+    let buy_intent_data = utils::load_buy_intent_data(indexs[0], Source::Input)?;
+
+    let account_book_hash: Hash = load_script_hash()?.into();
+    let discount = utils::verify_coupon(
+        &buy_intent_data,
+        &account_book_hash,
+        &cell_data.coupon_pubkey_hash().into(),
+    )?;
+    if discount == 0 {
+        return Ok(None);
+    }
+    utils::check_coupon_expiry(&buy_intent_data, indexs[0], Source::Input)?;
+
+    Ok(Some((discount, buy_intent_data.coupon().nonce().into())))
+}
+
+// Returns (owner lock of the BuyIntent, old bought count, new bought count)
+fn check_buyer_quota(
+    witness_data: &AccountBookData,
+    cell_data: &AccountBookCellData,
+) -> Result<Option<(Hash, Option<u128>, u128)>, Error> {
+    let max_per_buyer: u32 = cell_data.max_per_buyer().unpack();
+    if max_per_buyer == 0 {
+        return Ok(None);
+    }
+
+    let buy_intent_code_hash: Hash = cell_data.buy_intent_code_hash().into();
+    let indexs = utils::get_indexs(
+        utils::load_type_code_hash,
+        |h| buy_intent_code_hash == h,
+        Source::Input,
+    );
+    if indexs.len() != 1 {
+        log::error!(
+            "Only one BuyIntent allowed in Inputs, found: {}",
+            indexs.len()
+        );
+        return Err(Error::TxStructure);
+    }
+    let buy_intent_data = utils::load_buy_intent_data(indexs[0], Source::Input)?;
+
+    let old_bought: Option<u128> = witness_data
+        .buyer_lock_bought()
+        .to_opt()
+        .map(|v| v.unpack());
+    let new_bought = utils::checked_add(old_bought.unwrap_or(0), 1)?;
+    if new_bought > max_per_buyer as u128 {
+        log::error!(
+            "The buyer has bought {} Spores, limit: {}",
+            old_bought.unwrap_or(0),
+            max_per_buyer
+        );
+        return Err(Error::AccountBook);
+    }
+
+    Ok(Some((
+        buy_intent_data.owner_script_hash().into(),
+        old_bought,
+        new_bought,
+    )))
+}
+
 pub fn selling(
     witness_data: AccountBookData,
     cell_data: AccountBookCellData,
     old_smt_hash: Hash,
 ) -> Result<(), Error> {
     let (spore_data, spore_id) = load_spore(Source::Output)?;
+    crate::withdrawal::check_new_spore(&spore_id)?;
 
     // Check cluster id
     if spore_data
@@ -55,7 +163,7 @@ pub fn selling(
     }
     // Check spore level
     let level_by_witness: u8 = cell_data.level().into();
-    let level_by_spore = utils::get_spore_level(&spore_data)?;
+    let level_by_spore = utils::get_spore_level_by_rule(&spore_data, &cell_data.level_rule())?;
     if level_by_witness != level_by_spore {
         log::error!(
             "The Spore level being sold is incorrect, {}, {}",
@@ -65,13 +173,17 @@ pub fn selling(
         return Err(Error::Spore);
     }
 
-    // Check price
-    let price: u128 = cell_data.price().unpack();
+    // Check price, a coupon signed by the platform takes its discount off
+    let coupon = load_coupon(&cell_data)?;
+    let price: u128 = match &coupon {
+        Some((discount, _nonce)) => utils::checked_sub(cell_data.price().unpack(), *discount)?,
+        None => cell_data.price().unpack(),
+    };
     let (old_amount, new_amount) = {
         let udt_info = utils::UDTInfo::new(cell_data.xudt_script_hash().into())?;
         let (old, new) = super::check_input_type_proxy_lock(&cell_data, &udt_info)?;
 
-        if old + price != new {
+        if utils::checked_add(old, price)? != new {
             log::error!(
                 "In and Out Error: input: {}, output: {}, price: {}",
                 old,
@@ -86,28 +198,145 @@ pub fn selling(
 
     let (old_total_income, new_total_income): (u128, u128) = {
         let total: u128 = witness_data.total_income_udt().unpack();
-        (total, total + price)
+        (total, utils::checked_add(total, price)?)
     };
+    let total_withdrawn: u128 = witness_data.total_withdrawn_udt().unpack();
 
     use utils::{AccountBookProof, SmtKey};
-    // Check the spore id here to avoid duplicate sales
+    // Check the spore id here to avoid duplicate sales,
+    // and the DNA to avoid selling the same DNA twice
+    let dna_hash = utils::get_spore_dna_hash(&spore_data)?;
+    check_approved_dna(&witness_data, &cell_data, &dna_hash)?;
+    let mut old_leaves = vec![
+        (SmtKey::Buyer(spore_id.clone()), None),
+        (SmtKey::Dna(dna_hash.clone()), None),
+    ];
+    let mut new_leaves = vec![
+        (SmtKey::Buyer(spore_id), Some(0)),
+        (SmtKey::Dna(dna_hash), Some(0)),
+    ];
+    if let Some((buyer_lock, old_bought, new_bought)) =
+        check_buyer_quota(&witness_data, &cell_data)?
+    {
+        old_leaves.push((SmtKey::BuyerLock(buyer_lock.clone()), old_bought));
+        new_leaves.push((SmtKey::BuyerLock(buyer_lock), Some(new_bought)));
+    }
+    // Each coupon can only be used once
+    if let Some((_discount, nonce)) = coupon {
+        old_leaves.push((SmtKey::Coupon(nonce.clone()), None));
+        new_leaves.push((SmtKey::Coupon(nonce), Some(0)));
+    }
+
     let proof = AccountBookProof::new(witness_data.proof().unpack());
-    if !proof.verify(
+    if !proof.verify_old_leaves(
         old_smt_hash,
         old_total_income,
         old_amount,
-        (SmtKey::Buyer(spore_id.clone()), None),
+        total_withdrawn,
+        &old_leaves,
     )? {
-        log::error!("Verify Input SMT failed");
+        log::error!("Verify Input SMT failed, the Spore or its DNA may have been sold");
         return Err(Error::Smt);
     }
 
     let new_smt_hash: Hash = cell_data.smt_root_hash().into();
-    if !proof.verify(
+    if !proof.verify_leaves(
         new_smt_hash,
         new_total_income,
         new_amount,
-        (SmtKey::Buyer(spore_id), Some(0)),
+        total_withdrawn,
+        &new_leaves,
+    )? {
+        log::error!("Verify Output SMT failed");
+        return Err(Error::Smt);
+    }
+
+    Ok(())
+}
+
+// The auther mints a reserved Spore at zero price, it shares profits like a sold one
+pub fn reserve_mint(
+    witness_data: AccountBookData,
+    cell_data: AccountBookCellData,
+    old_smt_hash: Hash,
+) -> Result<(), Error> {
+    let auther_id: Hash = cell_data.auther_id().into();
+    if !QueryIter::new(load_cell_lock_hash, Source::Input).any(|hash| auther_id == hash) {
+        log::error!("Reserve mint must be authorized by the auther");
+        return Err(Error::CheckScript);
+    }
+
+    let old_cell_data = utils::load_account_bool_cell_data(0, Source::GroupInput)?;
+    let old_reserved: u32 = old_cell_data.reserved_count().unpack();
+    let new_reserved: u32 = cell_data.reserved_count().unpack();
+    if old_reserved == 0 || old_reserved - 1 != new_reserved {
+        log::error!(
+            "The reserved_count is incorrect: old({}), new({})",
+            old_reserved,
+            new_reserved
+        );
+        return Err(Error::AccountBookModified);
+    }
+
+    let spore_info = witness_data.reserved_spore().to_opt().ok_or_else(|| {
+        log::error!("Reserved Spore not found in witness");
+        Error::ParseWitness
+    })?;
+    let spore_data = crate::withdrawal::check_spore(&cell_data, &spore_info, Source::Output)?;
+    crate::withdrawal::check_new_spore(&spore_info.spore_id().into())?;
+    let level: u8 = cell_data.level().into();
+    if level != u8::from(spore_info.spore_level()) {
+        log::error!(
+            "The reserved Spore level is incorrect, {}, {}",
+            level,
+            u8::from(spore_info.spore_level())
+        );
+        return Err(Error::Spore);
+    }
+
+    // No xUDT flows in or out
+    let udt_info = utils::UDTInfo::new(cell_data.xudt_script_hash().into())?;
+    let (old_amount, new_amount) = super::check_input_type_proxy_lock(&cell_data, &udt_info)?;
+    if old_amount != new_amount {
+        log::error!(
+            "In and Out Error: input: {}, output: {}",
+            old_amount,
+            new_amount
+        );
+        return Err(Error::CheckXUDT);
+    }
+
+    use utils::{AccountBookProof, SmtKey};
+    let spore_id: Hash = spore_info.spore_id().into();
+    let dna_hash = utils::get_spore_dna_hash(&spore_data)?;
+    check_approved_dna(&witness_data, &cell_data, &dna_hash)?;
+    let total_income: u128 = witness_data.total_income_udt().unpack();
+    let total_withdrawn: u128 = witness_data.total_withdrawn_udt().unpack();
+    let proof = AccountBookProof::new(witness_data.proof().unpack());
+    if !proof.verify_old_leaves(
+        old_smt_hash,
+        total_income,
+        old_amount,
+        total_withdrawn,
+        &[
+            (SmtKey::Buyer(spore_id.clone()), None),
+            (SmtKey::Dna(dna_hash.clone()), None),
+        ],
+    )? {
+        log::error!("Verify Input SMT failed, the Spore or its DNA may have been sold");
+        return Err(Error::Smt);
+    }
+
+    let new_smt_hash: Hash = cell_data.smt_root_hash().into();
+    if !proof.verify_leaves(
+        new_smt_hash,
+        total_income,
+        new_amount,
+        total_withdrawn,
+        &[
+            (SmtKey::Buyer(spore_id), Some(0)),
+            (SmtKey::Dna(dna_hash), Some(0)),
+        ],
     )? {
         log::error!("Verify Output SMT failed");
         return Err(Error::Smt);
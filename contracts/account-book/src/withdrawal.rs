@@ -1,9 +1,23 @@
 use super::Error;
+use alloc::vec;
 use ckb_std::{
-    ckb_constants::Source, ckb_types::prelude::Unpack, high_level::load_cell_lock_hash, log,
+    ckb_constants::Source,
+    ckb_types::prelude::{Entity, Reader, Unpack},
+    high_level::{
+        load_cell_data, load_cell_lock_hash, load_cell_type, load_input_since, QueryIter,
+    },
+    log,
+    since::Since,
+};
+use spore_types::spore::{SporeData, SporeDataReader};
+use types::{
+    AccountBookCellData, AccountBookData, WithdrawalBuyer, WithdrawalBuyerUnion,
+    WithdrawalSporeInfo,
+};
+use utils::{
+    checked_add, checked_mul, checked_mul_div, get_indexs, load_type_code_hash,
+    load_withdrawal_data, Hash, SmtKey, UDTInfo,
 };
-use types::{AccountBookCellData, AccountBookData, WithdrawalBuyer, WithdrawalBuyerUnion};
-use utils::{get_indexs, load_type_code_hash, load_withdrawal_data, Hash, SmtKey, UDTInfo};
 
 fn get_buyer(hash: Hash) -> Result<WithdrawalBuyer, Error> {
     let indexs = get_indexs(load_type_code_hash, |h| hash == h, Source::Input);
@@ -11,6 +25,69 @@ fn get_buyer(hash: Hash) -> Result<WithdrawalBuyer, Error> {
     Ok(withdrawal_data.buyer())
 }
 
+pub fn get_spore_total_withdrawn(
+    cell_data: &AccountBookCellData,
+    ratios: &[u8],
+    spore_level: u8,
+    total_income: u128,
+) -> Result<u128, Error> {
+    let account_book_level: u8 = cell_data.level().into();
+    if account_book_level <= spore_level {
+        log::error!(
+            "This Spore({}) is not eligible for profit sharing",
+            spore_level
+        );
+        return Err(Error::Spore);
+    }
+
+    let nums = cell_data.profit_distribution_number().raw_data().to_vec();
+    if nums.len() != account_book_level as usize {
+        log::error!(
+            "The profit_distribution_num price in the account book is wrong, it needs: {}, actual: {}",
+            account_book_level,
+            nums.len()
+        );
+        return Err(Error::AccountBook);
+    }
+    let num = nums[spore_level as usize] as u128;
+    if num == 0 {
+        log::error!("No Spore left in level {}", spore_level);
+        return Err(Error::Spore);
+    }
+
+    // What retired Spores have withdrawn is no longer shared by this level,
+    // what minted Spores are credited with is shared as if it had been paid in
+    let ratio = ratios[spore_level as usize + 2] as u128;
+    let retired = crate::get_retired_withdrawn(cell_data, spore_level)?;
+    let minted = crate::get_minted_withdrawn(cell_data, spore_level)?;
+    Ok(
+        checked_add(checked_mul_div(total_income, ratio, 100)?, minted)?.saturating_sub(retired)
+            / num,
+    )
+}
+
+// Returns (weight, total weight) of the co-author
+fn get_co_author_weight(
+    cell_data: &AccountBookCellData,
+    script_hash: &Hash,
+) -> Result<Option<(u128, u128)>, Error> {
+    let mut total_weight = 0u128;
+    let mut weight = None;
+    for co_author in cell_data.co_authors().into_iter() {
+        let co_author_weight: u8 = co_author.weight().into();
+        total_weight = checked_add(total_weight, co_author_weight as u128)?;
+        if *script_hash == co_author.lock_script_hash() {
+            weight = Some(co_author_weight as u128);
+        }
+    }
+    if total_weight == 0 {
+        log::error!("The total weight of co-authors is 0");
+        return Err(Error::AccountBook);
+    }
+
+    Ok(weight.map(|w| (w, total_weight)))
+}
+
 fn get_total_withdrawn(
     cell_data: &AccountBookCellData,
     witness_data: &AccountBookData,
@@ -19,54 +96,88 @@ fn get_total_withdrawn(
     let ratios = crate::get_ratios(cell_data, account_book_level)?;
 
     let buyer = get_buyer(cell_data.withdrawal_intent_code_hash().into())?;
+    let total_income = witness_data.total_income_udt().unpack();
 
-    let (ratio, num, smt_key) = match buyer.to_enum() {
+    match buyer.to_enum() {
         WithdrawalBuyerUnion::WithdrawalSporeInfo(spore_info) => {
-            // Load spore level
-            let (spore_level, spore_id) = {
-                let level = spore_info.spore_level().into();
-                let id: Hash = spore_info.spore_id().into();
-                (level, id)
-            };
-            if account_book_level <= spore_level {
-                log::error!(
-                    "This Spore({}) is not eligible for profit sharing",
-                    spore_level
-                );
-                return Err(Error::Spore);
-            }
-
-            let nums = cell_data.profit_distribution_number().raw_data().to_vec();
-            if nums.len() != account_book_level as usize {
-                log::error!(
-                "The profit_distribution_num price in the account book is wrong, it needs: {}, actual: {}",
-                account_book_level,
-                nums.len()
-            );
-                return Err(Error::AccountBook);
+            if witness_data.withdrawn_udt().is_none() {
+                crate::register::check_origin(witness_data, cell_data, &spore_info)?;
             }
-
-            (
-                ratios[spore_level as usize + 2] as usize,
-                nums[spore_level as usize] as usize,
-                SmtKey::Buyer(spore_id),
-            )
+            let spore_id: Hash = spore_info.spore_id().into();
+            let total_withdrawn = get_spore_total_withdrawn(
+                cell_data,
+                &ratios,
+                spore_info.spore_level().into(),
+                total_income,
+            )?;
+            Ok((total_withdrawn, SmtKey::Buyer(spore_id)))
         }
         WithdrawalBuyerUnion::Byte32(script_hash) => {
             let script_hash: Hash = script_hash.into();
-            if script_hash == cell_data.auther_id() {
-                (ratios[1] as usize, 1usize, SmtKey::Auther)
+            let co_authors = cell_data.co_authors();
+            if !co_authors.is_empty() {
+                if let Some((weight, total_weight)) = get_co_author_weight(cell_data, &script_hash)?
+                {
+                    let total_withdrawn = checked_mul_div(
+                        total_income,
+                        checked_mul(ratios[1] as u128, weight)?,
+                        checked_mul(100, total_weight)?,
+                    )?;
+                    return Ok((
+                        get_auther_vested(cell_data, total_withdrawn)?,
+                        SmtKey::CoAuthor(script_hash),
+                    ));
+                }
+            }
+
+            if script_hash == cell_data.auther_id() && co_authors.is_empty() {
+                let total_withdrawn = checked_mul_div(total_income, ratios[1] as u128, 100)?;
+                Ok((
+                    get_auther_vested(cell_data, total_withdrawn)?,
+                    SmtKey::Auther,
+                ))
             } else if script_hash == cell_data.platform_id() {
-                (ratios[0] as usize, 1usize, SmtKey::Platform)
+                Ok((
+                    checked_mul_div(total_income, ratios[0] as u128, 100)?,
+                    SmtKey::Platform,
+                ))
             } else {
                 log::error!("Unknow WithdrawalBuyer: {:02x?}", script_hash.as_slice());
-                return Err(Error::AccountBook);
+                Err(Error::AccountBook)
             }
         }
-    };
+    }
+}
 
-    let total_income = witness_data.total_income_udt().unpack();
-    Ok((total_income * ratio as u128 / (100 * num as u128), smt_key))
+fn get_auther_vested(cell_data: &AccountBookCellData, total: u128) -> Result<u128, Error> {
+    let start: u64 = cell_data.auther_vesting_start().unpack();
+    let duration: u64 = cell_data.auther_vesting_duration().unpack();
+    if duration == 0 {
+        return Ok(total);
+    }
+
+    // The since of AccountBook is used as the current time,
+    // the transaction cannot be committed before it.
+    let since = Since::new(load_input_since(0, Source::GroupInput)?);
+    let timestamp = if since.is_absolute() {
+        since.extract_lock_value().and_then(|v| v.timestamp())
+    } else {
+        None
+    }
+    .ok_or_else(|| {
+        log::error!(
+            "Auther withdrawal requires an absolute timestamp since, since: {}",
+            since.as_u64()
+        );
+        Error::ExpireSince
+    })?;
+
+    let elapsed = timestamp.saturating_sub(start);
+    if elapsed >= duration {
+        Ok(total)
+    } else {
+        checked_mul_div(total, elapsed as u128, duration as u128)
+    }
 }
 
 fn get_output_udt(cell_data: &AccountBookCellData, udt_info: &UDTInfo) -> Result<u128, Error> {
@@ -77,8 +188,13 @@ fn get_output_udt(cell_data: &AccountBookCellData, udt_info: &UDTInfo) -> Result
         Source::Input,
     );
     let withdrawal_data = utils::load_withdrawal_data(indexs[0], Source::Input, true)?;
-    let xudt_lock_script_hash: Hash = withdrawal_data.xudt_lock_script_hash().into();
+    get_output_udt_by_lock(udt_info, withdrawal_data.xudt_lock_script_hash().into())
+}
 
+pub fn get_output_udt_by_lock(
+    udt_info: &UDTInfo,
+    xudt_lock_script_hash: Hash,
+) -> Result<u128, Error> {
     for (udt, index) in &udt_info.outputs {
         let lock_hash = load_cell_lock_hash(*index, Source::Output)?;
         if xudt_lock_script_hash == lock_hash {
@@ -90,17 +206,30 @@ fn get_output_udt(cell_data: &AccountBookCellData, udt_info: &UDTInfo) -> Result
     Err(Error::TxStructure)
 }
 
+// Returns the fee credited to the platform when a holder withdraws `payout`
+fn get_withdrawal_fee(cell_data: &AccountBookCellData, payout: u128) -> Result<u128, Error> {
+    let flat: u128 = cell_data.withdrawal_fee_udt().unpack();
+    let ratio: u8 = cell_data.withdrawal_fee_ratio().into();
+    checked_add(flat, checked_mul_div(payout, ratio as u128, 100)?)
+}
+
+fn has_withdrawal_fee(cell_data: &AccountBookCellData) -> bool {
+    let flat: u128 = cell_data.withdrawal_fee_udt().unpack();
+    let ratio: u8 = cell_data.withdrawal_fee_ratio().into();
+    flat != 0 || ratio != 0
+}
+
 pub fn withdrawal(
     witness_data: AccountBookData,
     cell_data: AccountBookCellData,
     old_smt_hash: Hash,
 ) -> Result<(), Error> {
-    let (new_total_withdrawn, smt_key) = get_total_withdrawn(&cell_data, &witness_data)?;
+    let (mut max_total_withdrawn, smt_key) = get_total_withdrawn(&cell_data, &witness_data)?;
 
     let udt_info = UDTInfo::new(cell_data.xudt_script_hash().into())?;
     let (old_total_udt, new_total_udt) = super::check_input_type_proxy_lock(&cell_data, &udt_info)?;
     let withdrawal_udt = get_output_udt(&cell_data, &udt_info)?;
-    if old_total_udt != new_total_udt + withdrawal_udt {
+    if old_total_udt != checked_add(new_total_udt, withdrawal_udt)? {
         log::error!("The extracted udt is incorrect");
         return Err(Error::AccountBook);
     }
@@ -109,22 +238,202 @@ pub fn withdrawal(
         witness_data.withdrawn_udt().to_opt().map(|v| v.unpack());
     let total_income = witness_data.total_income_udt().unpack();
 
-    if old_total_udt - new_total_udt != new_total_withdrawn - old_total_withdrawal.unwrap_or(0) {
+    // The fee stays in the AccountBook and is credited to the platform.
+    // The Platform leaf counts what the platform has withdrawn of its ratio, crediting a fee there
+    // would lower it below zero before the platform first withdraws. So the fees are summed in
+    // the PlatformFee leaf, which raises what the platform can withdraw.
+    let with_fee = has_withdrawal_fee(&cell_data);
+    let old_platform_fee: Option<u128> =
+        witness_data.platform_fee_udt().to_opt().map(|v| v.unpack());
+    let fee = match smt_key {
+        SmtKey::Buyer(_) if with_fee => get_withdrawal_fee(&cell_data, withdrawal_udt)?,
+        SmtKey::Platform if with_fee => {
+            max_total_withdrawn = checked_add(max_total_withdrawn, old_platform_fee.unwrap_or(0))?;
+            0
+        }
+        _ => 0,
+    };
+
+    // Any amount up to the entitlement can be withdrawn
+    let new_total_withdrawn = checked_add(
+        checked_add(old_total_withdrawal.unwrap_or(0), withdrawal_udt)?,
+        fee,
+    )?;
+    if withdrawal_udt == 0 || new_total_withdrawn > max_total_withdrawn {
         log::error!(
-            "Error in calculation of withdrawal: total udt: old({}) new({}), total_withdrawn: old({:?}) new({})",
+            "Error in calculation of withdrawal: total udt: old({}) new({}), total_withdrawn: old({:?}) new({}) max({}), fee: {}",
             old_total_udt,
             new_total_udt,
             old_total_withdrawal,
-            new_total_withdrawn);
+            new_total_withdrawn,
+            max_total_withdrawn,
+            fee);
         return Err(Error::AccountBook);
     }
 
+    // The AccountBook total grows by the payout, the fee stays in the AccountBook
+    let old_book_withdrawn: u128 = witness_data.total_withdrawn_udt().unpack();
+    let new_book_withdrawn = checked_add(old_book_withdrawn, withdrawal_udt)?;
+
     // SMT
+    let mut old_leaves = vec![(smt_key.clone(), old_total_withdrawal)];
+    let mut new_leaves = vec![(smt_key, Some(new_total_withdrawn))];
+    if with_fee {
+        old_leaves.push((SmtKey::PlatformFee, old_platform_fee));
+        new_leaves.push((
+            SmtKey::PlatformFee,
+            if fee == 0 {
+                old_platform_fee
+            } else {
+                Some(checked_add(old_platform_fee.unwrap_or(0), fee)?)
+            },
+        ));
+    }
+
     let proof = utils::AccountBookProof::new(witness_data.proof().unpack());
-    if !proof.verify(
+    if !proof.verify_old_leaves(
+        old_smt_hash,
+        total_income,
+        old_total_udt,
+        old_book_withdrawn,
+        &old_leaves,
+    )? {
+        log::error!("Verify old SMT failed");
+        return Err(Error::AccountBook);
+    }
+
+    let new_smt_hash = cell_data.smt_root_hash().into();
+    if !proof.verify_leaves(
+        new_smt_hash,
+        total_income,
+        new_total_udt,
+        new_book_withdrawn,
+        &new_leaves,
+    )? {
+        log::error!("Verify new SMT failed");
+        return Err(Error::AccountBook);
+    }
+
+    Ok(())
+}
+
+pub fn check_spore(
+    cell_data: &AccountBookCellData,
+    spore_info: &WithdrawalSporeInfo,
+    source: Source,
+) -> Result<SporeData, Error> {
+    let spore_code_hash: Hash = spore_info.spore_code_hash().into();
+    let spore_id: Hash = spore_info.spore_id().into();
+    let index = QueryIter::new(load_cell_type, source)
+        .position(|script| {
+            script.is_some_and(|s| {
+                spore_code_hash == s.code_hash() && spore_id == s.args().raw_data().as_ref()
+            })
+        })
+        .ok_or_else(|| {
+            log::error!("Spore not found in {:?}", source);
+            Error::Spore
+        })?;
+
+    let spore_data = load_cell_data(index, source)?;
+    SporeDataReader::verify(&spore_data, true)?;
+    let spore_data = SporeData::new_unchecked(spore_data.into());
+
+    let cluster_id: Hash = spore_data.cluster_id().try_into()?;
+    if cluster_id != cell_data.cluster_id() || cluster_id != spore_info.cluster_id() {
+        log::error!("The cluster id of Spore does not match");
+        return Err(Error::Spore);
+    }
+
+    let spore_level: u8 = spore_info.spore_level().into();
+    if spore_level != utils::get_spore_level_by_rule(&spore_data, &cell_data.level_rule())? {
+        log::error!("The level of Spore is incorrect: {}", spore_level);
+        return Err(Error::Spore);
+    }
+
+    Ok(spore_data)
+}
+
+// The Spore is minted in this transaction, not carried over from Inputs
+pub fn check_new_spore(spore_id: &Hash) -> Result<(), Error> {
+    if QueryIter::new(load_cell_type, Source::Input)
+        .any(|script| script.is_some_and(|s| *spore_id == s.args().raw_data().as_ref()))
+    {
+        log::error!("The Spore must be minted in this transaction");
+        return Err(Error::Spore);
+    }
+
+    Ok(())
+}
+
+pub fn sweep(
+    witness_data: AccountBookData,
+    cell_data: AccountBookCellData,
+    old_smt_hash: Hash,
+) -> Result<(), Error> {
+    let claim_deadline: u64 = cell_data.claim_deadline_since().unpack();
+    if claim_deadline == 0 {
+        log::error!("This AccountBook has no claim deadline");
+        return Err(Error::AccountBook);
+    }
+    if !utils::check_since(0, Source::GroupInput, claim_deadline)? {
+        log::error!("The claim deadline has not passed yet");
+        return Err(Error::ExpireSince);
+    }
+
+    let spore_info = witness_data.swept_spore().to_opt().ok_or_else(|| {
+        log::error!("Swept Spore not found in witness");
+        Error::ParseWitness
+    })?;
+    // The holder can no longer sign, so the Spore is only referenced by CellDeps
+    check_spore(&cell_data, &spore_info, Source::CellDep)?;
+
+    let account_book_level: u8 = cell_data.level().into();
+    let ratios = crate::get_ratios(&cell_data, account_book_level)?;
+    let total_income: u128 = witness_data.total_income_udt().unpack();
+    let new_total_withdrawn = get_spore_total_withdrawn(
+        &cell_data,
+        &ratios,
+        spore_info.spore_level().into(),
+        total_income,
+    )?;
+
+    let old_total_withdrawal: Option<u128> =
+        witness_data.withdrawn_udt().to_opt().map(|v| v.unpack());
+    if old_total_withdrawal.is_none() {
+        crate::register::check_origin(&witness_data, &cell_data, &spore_info)?;
+    }
+    let swept_udt = new_total_withdrawn.saturating_sub(old_total_withdrawal.unwrap_or(0));
+    if swept_udt == 0 {
+        log::error!("Nothing to sweep");
+        return Err(Error::AccountBook);
+    }
+
+    // Unclaimed shares go to the platform
+    let udt_info = UDTInfo::new(cell_data.xudt_script_hash().into())?;
+    let (old_total_udt, new_total_udt) = super::check_input_type_proxy_lock(&cell_data, &udt_info)?;
+    let platform_udt = get_output_udt_by_lock(&udt_info, cell_data.platform_id().into())?;
+    if old_total_udt != checked_add(new_total_udt, swept_udt)? || platform_udt != swept_udt {
+        log::error!(
+            "Error in calculation of sweep: total udt: old({}) new({}), swept: {}, platform: {}",
+            old_total_udt,
+            new_total_udt,
+            swept_udt,
+            platform_udt
+        );
+        return Err(Error::AccountBook);
+    }
+
+    let old_book_withdrawn: u128 = witness_data.total_withdrawn_udt().unpack();
+
+    // SMT
+    let smt_key = SmtKey::Buyer(spore_info.spore_id().into());
+    let proof = utils::AccountBookProof::new(witness_data.proof().unpack());
+    if !proof.verify_old(
         old_smt_hash,
         total_income,
         old_total_udt,
+        old_book_withdrawn,
         (smt_key.clone(), old_total_withdrawal),
     )? {
         log::error!("Verify old SMT failed");
@@ -136,6 +445,7 @@ pub fn withdrawal(
         new_smt_hash,
         total_income,
         new_total_udt,
+        checked_add(old_book_withdrawn, swept_udt)?,
         (smt_key, Some(new_total_withdrawn)),
     )? {
         log::error!("Verify new SMT failed");
@@ -14,21 +14,23 @@ ckb_std::default_alloc!();
 use alloc::vec::Vec;
 use ckb_std::{
     ckb_constants::Source,
-    ckb_types::prelude::{Entity, Unpack},
+    ckb_types::prelude::{Builder, Entity, Pack, Reader, Unpack},
     high_level::{
-        load_cell_capacity, load_cell_data, load_cell_lock_hash, load_cell_type_hash, QueryIter,
+        load_cell_capacity, load_cell_data, load_cell_lock, load_cell_lock_hash, load_cell_type,
+        load_cell_type_hash, load_script, load_script_hash, load_witness_args, QueryIter,
     },
     log::{self},
 };
 use types::error::SilentBerryError as Error;
-use types::{AccountBookCellData, BuyIntentData};
-use utils::{is_not_out_of_bound, load_args_to_hash, Hash, UDTInfo};
+use types::{AccountBookCellData, BuyIntentData, DobSellingData};
+use utils::{is_not_out_of_bound, load_args_to_hash, Hash, UDTInfo, HASH_SIZE};
 
-fn is_input() -> Result<bool, Error> {
+// Returns (in Inputs, in Outputs), both only when an installment is topped up with unchanged data
+fn load_group() -> Result<(bool, bool), Error> {
     let input = is_not_out_of_bound(load_cell_capacity(0, Source::GroupInput))?;
     let output = is_not_out_of_bound(load_cell_capacity(0, Source::GroupOutput))?;
-    if input == output {
-        log::error!("Both Inputs and Outputs has But Intent");
+    if !input && !output {
+        log::error!("Neither Inputs nor Outputs has Buy Intent");
         return Err(Error::TxStructure);
     }
 
@@ -41,7 +43,7 @@ fn is_input() -> Result<bool, Error> {
         return Err(Error::TxStructure);
     }
 
-    Ok(input)
+    Ok((input, output))
 }
 
 fn load_verified_data(is_input: bool) -> Result<(BuyIntentData, Hash), Error> {
@@ -91,13 +93,9 @@ fn check_input_dob_selling(dob_selling_hash: Hash) -> Result<usize, Error> {
 }
 
 fn has_account_book(account_book_hash: &Hash) -> Result<bool, Error> {
-    let mut count = 0;
-    QueryIter::new(load_cell_type_hash, Source::Input).all(|f| {
-        if *account_book_hash == f.unwrap() {
-            count += 1;
-        }
-        true
-    });
+    let count = QueryIter::new(load_cell_type_hash, Source::Input)
+        .filter(|f| *account_book_hash == *f)
+        .count();
     if count > 1 {
         log::error!("Multiple account book detected in Inputs: {}", count);
         return Err(Error::CheckScript);
@@ -106,7 +104,11 @@ fn has_account_book(account_book_hash: &Hash) -> Result<bool, Error> {
     Ok(count == 1)
 }
 
-fn check_account_book(account_book_hash: Hash, price: u128) -> Result<(), Error> {
+// Returns the cell data of the AccountBook
+fn check_account_book(
+    account_book_hash: Hash,
+    witness_data: &BuyIntentData,
+) -> Result<AccountBookCellData, Error> {
     let mut query_iter = QueryIter::new(load_cell_type_hash, Source::Output);
     let pos = query_iter.position(|f| account_book_hash == f);
     if pos.is_none() {
@@ -118,24 +120,75 @@ fn check_account_book(account_book_hash: Hash, price: u128) -> Result<(), Error>
         return Err(Error::CheckScript);
     }
 
-    let accountbook_asset_amount: u128 =
-        AccountBookCellData::new_unchecked(load_cell_data(pos.unwrap(), Source::Output)?.into())
-            .price()
-            .unpack();
+    let accountbook_data =
+        AccountBookCellData::new_unchecked(load_cell_data(pos.unwrap(), Source::Output)?.into());
+    let accountbook_asset_amount: u128 = accountbook_data.price().unpack();
 
-    if accountbook_asset_amount != price {
+    let price: u128 = witness_data.price().unpack();
+    let max_price: u128 = witness_data.max_price().unpack();
+    if (max_price == 0 && accountbook_asset_amount != price)
+        || (max_price != 0 && accountbook_asset_amount > max_price)
+    {
         log::error!(
-            "Does not match asset_amount in AccountBook, {}, {}",
+            "Does not match asset_amount in AccountBook, {}, {}, max: {}",
             price,
-            accountbook_asset_amount
+            accountbook_asset_amount,
+            max_price
         );
         return Err(Error::VerifiedData);
     }
 
+    Ok(accountbook_data)
+}
+
+// What is paid above the price of the AccountBook goes back to the owner
+fn check_refund(
+    witness_data: &BuyIntentData,
+    udt_info: &UDTInfo,
+    accountbook_price: u128,
+) -> Result<(), Error> {
+    let excess = utils::checked_sub(utils::get_unit_payment(witness_data), accountbook_price)?;
+    if excess == 0 {
+        return Ok(());
+    }
+
+    let refund = udt_info.outputs_by_lock(&witness_data.owner_script_hash().into())?;
+    if refund < excess {
+        log::error!(
+            "The excess xUDT is not refunded to the owner, need: {}, actual: {}",
+            excess,
+            refund
+        );
+        return Err(Error::CheckXUDT);
+    }
+
+    Ok(())
+}
+
+// A coupon is used once, on one Spore at a fixed price
+fn check_coupon(witness_data: &BuyIntentData) -> Result<(), Error> {
+    let discount: u128 = witness_data.coupon().discount().unpack();
+    if discount == 0 {
+        return Ok(());
+    }
+
+    let max_price: u128 = witness_data.max_price().unpack();
+    let price: u128 = witness_data.price().unpack();
+    if utils::get_quantity(witness_data) != 1 || max_price != 0 || discount > price {
+        log::error!(
+            "Coupon only applies to one Spore at a fixed price, discount: {}, price: {}",
+            discount,
+            price
+        );
+        return Err(Error::Coupon);
+    }
+
     Ok(())
 }
 
 fn create_intent(witness_data: BuyIntentData, udt_info: UDTInfo) -> Result<(), Error> {
+    check_coupon(&witness_data)?;
+
     let dob_selling_index = {
         let dob_selling_script_hash: Hash = witness_data.dob_selling_script_hash().into();
         let indexs: Vec<usize> = utils::get_indexs(
@@ -150,7 +203,8 @@ fn create_intent(witness_data: BuyIntentData, udt_info: UDTInfo) -> Result<(), E
         indexs[0]
     };
 
-    let price: u128 = witness_data.price().unpack();
+    let price = utils::get_full_payment(&witness_data)?;
+    let deposit: u128 = witness_data.deposit().unpack();
     let dob_selling_price = udt_info
         .outputs
         .iter()
@@ -166,15 +220,23 @@ fn create_intent(witness_data: BuyIntentData, udt_info: UDTInfo) -> Result<(), E
             Error::CheckScript
         })?;
 
-    if *dob_selling_price != price {
+    // Installments start from the deposit and are topped up to the price later
+    if (deposit == 0 && *dob_selling_price != price)
+        || (deposit != 0 && (*dob_selling_price < deposit || *dob_selling_price > price))
+    {
         log::error!(
-            "Incorrect xUDT payment: Need: {}, Actually: {}",
+            "Incorrect xUDT payment: Need: {}, Actually: {}, deposit: {}",
             price,
-            udt_info.outputs[0].0
+            dob_selling_price,
+            deposit
         );
         return Err(Error::CheckXUDT);
     }
 
+    check_min_capacity(&witness_data)
+}
+
+fn check_min_capacity(witness_data: &BuyIntentData) -> Result<(), Error> {
     let capacity = load_cell_capacity(0, Source::GroupOutput)?;
     let buy_intent_capacity = u64::from_le_bytes(
         witness_data
@@ -187,7 +249,7 @@ fn create_intent(witness_data: BuyIntentData, udt_info: UDTInfo) -> Result<(), E
             })?,
     );
 
-    if capacity > buy_intent_capacity {
+    if capacity < buy_intent_capacity {
         log::error!(
             "Capacity does not meet transaction needs, required: {}, actual: {}",
             buy_intent_capacity,
@@ -195,18 +257,304 @@ fn create_intent(witness_data: BuyIntentData, udt_info: UDTInfo) -> Result<(), E
         );
         return Err(Error::CapacityError);
     }
+
+    // The filler cannot be paid more than the intent holds
+    let fill_fee: u64 = witness_data.fill_fee().unpack();
+    if fill_fee > capacity {
+        log::error!(
+            "The fill fee is more than the capacity, fill_fee: {}, capacity: {}",
+            fill_fee,
+            capacity
+        );
+        return Err(Error::CapacityError);
+    }
     Ok(())
 }
 
-fn selling(witness_data: BuyIntentData, accountbook_hash: Hash) -> Result<(), Error> {
-    check_account_book(accountbook_hash, witness_data.price().unpack())?;
+fn load_dob_selling_data(
+    witness: Option<ckb_std::ckb_types::bytes::Bytes>,
+) -> Result<DobSellingData, Error> {
+    let witness = witness.ok_or_else(|| {
+        log::error!("DobSellingData not found in witness");
+        Error::ParseWitness
+    })?;
+    types::DobSellingDataReader::verify(&witness, false)?;
+    Ok(DobSellingData::new_unchecked(witness))
+}
+
+// A partial fill leaves an intent of quantity - 1 behind,
+// the rest of xUDT goes to a new DobSelling that only differs in the Spore
+fn check_remaining_intent(
+    witness_data: &BuyIntentData,
+    accountbook_hash: &Hash,
+    udt_info: &UDTInfo,
+    dob_selling_index: usize,
+) -> Result<usize, Error> {
+    let code_hash: Hash = load_script()?.code_hash().into();
+    let indexs = utils::get_indexs(
+        utils::load_type_code_hash,
+        |h| code_hash == h,
+        Source::Output,
+    );
+    if indexs.len() != 1 {
+        log::error!("The remaining BuyIntent not found in Outputs");
+        return Err(Error::TxStructure);
+    }
+    let remaining_intent_index = indexs[0];
+    let new_data = utils::load_buy_intent_data(remaining_intent_index, Source::Output)?;
+    let args = load_cell_type(remaining_intent_index, Source::Output)?
+        .ok_or_else(|| {
+            log::error!("Load remaining BuyIntent type script failed");
+            Error::TxStructure
+        })?
+        .args()
+        .raw_data();
+    if args.as_ref()
+        != [
+            accountbook_hash.as_slice(),
+            Hash::ckb_hash(new_data.as_slice()).as_slice(),
+        ]
+        .concat()
+    {
+        log::error!("The args of the remaining BuyIntent are incorrect");
+        return Err(Error::VerifiedData);
+    }
+
+    let expected = witness_data
+        .clone()
+        .as_builder()
+        .quantity((utils::get_quantity(witness_data) - 1).pack())
+        .dob_selling_script_hash(new_data.dob_selling_script_hash())
+        .build();
+    if expected.as_slice() != new_data.as_slice() {
+        log::error!("The remaining BuyIntent is incorrect");
+        return Err(Error::VerifiedData);
+    }
+
+    let new_dob_selling_hash: Hash = new_data.dob_selling_script_hash().into();
+    let indexs = utils::get_indexs(
+        load_cell_lock_hash,
+        |h| new_dob_selling_hash == h,
+        Source::Output,
+    );
+    if indexs.len() != 1 {
+        log::error!("The remaining DobSelling not found in Outputs");
+        return Err(Error::TxStructure);
+    }
+    let remaining = utils::checked_sub(
+        load_escrowed(udt_info, dob_selling_index)?,
+        utils::get_unit_payment(witness_data),
+    )?;
+    if udt_info.output(indexs[0]) != Some(remaining) {
+        log::error!(
+            "The xUDT of the remaining DobSelling is incorrect, need: {}, actual: {:?}",
+            remaining,
+            udt_info.output(indexs[0])
+        );
+        return Err(Error::CheckXUDT);
+    }
+    let old_dob_data = load_dob_selling_data(
+        load_witness_args(dob_selling_index, Source::Input)?
+            .lock()
+            .to_opt()
+            .map(|w| w.raw_data()),
+    )?;
+    let new_dob_data = load_dob_selling_data(
+        load_witness_args(indexs[0], Source::Output)?
+            .output_type()
+            .to_opt()
+            .map(|w| w.raw_data()),
+    )?;
+    if load_cell_lock(indexs[0], Source::Output)?
+        .args()
+        .raw_data()
+        .as_ref()
+        != Hash::ckb_hash(new_dob_data.as_slice()).as_slice()
+    {
+        log::error!("The args of the remaining DobSelling are incorrect");
+        return Err(Error::VerifiedData);
+    }
+    let expected = old_dob_data
+        .as_builder()
+        .spore_data_hash(new_dob_data.spore_data_hash())
+        .build();
+    if expected.as_slice() != new_dob_data.as_slice() {
+        log::error!("The remaining DobSelling is incorrect");
+        return Err(Error::VerifiedData);
+    }
+
+    Ok(remaining_intent_index)
+}
+
+// Capacity of the Outputs locked by the owner without Type Script
+fn load_owner_capacity(owner_script_hash: &Hash) -> Result<u64, Error> {
+    let mut capacity = 0u64;
+    for (index, lock_hash) in QueryIter::new(load_cell_lock_hash, Source::Output).enumerate() {
+        if *owner_script_hash == lock_hash && load_cell_type_hash(index, Source::Output)?.is_none()
+        {
+            capacity += load_cell_capacity(index, Source::Output)?;
+        }
+    }
+    Ok(capacity)
+}
+
+// The capacity of the intent minus the fill fee goes back to the owner,
+// or moves to the remaining intent when partially filled
+fn check_capacity(
+    witness_data: &BuyIntentData,
+    remaining_intent_index: Option<usize>,
+) -> Result<(), Error> {
+    let capacity = load_cell_capacity(0, Source::GroupInput)?;
+    let fill_fee: u64 = witness_data.fill_fee().unpack();
+    let need = capacity.saturating_sub(fill_fee);
+
+    let returned = if let Some(index) = remaining_intent_index {
+        load_cell_capacity(index, Source::Output)?
+    } else {
+        load_owner_capacity(&witness_data.owner_script_hash().into())?
+    };
+
+    if returned < need {
+        log::error!(
+            "The capacity of BuyIntent is not returned, need: {}, actual: {}",
+            need,
+            returned
+        );
+        return Err(Error::CapacityError);
+    }
+
+    Ok(())
+}
+
+fn selling(
+    witness_data: BuyIntentData,
+    accountbook_hash: Hash,
+    udt_info: UDTInfo,
+) -> Result<(), Error> {
+    let accountbook_data = check_account_book(accountbook_hash.clone(), &witness_data)?;
+    // The AccountBook takes the discount of the coupon off its price
+    let discount = utils::verify_coupon(
+        &witness_data,
+        &accountbook_hash,
+        &accountbook_data.coupon_pubkey_hash().into(),
+    )?;
+    utils::check_coupon_expiry(&witness_data, 0, Source::GroupInput)?;
+    let accountbook_price = utils::checked_sub(accountbook_data.price().unpack(), discount)?;
+    check_refund(&witness_data, &udt_info, accountbook_price)?;
     let dob_selling_index = check_input_dob_selling(witness_data.dob_selling_script_hash().into())?;
     utils::from_same_tx_hash(dob_selling_index)?;
+    check_full_payment(&witness_data, &udt_info, dob_selling_index)?;
+    let remaining_intent_index = if utils::get_quantity(&witness_data) > 1 {
+        Some(check_remaining_intent(
+            &witness_data,
+            &accountbook_hash,
+            &udt_info,
+            dob_selling_index,
+        )?)
+    } else {
+        None
+    };
+    check_capacity(&witness_data, remaining_intent_index)
+}
+
+// The owner cancels the intent before it expires by unlocking another cell of its own
+fn is_owner_authorized(owner_script_hash: &Hash) -> Result<bool, Error> {
+    let script_hash: Hash = load_script_hash()?.into();
+    for (index, lock_hash) in QueryIter::new(load_cell_lock_hash, Source::Input).enumerate() {
+        if *owner_script_hash == lock_hash
+            && !matches!(load_cell_type_hash(index, Source::Input)?, Some(h) if script_hash == h)
+        {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+fn load_escrowed(udt_info: &UDTInfo, dob_selling_index: usize) -> Result<u128, Error> {
+    udt_info.input(dob_selling_index).ok_or_else(|| {
+        log::error!("Dob Selling type lock isnot xUDT");
+        Error::CheckXUDT
+    })
+}
+
+// An installment can only be filled once the full payment is escrowed
+fn check_full_payment(
+    witness_data: &BuyIntentData,
+    udt_info: &UDTInfo,
+    dob_selling_index: usize,
+) -> Result<(), Error> {
+    let escrowed = load_escrowed(udt_info, dob_selling_index)?;
+    let price = utils::get_full_payment(witness_data)?;
+    if escrowed != price {
+        log::error!(
+            "The payment is not fully escrowed, need: {}, actual: {}",
+            price,
+            escrowed
+        );
+        return Err(Error::CheckXUDT);
+    }
+
+    Ok(())
+}
+
+// The xUDT paid into the DobSelling goes back to the owner,
+// except what is forfeited from an incomplete installment
+fn check_xudt_refund(
+    witness_data: &BuyIntentData,
+    udt_info: &UDTInfo,
+    dob_selling_index: usize,
+) -> Result<(), Error> {
+    let paid = load_escrowed(udt_info, dob_selling_index)?;
+    let need = utils::get_revocation_refund(witness_data, paid)?;
+    let refund = udt_info.outputs_by_lock(&witness_data.owner_script_hash().into())?;
+    if refund < need {
+        log::error!(
+            "The xUDT is not refunded to the owner, need: {}, actual: {}",
+            need,
+            refund
+        );
+        return Err(Error::CheckXUDT);
+    }
+
+    let forfeited = paid - need;
+    if forfeited != 0 {
+        let received = udt_info.outputs_by_lock(&witness_data.forfeit_script_hash().into())?;
+        if received < forfeited {
+            log::error!(
+                "The forfeited xUDT is not paid, need: {}, actual: {}",
+                forfeited,
+                received
+            );
+            return Err(Error::CheckXUDT);
+        }
+    }
+
+    Ok(())
+}
+
+// When cancelled by the owner, the capacity of the intent goes back in full
+fn check_cancel_capacity(witness_data: &BuyIntentData) -> Result<(), Error> {
+    let owner_script_hash: Hash = witness_data.owner_script_hash().into();
+    let capacity = load_cell_capacity(0, Source::GroupInput)?;
+    let returned = load_owner_capacity(&owner_script_hash)?;
+    if returned < capacity {
+        log::error!(
+            "The capacity of BuyIntent is not returned, need: {}, actual: {}",
+            capacity,
+            returned
+        );
+        return Err(Error::CapacityError);
+    }
+
     Ok(())
 }
 
-fn revocation(witness_data: BuyIntentData, _udt_info: UDTInfo) -> Result<(), Error> {
-    if !(utils::check_since(0, Source::GroupInput, witness_data.expire_since().unpack())?) {
+fn revocation(witness_data: BuyIntentData, udt_info: UDTInfo) -> Result<(), Error> {
+    let owner_script_hash: Hash = witness_data.owner_script_hash().into();
+    let is_cancel = is_owner_authorized(&owner_script_hash)?;
+    if !is_cancel
+        && !(utils::check_since(0, Source::GroupInput, witness_data.expire_since().unpack())?)
+    {
         return Err(Error::CheckScript);
     }
 
@@ -216,8 +564,11 @@ fn revocation(witness_data: BuyIntentData, _udt_info: UDTInfo) -> Result<(), Err
         return Err(Error::CheckScript);
     }
     utils::from_same_tx_hash(dob_selling_index)?;
+    check_xudt_refund(&witness_data, &udt_info, dob_selling_index)?;
+    if is_cancel {
+        check_cancel_capacity(&witness_data)?;
+    }
 
-    let owner_script_hash: Hash = witness_data.owner_script_hash().into();
     let lock_script_hash = load_cell_lock_hash(1, Source::Output)?;
     if owner_script_hash != lock_script_hash {
         log::error!("Revocation failed, not found owner in Output 1");
@@ -231,17 +582,165 @@ fn revocation(witness_data: BuyIntentData, _udt_info: UDTInfo) -> Result<(), Err
     Ok(())
 }
 
+// The other side of an amendment: the new intent in Outputs, or the old one in Inputs
+fn load_amended_intent(
+    accountbook_hash: &Hash,
+    source: Source,
+) -> Result<Option<BuyIntentData>, Error> {
+    let code_hash: Hash = load_script()?.code_hash().into();
+    let indexs = utils::get_indexs(utils::load_type_code_hash, |h| code_hash == h, source);
+    if indexs.len() != 1 {
+        return Ok(None);
+    }
+
+    let args = load_cell_type(indexs[0], source)?
+        .ok_or_else(|| {
+            log::error!("Load BuyIntent type script failed");
+            Error::TxStructure
+        })?
+        .args()
+        .raw_data();
+    if args.len() != HASH_SIZE * 2 || &args[..HASH_SIZE] != accountbook_hash.as_slice() {
+        return Ok(None);
+    }
+    let data = utils::load_buy_intent_data(indexs[0], source)?;
+    if &args[HASH_SIZE..] != Hash::ckb_hash(data.as_slice()).as_slice() {
+        log::error!("Check amended intent data hash failed");
+        return Err(Error::VerifiedData);
+    }
+
+    Ok(Some(data))
+}
+
+// The DobSelling is recreated together with the intent so that they still come from
+// the same transaction, only an installment can be topped up with more xUDT
+fn check_recreated_dob_selling(
+    witness_data: &BuyIntentData,
+    udt_info: &UDTInfo,
+) -> Result<(), Error> {
+    let dob_selling_index = check_input_dob_selling(witness_data.dob_selling_script_hash().into())?;
+    utils::from_same_tx_hash(dob_selling_index)?;
+    let old_escrowed = load_escrowed(udt_info, dob_selling_index)?;
+
+    let dob_selling_hash: Hash = witness_data.dob_selling_script_hash().into();
+    let indexs = utils::get_indexs(
+        load_cell_lock_hash,
+        |h| dob_selling_hash == h,
+        Source::Output,
+    );
+    if indexs.len() != 1 {
+        log::error!("The recreated DobSelling not found in Outputs");
+        return Err(Error::TxStructure);
+    }
+    let new_escrowed = udt_info
+        .outputs
+        .iter()
+        .find_map(|(udt, index)| {
+            if *index == indexs[0] {
+                Some(*udt)
+            } else {
+                None
+            }
+        })
+        .ok_or_else(|| {
+            log::error!("Dob Selling type lock isnot xUDT");
+            Error::CheckXUDT
+        })?;
+
+    let deposit: u128 = witness_data.deposit().unpack();
+    let price = utils::get_full_payment(witness_data)?;
+    if (deposit == 0 && new_escrowed != old_escrowed)
+        || (deposit != 0 && (new_escrowed < old_escrowed || new_escrowed > price))
+    {
+        log::error!(
+            "Incorrect xUDT in the recreated DobSelling: old: {}, new: {}, price: {}",
+            old_escrowed,
+            new_escrowed,
+            price
+        );
+        return Err(Error::CheckXUDT);
+    }
+
+    Ok(())
+}
+
+// The owner updates the expiry, capacity or fill fee of an open intent,
+// the payment moves to a recreated DobSelling
+fn amend(
+    witness_data: BuyIntentData,
+    new_data: BuyIntentData,
+    udt_info: UDTInfo,
+) -> Result<(), Error> {
+    if !is_owner_authorized(&witness_data.owner_script_hash().into())? {
+        log::error!("Amending BuyIntent must be authorized by the owner");
+        return Err(Error::CheckScript);
+    }
+
+    check_recreated_dob_selling(&witness_data, &udt_info)?;
+
+    let expected = witness_data
+        .as_builder()
+        .min_capacity(new_data.min_capacity())
+        .fill_fee(new_data.fill_fee())
+        .expire_since(new_data.expire_since())
+        .build();
+    if expected.as_slice() != new_data.as_slice() {
+        log::error!("Only min_capacity, fill_fee and expire_since can be amended");
+        return Err(Error::VerifiedData);
+    }
+
+    Ok(())
+}
+
+// The args of an intent hash its data, so an installment topped up without other changes
+// is recreated with the same args
+fn top_up(
+    witness_data: BuyIntentData,
+    accountbook_hash: Hash,
+    udt_info: UDTInfo,
+) -> Result<(), Error> {
+    let deposit: u128 = witness_data.deposit().unpack();
+    if deposit == 0 || has_account_book(&accountbook_hash)? {
+        log::error!("Both Inputs and Outputs has Buy Intent, only an installment can be topped up");
+        return Err(Error::TxStructure);
+    }
+    if !is_owner_authorized(&witness_data.owner_script_hash().into())? {
+        log::error!("Topping up BuyIntent must be authorized by the owner");
+        return Err(Error::CheckScript);
+    }
+
+    check_recreated_dob_selling(&witness_data, &udt_info)?;
+    check_min_capacity(&witness_data)
+}
+
+fn is_amended(witness_data: &BuyIntentData, accountbook_hash: &Hash) -> Result<bool, Error> {
+    if has_account_book(accountbook_hash)? {
+        return Ok(false);
+    }
+    Ok(
+        load_amended_intent(accountbook_hash, Source::Input)?.is_some_and(|old_data| {
+            old_data.dob_selling_script_hash() == witness_data.dob_selling_script_hash()
+        }),
+    )
+}
+
 fn program_entry2() -> Result<(), Error> {
-    let is_input = is_input()?;
+    let (is_input, is_output) = load_group()?;
     let (witness_data, accountbook_hash) = load_verified_data(is_input)?;
     let udt_info = utils::UDTInfo::new(witness_data.xudt_script_hash().into())?;
 
-    if is_input {
+    if is_input && is_output {
+        top_up(witness_data, accountbook_hash, udt_info)
+    } else if is_input {
         if has_account_book(&accountbook_hash)? {
-            selling(witness_data, accountbook_hash)
+            selling(witness_data, accountbook_hash, udt_info)
+        } else if let Some(new_data) = load_amended_intent(&accountbook_hash, Source::Output)? {
+            amend(witness_data, new_data, udt_info)
         } else {
             revocation(witness_data, udt_info)
         }
+    } else if is_amended(&witness_data, &accountbook_hash)? {
+        check_min_capacity(&witness_data)
     } else {
         create_intent(witness_data, udt_info)
     }
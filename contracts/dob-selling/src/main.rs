@@ -13,17 +13,20 @@ use alloc::vec::Vec;
 use ckb_std::{
     ckb_constants::Source,
     ckb_types::prelude::{Entity, Reader},
-    error::SysError,
     high_level::{
-        load_cell_data_hash, load_cell_lock_hash, load_cell_type, load_cell_type_hash,
-        load_witness_args, QueryIter,
+        load_cell_data, load_cell_data_hash, load_cell_lock_hash, load_cell_type,
+        load_cell_type_hash, load_script_hash, load_witness_args, QueryIter,
     },
     log,
 };
+use spore_types::spore::{SporeData, SporeDataReader};
 use types::error::SilentBerryError as Error;
-use types::DobSellingData;
+use types::{BuyIntentData, DobSellingData};
 use utils::{load_args_to_hash, Hash};
 
+// Any Spore of the cluster and level can be sold to the intent
+const FLOOR_OFFER_SPORE_DATA_HASH: [u8; 32] = [0u8; 32];
+
 fn load_verified_data() -> Result<DobSellingData, Error> {
     let args = load_args_to_hash()?;
     if args.len() != 1 {
@@ -69,10 +72,45 @@ fn get_spore_cell_index(code_hash: Hash) -> Result<Option<usize>, Error> {
     }
 }
 
+// A floor offer takes any Spore in the cluster and level of the AccountBook
+fn check_floor_spore(spore_index: usize, witness_data: &DobSellingData) -> Result<(), Error> {
+    let account_book_hash: Hash = witness_data.account_book_script_hash().into();
+    let account_book_index = QueryIter::new(load_cell_type_hash, Source::Output)
+        .position(|f| account_book_hash == f)
+        .ok_or_else(|| {
+            log::error!("AccountBook not found in Output");
+            Error::CheckScript
+        })?;
+    let cell_data = utils::load_account_bool_cell_data(account_book_index, Source::Output)?;
+
+    let spore_data = load_cell_data(spore_index, Source::Output)?;
+    SporeDataReader::verify(&spore_data, true)?;
+    let spore_data = SporeData::new_unchecked(spore_data.into());
+
+    let cluster_id: Hash = spore_data.cluster_id().try_into()?;
+    if cluster_id != cell_data.cluster_id() {
+        log::error!("Spore Error, the cluster id does not match the AccountBook");
+        return Err(Error::Spore);
+    }
+    let level: u8 = cell_data.level().into();
+    let spore_level = utils::get_spore_level_by_rule(&spore_data, &cell_data.level_rule())?;
+    if level != spore_level {
+        log::error!(
+            "Spore Error, the level does not match the AccountBook, {}, {}",
+            level,
+            spore_level
+        );
+        return Err(Error::Spore);
+    }
+
+    Ok(())
+}
+
 fn check_spore(spore_index: usize, witness_data: &DobSellingData) -> Result<(), Error> {
     let data_hash: Hash = witness_data.spore_data_hash().into();
-    let hash = load_cell_data_hash(spore_index, Source::Output)?;
-    if data_hash != hash {
+    if data_hash == FLOOR_OFFER_SPORE_DATA_HASH {
+        check_floor_spore(spore_index, witness_data)?;
+    } else if data_hash != load_cell_data_hash(spore_index, Source::Output)? {
         log::error!("Spore Error, SporeData does not match Hash");
         return Err(Error::CheckScript);
     }
@@ -131,13 +169,34 @@ fn revocation(witness_data: DobSellingData) -> Result<(), Error> {
         return Err(Error::CheckScript);
     }
 
+    // All xUDT in the DobSelling goes back to the owner
     let owner_script_hash: Hash = witness_data.owner_script_hash().into();
-    if owner_script_hash != load_cell_lock_hash(0, Source::Output)? {
-        log::error!("Revocation failed, owner hash");
-        return Err(Error::CheckScript);
+    let buy_intent_data = utils::load_buy_intent_data(BUY_INTENT_INDEX, Source::Input)?;
+    let udt_info = utils::UDTInfo::new(buy_intent_data.xudt_script_hash().into())?;
+    let script_hash: Hash = load_script_hash()?.into();
+    let paid = QueryIter::new(load_cell_lock_hash, Source::Input)
+        .position(|hash| script_hash == hash)
+        .and_then(|index| udt_info.input(index))
+        .ok_or_else(|| {
+            log::error!("Revocation failed, DobSelling is not xUDT");
+            Error::CheckXUDT
+        })?;
+    let need = utils::get_revocation_refund(&buy_intent_data, paid)?;
+    let refund = udt_info.outputs_by_lock(&owner_script_hash)?;
+    if refund < need {
+        log::error!(
+            "Revocation failed, xUDT is not refunded to the owner, need: {}, actual: {}",
+            need,
+            refund
+        );
+        return Err(Error::CheckXUDT);
     }
 
-    if load_cell_lock_hash(2, Source::Input) != Err(SysError::IndexOutOfBound) {
+    // Only the cells of the owner can be added, to cancel the intent before it expires
+    if QueryIter::new(load_cell_lock_hash, Source::Input)
+        .skip(BUY_INTENT_INDEX + 1)
+        .any(|hash| owner_script_hash != hash)
+    {
         log::error!("As a revocation , no other contracts can exist for Input");
         return Err(Error::TxStructure);
     }
@@ -145,6 +204,64 @@ fn revocation(witness_data: DobSellingData) -> Result<(), Error> {
     Ok(())
 }
 
+// The BuyIntent paid into this DobSelling, it comes from the same transaction
+fn load_buy_intent(
+    witness_data: &DobSellingData,
+    script_hash: &Hash,
+) -> Result<BuyIntentData, Error> {
+    let buy_intent_code_hash: Hash = witness_data.buy_intent_code_hash().into();
+    let indexs = utils::get_indexs(
+        utils::load_type_code_hash,
+        |h| buy_intent_code_hash == h,
+        Source::Input,
+    );
+    for index in indexs {
+        let buy_intent_data = utils::load_buy_intent_data(index, Source::Input)?;
+        if *script_hash == buy_intent_data.dob_selling_script_hash() {
+            utils::from_same_tx_hash(index)?;
+            return Ok(buy_intent_data);
+        }
+    }
+
+    log::error!("The BuyIntent of this DobSelling not found in Inputs");
+    Err(Error::CheckScript)
+}
+
+// Amending the BuyIntent recreates the DobSelling, the BuyIntent checks the amount
+fn recreate(witness_data: DobSellingData, script_hash: Hash) -> Result<(), Error> {
+    let buy_intent_data = load_buy_intent(&witness_data, &script_hash)?;
+
+    let indexs = utils::get_indexs(load_cell_lock_hash, |h| script_hash == h, Source::Output);
+    if indexs.len() != 1 {
+        log::error!(
+            "Only one DobSelling allowed in Outputs, found: {}",
+            indexs.len()
+        );
+        return Err(Error::TxStructure);
+    }
+
+    // The xUDT can only be topped up
+    let udt_info = utils::UDTInfo::new(buy_intent_data.xudt_script_hash().into())?;
+    let old_udt = QueryIter::new(load_cell_lock_hash, Source::Input)
+        .position(|hash| script_hash == hash)
+        .and_then(|index| udt_info.input(index));
+    let new_udt = udt_info.output(indexs[0]);
+    let (old_udt, new_udt) = old_udt.zip(new_udt).ok_or_else(|| {
+        log::error!("Recreation failed, DobSelling is not xUDT");
+        Error::CheckXUDT
+    })?;
+    if new_udt < old_udt {
+        log::error!(
+            "Recreation failed, xUDT is taken from DobSelling, old: {}, new: {}",
+            old_udt,
+            new_udt
+        );
+        return Err(Error::CheckXUDT);
+    }
+
+    Ok(())
+}
+
 fn program_entry2() -> Result<(), Error> {
     let witness_data = load_verified_data()?;
     let spore_index = get_spore_cell_index(witness_data.spore_code_hash().into())?;
@@ -154,7 +271,12 @@ fn program_entry2() -> Result<(), Error> {
         check_account_book(witness_data.account_book_script_hash().into())?;
         check_buy_intent_code_hash(witness_data.buy_intent_code_hash().into())?;
     } else {
-        revocation(witness_data)?;
+        let script_hash: Hash = load_script_hash()?.into();
+        if QueryIter::new(load_cell_lock_hash, Source::Output).any(|hash| script_hash == hash) {
+            recreate(witness_data, script_hash)?;
+        } else {
+            revocation(witness_data)?;
+        }
     }
     Ok(())
 }
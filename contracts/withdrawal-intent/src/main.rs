@@ -20,7 +20,7 @@ use ckb_std::{
 };
 use spore_types::spore::{SporeData, SporeDataReader};
 use types::{error::SilentBerryError as Error, WithdrawalBuyerUnion};
-use types::{WithdrawalIntentData, WithdrawalSporeInfo};
+use types::{LevelRuleOpt, WithdrawalIntentData, WithdrawalSporeInfo};
 use utils::{is_not_out_of_bound, Hash, UDTInfo, HASH_SIZE};
 
 fn is_input() -> Result<bool, Error> {
@@ -66,7 +66,19 @@ fn load_verified_data(is_input: bool) -> Result<(WithdrawalIntentData, Hash), Er
     Ok((witness_data, args[0].clone()))
 }
 
-fn check_spore(spore_info: WithdrawalSporeInfo) -> Result<(), Error> {
+// The level rule of the AccountBook in CellDeps,
+// the AccountBook trusts the Spore level checked here when withdrawing
+fn load_level_rule(accountbook_hash: &Hash) -> Result<LevelRuleOpt, Error> {
+    let index = QueryIter::new(load_cell_type_hash, Source::CellDep)
+        .position(|script_hash| *accountbook_hash == script_hash)
+        .ok_or_else(|| {
+            log::error!("AccountBook not found in CellDeps");
+            Error::TxStructure
+        })?;
+    Ok(utils::load_account_bool_cell_data(index, Source::CellDep)?.level_rule())
+}
+
+fn check_spore(spore_info: WithdrawalSporeInfo, level_rule: LevelRuleOpt) -> Result<(), Error> {
     let spore_code_hash: Hash = spore_info.spore_code_hash().into();
     let spore_data = {
         let spore_input_index = {
@@ -127,11 +139,12 @@ fn check_spore(spore_info: WithdrawalSporeInfo) -> Result<(), Error> {
 
     // Check Spore Level
     let data_level: u8 = spore_info.spore_level().into();
-    if data_level != utils::get_spore_level(&spore_data)? {
+    let spore_level = utils::get_spore_level_by_rule(&spore_data, &level_rule)?;
+    if data_level != spore_level {
         log::error!(
             "The Spore level being sold is incorrect: {}, {}",
             data_level,
-            utils::get_spore_level(&spore_data)?
+            spore_level
         );
         return Err(Error::Spore);
     }
@@ -154,11 +167,13 @@ fn has_account_book(hash: Hash) -> Result<bool, Error> {
     }
 }
 
-fn create_intent(witness_data: WithdrawalIntentData) -> Result<(), Error> {
+fn create_intent(witness_data: WithdrawalIntentData, accountbook_hash: Hash) -> Result<(), Error> {
     let buyer = witness_data.buyer();
 
     match buyer.to_enum() {
-        WithdrawalBuyerUnion::WithdrawalSporeInfo(spore_info) => check_spore(spore_info),
+        WithdrawalBuyerUnion::WithdrawalSporeInfo(spore_info) => {
+            check_spore(spore_info, load_level_rule(&accountbook_hash)?)
+        }
         WithdrawalBuyerUnion::Byte32(script_hash) => {
             let script_hash: Hash = script_hash.into();
             if !QueryIter::new(load_cell_lock_hash, Source::Input).any(|hash| script_hash == hash) {
@@ -222,7 +237,7 @@ fn program_entry2() -> Result<(), Error> {
             revocation(witness_data)
         }
     } else {
-        create_intent(witness_data)
+        create_intent(witness_data, accountbook_hash)
     }
 }
 
@@ -24,6 +24,7 @@ pub enum SilentBerryError {
     CapacityError,
     ExpireSince,
     AccountBookModified,
+    Coupon,
 }
 
 impl From<ckb_std::error::SysError> for SilentBerryError {
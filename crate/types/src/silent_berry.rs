@@ -182,8 +182,8 @@ impl From<Uint128> for Uint128Opt {
     }
 }
 #[derive(Clone)]
-pub struct BuyIntentData(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for BuyIntentData {
+pub struct Uint128Vec(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Uint128Vec {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -192,72 +192,66 @@ impl ::core::fmt::LowerHex for BuyIntentData {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for BuyIntentData {
+impl ::core::fmt::Debug for Uint128Vec {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for BuyIntentData {
+impl ::core::fmt::Display for Uint128Vec {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(
-            f,
-            "{}: {}",
-            "dob_selling_script_hash",
-            self.dob_selling_script_hash()
-        )?;
-        write!(f, ", {}: {}", "xudt_script_hash", self.xudt_script_hash())?;
-        write!(f, ", {}: {}", "price", self.price())?;
-        write!(f, ", {}: {}", "min_capacity", self.min_capacity())?;
-        write!(f, ", {}: {}", "expire_since", self.expire_since())?;
-        write!(f, ", {}: {}", "owner_script_hash", self.owner_script_hash())?;
-        write!(f, " }}")
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
     }
 }
-impl ::core::default::Default for BuyIntentData {
+impl ::core::default::Default for Uint128Vec {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        BuyIntentData::new_unchecked(v)
+        Uint128Vec::new_unchecked(v)
     }
 }
-impl BuyIntentData {
-    const DEFAULT_VALUE: [u8; 128] = [
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0,
-    ];
-    pub const TOTAL_SIZE: usize = 128;
-    pub const FIELD_SIZES: [usize; 6] = [32, 32, 16, 8, 8, 32];
-    pub const FIELD_COUNT: usize = 6;
-    pub fn dob_selling_script_hash(&self) -> Byte32 {
-        Byte32::new_unchecked(self.0.slice(0..32))
+impl Uint128Vec {
+    const DEFAULT_VALUE: [u8; 4] = [0, 0, 0, 0];
+    pub const ITEM_SIZE: usize = 16;
+    pub fn total_size(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
     }
-    pub fn xudt_script_hash(&self) -> Byte32 {
-        Byte32::new_unchecked(self.0.slice(32..64))
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn price(&self) -> Uint128 {
-        Uint128::new_unchecked(self.0.slice(64..80))
+    pub fn len(&self) -> usize {
+        self.item_count()
     }
-    pub fn min_capacity(&self) -> Uint64 {
-        Uint64::new_unchecked(self.0.slice(80..88))
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
-    pub fn expire_since(&self) -> Uint64 {
-        Uint64::new_unchecked(self.0.slice(88..96))
+    pub fn get(&self, idx: usize) -> Option<Uint128> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
     }
-    pub fn owner_script_hash(&self) -> Byte32 {
-        Byte32::new_unchecked(self.0.slice(96..128))
+    pub fn get_unchecked(&self, idx: usize) -> Uint128 {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        Uint128::new_unchecked(self.0.slice(start..end))
     }
-    pub fn as_reader<'r>(&'r self) -> BuyIntentDataReader<'r> {
-        BuyIntentDataReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> Uint128VecReader<'r> {
+        Uint128VecReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for BuyIntentData {
-    type Builder = BuyIntentDataBuilder;
-    const NAME: &'static str = "BuyIntentData";
+impl molecule::prelude::Entity for Uint128Vec {
+    type Builder = Uint128VecBuilder;
+    const NAME: &'static str = "Uint128Vec";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        BuyIntentData(data)
+        Uint128Vec(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -266,27 +260,21 @@ impl molecule::prelude::Entity for BuyIntentData {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        BuyIntentDataReader::from_slice(slice).map(|reader| reader.to_entity())
+        Uint128VecReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        BuyIntentDataReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        Uint128VecReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder()
-            .dob_selling_script_hash(self.dob_selling_script_hash())
-            .xudt_script_hash(self.xudt_script_hash())
-            .price(self.price())
-            .min_capacity(self.min_capacity())
-            .expire_since(self.expire_since())
-            .owner_script_hash(self.owner_script_hash())
+        Self::new_builder().extend(self.into_iter())
     }
 }
 #[derive(Clone, Copy)]
-pub struct BuyIntentDataReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for BuyIntentDataReader<'r> {
+pub struct Uint128VecReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for Uint128VecReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -295,59 +283,59 @@ impl<'r> ::core::fmt::LowerHex for BuyIntentDataReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for BuyIntentDataReader<'r> {
+impl<'r> ::core::fmt::Debug for Uint128VecReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for BuyIntentDataReader<'r> {
+impl<'r> ::core::fmt::Display for Uint128VecReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(
-            f,
-            "{}: {}",
-            "dob_selling_script_hash",
-            self.dob_selling_script_hash()
-        )?;
-        write!(f, ", {}: {}", "xudt_script_hash", self.xudt_script_hash())?;
-        write!(f, ", {}: {}", "price", self.price())?;
-        write!(f, ", {}: {}", "min_capacity", self.min_capacity())?;
-        write!(f, ", {}: {}", "expire_since", self.expire_since())?;
-        write!(f, ", {}: {}", "owner_script_hash", self.owner_script_hash())?;
-        write!(f, " }}")
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
     }
 }
-impl<'r> BuyIntentDataReader<'r> {
-    pub const TOTAL_SIZE: usize = 128;
-    pub const FIELD_SIZES: [usize; 6] = [32, 32, 16, 8, 8, 32];
-    pub const FIELD_COUNT: usize = 6;
-    pub fn dob_selling_script_hash(&self) -> Byte32Reader<'r> {
-        Byte32Reader::new_unchecked(&self.as_slice()[0..32])
+impl<'r> Uint128VecReader<'r> {
+    pub const ITEM_SIZE: usize = 16;
+    pub fn total_size(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
     }
-    pub fn xudt_script_hash(&self) -> Byte32Reader<'r> {
-        Byte32Reader::new_unchecked(&self.as_slice()[32..64])
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn price(&self) -> Uint128Reader<'r> {
-        Uint128Reader::new_unchecked(&self.as_slice()[64..80])
+    pub fn len(&self) -> usize {
+        self.item_count()
     }
-    pub fn min_capacity(&self) -> Uint64Reader<'r> {
-        Uint64Reader::new_unchecked(&self.as_slice()[80..88])
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
-    pub fn expire_since(&self) -> Uint64Reader<'r> {
-        Uint64Reader::new_unchecked(&self.as_slice()[88..96])
+    pub fn get(&self, idx: usize) -> Option<Uint128Reader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
     }
-    pub fn owner_script_hash(&self) -> Byte32Reader<'r> {
-        Byte32Reader::new_unchecked(&self.as_slice()[96..128])
+    pub fn get_unchecked(&self, idx: usize) -> Uint128Reader<'r> {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        Uint128Reader::new_unchecked(&self.as_slice()[start..end])
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for BuyIntentDataReader<'r> {
-    type Entity = BuyIntentData;
-    const NAME: &'static str = "BuyIntentDataReader";
+impl<'r> molecule::prelude::Reader<'r> for Uint128VecReader<'r> {
+    type Entity = Uint128Vec;
+    const NAME: &'static str = "Uint128VecReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        BuyIntentDataReader(slice)
+        Uint128VecReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -355,75 +343,124 @@ impl<'r> molecule::prelude::Reader<'r> for BuyIntentDataReader<'r> {
     fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
         use molecule::verification_error as ve;
         let slice_len = slice.len();
-        if slice_len != Self::TOTAL_SIZE {
-            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let item_count = molecule::unpack_number(slice) as usize;
+        if item_count == 0 {
+            if slice_len != molecule::NUMBER_SIZE {
+                return ve!(Self, TotalSizeNotMatch, molecule::NUMBER_SIZE, slice_len);
+            }
+            return Ok(());
+        }
+        let total_size = molecule::NUMBER_SIZE + Self::ITEM_SIZE * item_count;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
         }
         Ok(())
     }
 }
 #[derive(Clone, Debug, Default)]
-pub struct BuyIntentDataBuilder {
-    pub(crate) dob_selling_script_hash: Byte32,
-    pub(crate) xudt_script_hash: Byte32,
-    pub(crate) price: Uint128,
-    pub(crate) min_capacity: Uint64,
-    pub(crate) expire_since: Uint64,
-    pub(crate) owner_script_hash: Byte32,
-}
-impl BuyIntentDataBuilder {
-    pub const TOTAL_SIZE: usize = 128;
-    pub const FIELD_SIZES: [usize; 6] = [32, 32, 16, 8, 8, 32];
-    pub const FIELD_COUNT: usize = 6;
-    pub fn dob_selling_script_hash(mut self, v: Byte32) -> Self {
-        self.dob_selling_script_hash = v;
-        self
-    }
-    pub fn xudt_script_hash(mut self, v: Byte32) -> Self {
-        self.xudt_script_hash = v;
-        self
-    }
-    pub fn price(mut self, v: Uint128) -> Self {
-        self.price = v;
+pub struct Uint128VecBuilder(pub(crate) Vec<Uint128>);
+impl Uint128VecBuilder {
+    pub const ITEM_SIZE: usize = 16;
+    pub fn set(mut self, v: Vec<Uint128>) -> Self {
+        self.0 = v;
         self
     }
-    pub fn min_capacity(mut self, v: Uint64) -> Self {
-        self.min_capacity = v;
+    pub fn push(mut self, v: Uint128) -> Self {
+        self.0.push(v);
         self
     }
-    pub fn expire_since(mut self, v: Uint64) -> Self {
-        self.expire_since = v;
+    pub fn extend<T: ::core::iter::IntoIterator<Item = Uint128>>(mut self, iter: T) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
         self
     }
-    pub fn owner_script_hash(mut self, v: Byte32) -> Self {
-        self.owner_script_hash = v;
-        self
+    pub fn replace(&mut self, index: usize, v: Uint128) -> Option<Uint128> {
+        self.0
+            .get_mut(index)
+            .map(|item| ::core::mem::replace(item, v))
     }
 }
-impl molecule::prelude::Builder for BuyIntentDataBuilder {
-    type Entity = BuyIntentData;
-    const NAME: &'static str = "BuyIntentDataBuilder";
+impl molecule::prelude::Builder for Uint128VecBuilder {
+    type Entity = Uint128Vec;
+    const NAME: &'static str = "Uint128VecBuilder";
     fn expected_length(&self) -> usize {
-        Self::TOTAL_SIZE
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.0.len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        writer.write_all(self.dob_selling_script_hash.as_slice())?;
-        writer.write_all(self.xudt_script_hash.as_slice())?;
-        writer.write_all(self.price.as_slice())?;
-        writer.write_all(self.min_capacity.as_slice())?;
-        writer.write_all(self.expire_since.as_slice())?;
-        writer.write_all(self.owner_script_hash.as_slice())?;
+        writer.write_all(&molecule::pack_number(self.0.len() as molecule::Number))?;
+        for inner in &self.0[..] {
+            writer.write_all(inner.as_slice())?;
+        }
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        BuyIntentData::new_unchecked(inner.into())
+        Uint128Vec::new_unchecked(inner.into())
+    }
+}
+pub struct Uint128VecIterator(Uint128Vec, usize, usize);
+impl ::core::iter::Iterator for Uint128VecIterator {
+    type Item = Uint128;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::core::iter::ExactSizeIterator for Uint128VecIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for Uint128Vec {
+    type Item = Uint128;
+    type IntoIter = Uint128VecIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        Uint128VecIterator(self, 0, len)
+    }
+}
+impl<'r> Uint128VecReader<'r> {
+    pub fn iter<'t>(&'t self) -> Uint128VecReaderIterator<'t, 'r> {
+        Uint128VecReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct Uint128VecReaderIterator<'t, 'r>(&'t Uint128VecReader<'r>, usize, usize);
+impl<'t: 'r, 'r> ::core::iter::Iterator for Uint128VecReaderIterator<'t, 'r> {
+    type Item = Uint128Reader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for Uint128VecReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::FromIterator<Uint128> for Uint128Vec {
+    fn from_iter<T: IntoIterator<Item = Uint128>>(iter: T) -> Self {
+        Self::new_builder().extend(iter).build()
     }
 }
 #[derive(Clone)]
-pub struct DobSellingData(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for DobSellingData {
+pub struct Uint128OptVec(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Uint128OptVec {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -432,84 +469,76 @@ impl ::core::fmt::LowerHex for DobSellingData {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for DobSellingData {
+impl ::core::fmt::Debug for Uint128OptVec {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for DobSellingData {
+impl ::core::fmt::Display for Uint128OptVec {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(
-            f,
-            "{}: {}",
-            "account_book_script_hash",
-            self.account_book_script_hash()
-        )?;
-        write!(f, ", {}: {}", "spore_code_hash", self.spore_code_hash())?;
-        write!(f, ", {}: {}", "spore_data_hash", self.spore_data_hash())?;
-        write!(
-            f,
-            ", {}: {}",
-            "buy_intent_code_hash",
-            self.buy_intent_code_hash()
-        )?;
-        write!(f, ", {}: {}", "owner_script_hash", self.owner_script_hash())?;
-        write!(
-            f,
-            ", {}: {}",
-            "spore_lock_script_hash",
-            self.spore_lock_script_hash()
-        )?;
-        write!(f, " }}")
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
     }
 }
-impl ::core::default::Default for DobSellingData {
+impl ::core::default::Default for Uint128OptVec {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        DobSellingData::new_unchecked(v)
+        Uint128OptVec::new_unchecked(v)
     }
 }
-impl DobSellingData {
-    const DEFAULT_VALUE: [u8; 192] = [
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    ];
-    pub const TOTAL_SIZE: usize = 192;
-    pub const FIELD_SIZES: [usize; 6] = [32, 32, 32, 32, 32, 32];
-    pub const FIELD_COUNT: usize = 6;
-    pub fn account_book_script_hash(&self) -> Byte32 {
-        Byte32::new_unchecked(self.0.slice(0..32))
+impl Uint128OptVec {
+    const DEFAULT_VALUE: [u8; 4] = [4, 0, 0, 0];
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn spore_code_hash(&self) -> Byte32 {
-        Byte32::new_unchecked(self.0.slice(32..64))
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
     }
-    pub fn spore_data_hash(&self) -> Byte32 {
-        Byte32::new_unchecked(self.0.slice(64..96))
+    pub fn len(&self) -> usize {
+        self.item_count()
     }
-    pub fn buy_intent_code_hash(&self) -> Byte32 {
-        Byte32::new_unchecked(self.0.slice(96..128))
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
-    pub fn owner_script_hash(&self) -> Byte32 {
-        Byte32::new_unchecked(self.0.slice(128..160))
+    pub fn get(&self, idx: usize) -> Option<Uint128Opt> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
     }
-    pub fn spore_lock_script_hash(&self) -> Byte32 {
-        Byte32::new_unchecked(self.0.slice(160..192))
+    pub fn get_unchecked(&self, idx: usize) -> Uint128Opt {
+        let slice = self.as_slice();
+        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
+        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
+        if idx == self.len() - 1 {
+            Uint128Opt::new_unchecked(self.0.slice(start..))
+        } else {
+            let end_idx = start_idx + molecule::NUMBER_SIZE;
+            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
+            Uint128Opt::new_unchecked(self.0.slice(start..end))
+        }
     }
-    pub fn as_reader<'r>(&'r self) -> DobSellingDataReader<'r> {
-        DobSellingDataReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> Uint128OptVecReader<'r> {
+        Uint128OptVecReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for DobSellingData {
-    type Builder = DobSellingDataBuilder;
-    const NAME: &'static str = "DobSellingData";
+impl molecule::prelude::Entity for Uint128OptVec {
+    type Builder = Uint128OptVecBuilder;
+    const NAME: &'static str = "Uint128OptVec";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        DobSellingData(data)
+        Uint128OptVec(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -518,27 +547,21 @@ impl molecule::prelude::Entity for DobSellingData {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        DobSellingDataReader::from_slice(slice).map(|reader| reader.to_entity())
+        Uint128OptVecReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        DobSellingDataReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        Uint128OptVecReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder()
-            .account_book_script_hash(self.account_book_script_hash())
-            .spore_code_hash(self.spore_code_hash())
-            .spore_data_hash(self.spore_data_hash())
-            .buy_intent_code_hash(self.buy_intent_code_hash())
-            .owner_script_hash(self.owner_script_hash())
-            .spore_lock_script_hash(self.spore_lock_script_hash())
+        Self::new_builder().extend(self.into_iter())
     }
 }
 #[derive(Clone, Copy)]
-pub struct DobSellingDataReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for DobSellingDataReader<'r> {
+pub struct Uint128OptVecReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for Uint128OptVecReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -547,145 +570,242 @@ impl<'r> ::core::fmt::LowerHex for DobSellingDataReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for DobSellingDataReader<'r> {
+impl<'r> ::core::fmt::Debug for Uint128OptVecReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for DobSellingDataReader<'r> {
+impl<'r> ::core::fmt::Display for Uint128OptVecReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(
-            f,
-            "{}: {}",
-            "account_book_script_hash",
-            self.account_book_script_hash()
-        )?;
-        write!(f, ", {}: {}", "spore_code_hash", self.spore_code_hash())?;
-        write!(f, ", {}: {}", "spore_data_hash", self.spore_data_hash())?;
-        write!(
-            f,
-            ", {}: {}",
-            "buy_intent_code_hash",
-            self.buy_intent_code_hash()
-        )?;
-        write!(f, ", {}: {}", "owner_script_hash", self.owner_script_hash())?;
-        write!(
-            f,
-            ", {}: {}",
-            "spore_lock_script_hash",
-            self.spore_lock_script_hash()
-        )?;
-        write!(f, " }}")
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
     }
 }
-impl<'r> DobSellingDataReader<'r> {
-    pub const TOTAL_SIZE: usize = 192;
-    pub const FIELD_SIZES: [usize; 6] = [32, 32, 32, 32, 32, 32];
-    pub const FIELD_COUNT: usize = 6;
-    pub fn account_book_script_hash(&self) -> Byte32Reader<'r> {
-        Byte32Reader::new_unchecked(&self.as_slice()[0..32])
+impl<'r> Uint128OptVecReader<'r> {
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn spore_code_hash(&self) -> Byte32Reader<'r> {
-        Byte32Reader::new_unchecked(&self.as_slice()[32..64])
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
     }
-    pub fn spore_data_hash(&self) -> Byte32Reader<'r> {
-        Byte32Reader::new_unchecked(&self.as_slice()[64..96])
+    pub fn len(&self) -> usize {
+        self.item_count()
     }
-    pub fn buy_intent_code_hash(&self) -> Byte32Reader<'r> {
-        Byte32Reader::new_unchecked(&self.as_slice()[96..128])
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
-    pub fn owner_script_hash(&self) -> Byte32Reader<'r> {
-        Byte32Reader::new_unchecked(&self.as_slice()[128..160])
+    pub fn get(&self, idx: usize) -> Option<Uint128OptReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
     }
-    pub fn spore_lock_script_hash(&self) -> Byte32Reader<'r> {
-        Byte32Reader::new_unchecked(&self.as_slice()[160..192])
+    pub fn get_unchecked(&self, idx: usize) -> Uint128OptReader<'r> {
+        let slice = self.as_slice();
+        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
+        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
+        if idx == self.len() - 1 {
+            Uint128OptReader::new_unchecked(&self.as_slice()[start..])
+        } else {
+            let end_idx = start_idx + molecule::NUMBER_SIZE;
+            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
+            Uint128OptReader::new_unchecked(&self.as_slice()[start..end])
+        }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for DobSellingDataReader<'r> {
-    type Entity = DobSellingData;
-    const NAME: &'static str = "DobSellingDataReader";
+impl<'r> molecule::prelude::Reader<'r> for Uint128OptVecReader<'r> {
+    type Entity = Uint128OptVec;
+    const NAME: &'static str = "Uint128OptVecReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        DobSellingDataReader(slice)
+        Uint128OptVecReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
     }
-    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
         use molecule::verification_error as ve;
         let slice_len = slice.len();
-        if slice_len != Self::TOTAL_SIZE {
-            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(
+                Self,
+                TotalSizeNotMatch,
+                molecule::NUMBER_SIZE * 2,
+                slice_len
+            );
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        for pair in offsets.windows(2) {
+            let start = pair[0];
+            let end = pair[1];
+            Uint128OptReader::verify(&slice[start..end], compatible)?;
         }
         Ok(())
     }
 }
 #[derive(Clone, Debug, Default)]
-pub struct DobSellingDataBuilder {
-    pub(crate) account_book_script_hash: Byte32,
-    pub(crate) spore_code_hash: Byte32,
-    pub(crate) spore_data_hash: Byte32,
-    pub(crate) buy_intent_code_hash: Byte32,
-    pub(crate) owner_script_hash: Byte32,
-    pub(crate) spore_lock_script_hash: Byte32,
-}
-impl DobSellingDataBuilder {
-    pub const TOTAL_SIZE: usize = 192;
-    pub const FIELD_SIZES: [usize; 6] = [32, 32, 32, 32, 32, 32];
-    pub const FIELD_COUNT: usize = 6;
-    pub fn account_book_script_hash(mut self, v: Byte32) -> Self {
-        self.account_book_script_hash = v;
-        self
-    }
-    pub fn spore_code_hash(mut self, v: Byte32) -> Self {
-        self.spore_code_hash = v;
-        self
-    }
-    pub fn spore_data_hash(mut self, v: Byte32) -> Self {
-        self.spore_data_hash = v;
+pub struct Uint128OptVecBuilder(pub(crate) Vec<Uint128Opt>);
+impl Uint128OptVecBuilder {
+    pub fn set(mut self, v: Vec<Uint128Opt>) -> Self {
+        self.0 = v;
         self
     }
-    pub fn buy_intent_code_hash(mut self, v: Byte32) -> Self {
-        self.buy_intent_code_hash = v;
+    pub fn push(mut self, v: Uint128Opt) -> Self {
+        self.0.push(v);
         self
     }
-    pub fn owner_script_hash(mut self, v: Byte32) -> Self {
-        self.owner_script_hash = v;
+    pub fn extend<T: ::core::iter::IntoIterator<Item = Uint128Opt>>(mut self, iter: T) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
         self
     }
-    pub fn spore_lock_script_hash(mut self, v: Byte32) -> Self {
-        self.spore_lock_script_hash = v;
-        self
+    pub fn replace(&mut self, index: usize, v: Uint128Opt) -> Option<Uint128Opt> {
+        self.0
+            .get_mut(index)
+            .map(|item| ::core::mem::replace(item, v))
     }
 }
-impl molecule::prelude::Builder for DobSellingDataBuilder {
-    type Entity = DobSellingData;
-    const NAME: &'static str = "DobSellingDataBuilder";
+impl molecule::prelude::Builder for Uint128OptVecBuilder {
+    type Entity = Uint128OptVec;
+    const NAME: &'static str = "Uint128OptVecBuilder";
     fn expected_length(&self) -> usize {
-        Self::TOTAL_SIZE
+        molecule::NUMBER_SIZE * (self.0.len() + 1)
+            + self
+                .0
+                .iter()
+                .map(|inner| inner.as_slice().len())
+                .sum::<usize>()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        writer.write_all(self.account_book_script_hash.as_slice())?;
-        writer.write_all(self.spore_code_hash.as_slice())?;
-        writer.write_all(self.spore_data_hash.as_slice())?;
-        writer.write_all(self.buy_intent_code_hash.as_slice())?;
-        writer.write_all(self.owner_script_hash.as_slice())?;
-        writer.write_all(self.spore_lock_script_hash.as_slice())?;
+        let item_count = self.0.len();
+        if item_count == 0 {
+            writer.write_all(&molecule::pack_number(
+                molecule::NUMBER_SIZE as molecule::Number,
+            ))?;
+        } else {
+            let (total_size, offsets) = self.0.iter().fold(
+                (
+                    molecule::NUMBER_SIZE * (item_count + 1),
+                    Vec::with_capacity(item_count),
+                ),
+                |(start, mut offsets), inner| {
+                    offsets.push(start);
+                    (start + inner.as_slice().len(), offsets)
+                },
+            );
+            writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+            for offset in offsets.into_iter() {
+                writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+            }
+            for inner in self.0.iter() {
+                writer.write_all(inner.as_slice())?;
+            }
+        }
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        DobSellingData::new_unchecked(inner.into())
+        Uint128OptVec::new_unchecked(inner.into())
+    }
+}
+pub struct Uint128OptVecIterator(Uint128OptVec, usize, usize);
+impl ::core::iter::Iterator for Uint128OptVecIterator {
+    type Item = Uint128Opt;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::core::iter::ExactSizeIterator for Uint128OptVecIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for Uint128OptVec {
+    type Item = Uint128Opt;
+    type IntoIter = Uint128OptVecIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        Uint128OptVecIterator(self, 0, len)
+    }
+}
+impl<'r> Uint128OptVecReader<'r> {
+    pub fn iter<'t>(&'t self) -> Uint128OptVecReaderIterator<'t, 'r> {
+        Uint128OptVecReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct Uint128OptVecReaderIterator<'t, 'r>(&'t Uint128OptVecReader<'r>, usize, usize);
+impl<'t: 'r, 'r> ::core::iter::Iterator for Uint128OptVecReaderIterator<'t, 'r> {
+    type Item = Uint128OptReader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for Uint128OptVecReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::FromIterator<Uint128Opt> for Uint128OptVec {
+    fn from_iter<T: IntoIterator<Item = Uint128Opt>>(iter: T) -> Self {
+        Self::new_builder().extend(iter).build()
     }
 }
 #[derive(Clone)]
-pub struct WithdrawalSporeInfo(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for WithdrawalSporeInfo {
+pub struct Byte65(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Byte65 {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -694,197 +814,4862 @@ impl ::core::fmt::LowerHex for WithdrawalSporeInfo {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for WithdrawalSporeInfo {
+impl ::core::fmt::Debug for Byte65 {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for WithdrawalSporeInfo {
+impl ::core::fmt::Display for Byte65 {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "spore_code_hash", self.spore_code_hash())?;
-        write!(f, ", {}: {}", "spore_level", self.spore_level())?;
-        write!(f, ", {}: {}", "spore_id", self.spore_id())?;
-        write!(f, ", {}: {}", "cluster_id", self.cluster_id())?;
-        write!(f, " }}")
+        use molecule::hex_string;
+        let raw_data = hex_string(&self.raw_data());
+        write!(f, "{}(0x{})", Self::NAME, raw_data)
     }
 }
-impl ::core::default::Default for WithdrawalSporeInfo {
+impl ::core::default::Default for Byte65 {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        WithdrawalSporeInfo::new_unchecked(v)
+        Byte65::new_unchecked(v)
     }
 }
-impl WithdrawalSporeInfo {
-    const DEFAULT_VALUE: [u8; 97] = [
+impl Byte65 {
+    const DEFAULT_VALUE: [u8; 65] = [
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0,
     ];
-    pub const TOTAL_SIZE: usize = 97;
-    pub const FIELD_SIZES: [usize; 4] = [32, 1, 32, 32];
-    pub const FIELD_COUNT: usize = 4;
-    pub fn spore_code_hash(&self) -> Byte32 {
-        Byte32::new_unchecked(self.0.slice(0..32))
+    pub const TOTAL_SIZE: usize = 65;
+    pub const ITEM_SIZE: usize = 1;
+    pub const ITEM_COUNT: usize = 65;
+    pub fn nth0(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(0..1))
     }
-    pub fn spore_level(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(32..33))
+    pub fn nth1(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(1..2))
     }
-    pub fn spore_id(&self) -> Byte32 {
-        Byte32::new_unchecked(self.0.slice(33..65))
+    pub fn nth2(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(2..3))
     }
-    pub fn cluster_id(&self) -> Byte32 {
-        Byte32::new_unchecked(self.0.slice(65..97))
+    pub fn nth3(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(3..4))
     }
-    pub fn as_reader<'r>(&'r self) -> WithdrawalSporeInfoReader<'r> {
-        WithdrawalSporeInfoReader::new_unchecked(self.as_slice())
+    pub fn nth4(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(4..5))
     }
-}
-impl molecule::prelude::Entity for WithdrawalSporeInfo {
-    type Builder = WithdrawalSporeInfoBuilder;
-    const NAME: &'static str = "WithdrawalSporeInfo";
-    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        WithdrawalSporeInfo(data)
+    pub fn nth5(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(5..6))
     }
-    fn as_bytes(&self) -> molecule::bytes::Bytes {
-        self.0.clone()
+    pub fn nth6(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(6..7))
     }
-    fn as_slice(&self) -> &[u8] {
-        &self.0[..]
+    pub fn nth7(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(7..8))
     }
-    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        WithdrawalSporeInfoReader::from_slice(slice).map(|reader| reader.to_entity())
+    pub fn nth8(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(8..9))
     }
-    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        WithdrawalSporeInfoReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    pub fn nth9(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(9..10))
     }
-    fn new_builder() -> Self::Builder {
-        ::core::default::Default::default()
+    pub fn nth10(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(10..11))
     }
-    fn as_builder(self) -> Self::Builder {
-        Self::new_builder()
-            .spore_code_hash(self.spore_code_hash())
-            .spore_level(self.spore_level())
-            .spore_id(self.spore_id())
-            .cluster_id(self.cluster_id())
+    pub fn nth11(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(11..12))
     }
-}
-#[derive(Clone, Copy)]
-pub struct WithdrawalSporeInfoReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for WithdrawalSporeInfoReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        if f.alternate() {
-            write!(f, "0x")?;
-        }
-        write!(f, "{}", hex_string(self.as_slice()))
+    pub fn nth12(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(12..13))
     }
-}
-impl<'r> ::core::fmt::Debug for WithdrawalSporeInfoReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:#x})", Self::NAME, self)
+    pub fn nth13(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(13..14))
     }
-}
-impl<'r> ::core::fmt::Display for WithdrawalSporeInfoReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "spore_code_hash", self.spore_code_hash())?;
-        write!(f, ", {}: {}", "spore_level", self.spore_level())?;
-        write!(f, ", {}: {}", "spore_id", self.spore_id())?;
-        write!(f, ", {}: {}", "cluster_id", self.cluster_id())?;
-        write!(f, " }}")
+    pub fn nth14(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(14..15))
     }
-}
-impl<'r> WithdrawalSporeInfoReader<'r> {
-    pub const TOTAL_SIZE: usize = 97;
-    pub const FIELD_SIZES: [usize; 4] = [32, 1, 32, 32];
-    pub const FIELD_COUNT: usize = 4;
-    pub fn spore_code_hash(&self) -> Byte32Reader<'r> {
-        Byte32Reader::new_unchecked(&self.as_slice()[0..32])
+    pub fn nth15(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(15..16))
     }
-    pub fn spore_level(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[32..33])
+    pub fn nth16(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(16..17))
     }
-    pub fn spore_id(&self) -> Byte32Reader<'r> {
-        Byte32Reader::new_unchecked(&self.as_slice()[33..65])
+    pub fn nth17(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(17..18))
     }
-    pub fn cluster_id(&self) -> Byte32Reader<'r> {
-        Byte32Reader::new_unchecked(&self.as_slice()[65..97])
+    pub fn nth18(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(18..19))
     }
-}
-impl<'r> molecule::prelude::Reader<'r> for WithdrawalSporeInfoReader<'r> {
-    type Entity = WithdrawalSporeInfo;
-    const NAME: &'static str = "WithdrawalSporeInfoReader";
-    fn to_entity(&self) -> Self::Entity {
-        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    pub fn nth19(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(19..20))
     }
-    fn new_unchecked(slice: &'r [u8]) -> Self {
+    pub fn nth20(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(20..21))
+    }
+    pub fn nth21(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(21..22))
+    }
+    pub fn nth22(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(22..23))
+    }
+    pub fn nth23(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(23..24))
+    }
+    pub fn nth24(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(24..25))
+    }
+    pub fn nth25(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(25..26))
+    }
+    pub fn nth26(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(26..27))
+    }
+    pub fn nth27(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(27..28))
+    }
+    pub fn nth28(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(28..29))
+    }
+    pub fn nth29(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(29..30))
+    }
+    pub fn nth30(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(30..31))
+    }
+    pub fn nth31(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(31..32))
+    }
+    pub fn nth32(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(32..33))
+    }
+    pub fn nth33(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(33..34))
+    }
+    pub fn nth34(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(34..35))
+    }
+    pub fn nth35(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(35..36))
+    }
+    pub fn nth36(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(36..37))
+    }
+    pub fn nth37(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(37..38))
+    }
+    pub fn nth38(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(38..39))
+    }
+    pub fn nth39(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(39..40))
+    }
+    pub fn nth40(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(40..41))
+    }
+    pub fn nth41(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(41..42))
+    }
+    pub fn nth42(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(42..43))
+    }
+    pub fn nth43(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(43..44))
+    }
+    pub fn nth44(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(44..45))
+    }
+    pub fn nth45(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(45..46))
+    }
+    pub fn nth46(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(46..47))
+    }
+    pub fn nth47(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(47..48))
+    }
+    pub fn nth48(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(48..49))
+    }
+    pub fn nth49(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(49..50))
+    }
+    pub fn nth50(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(50..51))
+    }
+    pub fn nth51(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(51..52))
+    }
+    pub fn nth52(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(52..53))
+    }
+    pub fn nth53(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(53..54))
+    }
+    pub fn nth54(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(54..55))
+    }
+    pub fn nth55(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(55..56))
+    }
+    pub fn nth56(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(56..57))
+    }
+    pub fn nth57(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(57..58))
+    }
+    pub fn nth58(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(58..59))
+    }
+    pub fn nth59(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(59..60))
+    }
+    pub fn nth60(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(60..61))
+    }
+    pub fn nth61(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(61..62))
+    }
+    pub fn nth62(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(62..63))
+    }
+    pub fn nth63(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(63..64))
+    }
+    pub fn nth64(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(64..65))
+    }
+    pub fn raw_data(&self) -> molecule::bytes::Bytes {
+        self.as_bytes()
+    }
+    pub fn as_reader<'r>(&'r self) -> Byte65Reader<'r> {
+        Byte65Reader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for Byte65 {
+    type Builder = Byte65Builder;
+    const NAME: &'static str = "Byte65";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        Byte65(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        Byte65Reader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        Byte65Reader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().set([
+            self.nth0(),
+            self.nth1(),
+            self.nth2(),
+            self.nth3(),
+            self.nth4(),
+            self.nth5(),
+            self.nth6(),
+            self.nth7(),
+            self.nth8(),
+            self.nth9(),
+            self.nth10(),
+            self.nth11(),
+            self.nth12(),
+            self.nth13(),
+            self.nth14(),
+            self.nth15(),
+            self.nth16(),
+            self.nth17(),
+            self.nth18(),
+            self.nth19(),
+            self.nth20(),
+            self.nth21(),
+            self.nth22(),
+            self.nth23(),
+            self.nth24(),
+            self.nth25(),
+            self.nth26(),
+            self.nth27(),
+            self.nth28(),
+            self.nth29(),
+            self.nth30(),
+            self.nth31(),
+            self.nth32(),
+            self.nth33(),
+            self.nth34(),
+            self.nth35(),
+            self.nth36(),
+            self.nth37(),
+            self.nth38(),
+            self.nth39(),
+            self.nth40(),
+            self.nth41(),
+            self.nth42(),
+            self.nth43(),
+            self.nth44(),
+            self.nth45(),
+            self.nth46(),
+            self.nth47(),
+            self.nth48(),
+            self.nth49(),
+            self.nth50(),
+            self.nth51(),
+            self.nth52(),
+            self.nth53(),
+            self.nth54(),
+            self.nth55(),
+            self.nth56(),
+            self.nth57(),
+            self.nth58(),
+            self.nth59(),
+            self.nth60(),
+            self.nth61(),
+            self.nth62(),
+            self.nth63(),
+            self.nth64(),
+        ])
+    }
+}
+#[derive(Clone, Copy)]
+pub struct Byte65Reader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for Byte65Reader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for Byte65Reader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for Byte65Reader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        let raw_data = hex_string(&self.raw_data());
+        write!(f, "{}(0x{})", Self::NAME, raw_data)
+    }
+}
+impl<'r> Byte65Reader<'r> {
+    pub const TOTAL_SIZE: usize = 65;
+    pub const ITEM_SIZE: usize = 1;
+    pub const ITEM_COUNT: usize = 65;
+    pub fn nth0(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[0..1])
+    }
+    pub fn nth1(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[1..2])
+    }
+    pub fn nth2(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[2..3])
+    }
+    pub fn nth3(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[3..4])
+    }
+    pub fn nth4(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[4..5])
+    }
+    pub fn nth5(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[5..6])
+    }
+    pub fn nth6(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[6..7])
+    }
+    pub fn nth7(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[7..8])
+    }
+    pub fn nth8(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[8..9])
+    }
+    pub fn nth9(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[9..10])
+    }
+    pub fn nth10(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[10..11])
+    }
+    pub fn nth11(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[11..12])
+    }
+    pub fn nth12(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[12..13])
+    }
+    pub fn nth13(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[13..14])
+    }
+    pub fn nth14(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[14..15])
+    }
+    pub fn nth15(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[15..16])
+    }
+    pub fn nth16(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[16..17])
+    }
+    pub fn nth17(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[17..18])
+    }
+    pub fn nth18(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[18..19])
+    }
+    pub fn nth19(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[19..20])
+    }
+    pub fn nth20(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[20..21])
+    }
+    pub fn nth21(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[21..22])
+    }
+    pub fn nth22(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[22..23])
+    }
+    pub fn nth23(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[23..24])
+    }
+    pub fn nth24(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[24..25])
+    }
+    pub fn nth25(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[25..26])
+    }
+    pub fn nth26(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[26..27])
+    }
+    pub fn nth27(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[27..28])
+    }
+    pub fn nth28(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[28..29])
+    }
+    pub fn nth29(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[29..30])
+    }
+    pub fn nth30(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[30..31])
+    }
+    pub fn nth31(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[31..32])
+    }
+    pub fn nth32(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[32..33])
+    }
+    pub fn nth33(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[33..34])
+    }
+    pub fn nth34(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[34..35])
+    }
+    pub fn nth35(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[35..36])
+    }
+    pub fn nth36(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[36..37])
+    }
+    pub fn nth37(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[37..38])
+    }
+    pub fn nth38(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[38..39])
+    }
+    pub fn nth39(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[39..40])
+    }
+    pub fn nth40(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[40..41])
+    }
+    pub fn nth41(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[41..42])
+    }
+    pub fn nth42(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[42..43])
+    }
+    pub fn nth43(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[43..44])
+    }
+    pub fn nth44(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[44..45])
+    }
+    pub fn nth45(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[45..46])
+    }
+    pub fn nth46(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[46..47])
+    }
+    pub fn nth47(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[47..48])
+    }
+    pub fn nth48(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[48..49])
+    }
+    pub fn nth49(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[49..50])
+    }
+    pub fn nth50(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[50..51])
+    }
+    pub fn nth51(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[51..52])
+    }
+    pub fn nth52(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[52..53])
+    }
+    pub fn nth53(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[53..54])
+    }
+    pub fn nth54(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[54..55])
+    }
+    pub fn nth55(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[55..56])
+    }
+    pub fn nth56(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[56..57])
+    }
+    pub fn nth57(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[57..58])
+    }
+    pub fn nth58(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[58..59])
+    }
+    pub fn nth59(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[59..60])
+    }
+    pub fn nth60(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[60..61])
+    }
+    pub fn nth61(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[61..62])
+    }
+    pub fn nth62(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[62..63])
+    }
+    pub fn nth63(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[63..64])
+    }
+    pub fn nth64(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[64..65])
+    }
+    pub fn raw_data(&self) -> &'r [u8] {
+        self.as_slice()
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for Byte65Reader<'r> {
+    type Entity = Byte65;
+    const NAME: &'static str = "Byte65Reader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        Byte65Reader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+pub struct Byte65Builder(pub(crate) [Byte; 65]);
+impl ::core::fmt::Debug for Byte65Builder {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:?})", Self::NAME, &self.0[..])
+    }
+}
+impl ::core::default::Default for Byte65Builder {
+    fn default() -> Self {
+        Byte65Builder([
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+        ])
+    }
+}
+impl Byte65Builder {
+    pub const TOTAL_SIZE: usize = 65;
+    pub const ITEM_SIZE: usize = 1;
+    pub const ITEM_COUNT: usize = 65;
+    pub fn set(mut self, v: [Byte; 65]) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn nth0(mut self, v: Byte) -> Self {
+        self.0[0] = v;
+        self
+    }
+    pub fn nth1(mut self, v: Byte) -> Self {
+        self.0[1] = v;
+        self
+    }
+    pub fn nth2(mut self, v: Byte) -> Self {
+        self.0[2] = v;
+        self
+    }
+    pub fn nth3(mut self, v: Byte) -> Self {
+        self.0[3] = v;
+        self
+    }
+    pub fn nth4(mut self, v: Byte) -> Self {
+        self.0[4] = v;
+        self
+    }
+    pub fn nth5(mut self, v: Byte) -> Self {
+        self.0[5] = v;
+        self
+    }
+    pub fn nth6(mut self, v: Byte) -> Self {
+        self.0[6] = v;
+        self
+    }
+    pub fn nth7(mut self, v: Byte) -> Self {
+        self.0[7] = v;
+        self
+    }
+    pub fn nth8(mut self, v: Byte) -> Self {
+        self.0[8] = v;
+        self
+    }
+    pub fn nth9(mut self, v: Byte) -> Self {
+        self.0[9] = v;
+        self
+    }
+    pub fn nth10(mut self, v: Byte) -> Self {
+        self.0[10] = v;
+        self
+    }
+    pub fn nth11(mut self, v: Byte) -> Self {
+        self.0[11] = v;
+        self
+    }
+    pub fn nth12(mut self, v: Byte) -> Self {
+        self.0[12] = v;
+        self
+    }
+    pub fn nth13(mut self, v: Byte) -> Self {
+        self.0[13] = v;
+        self
+    }
+    pub fn nth14(mut self, v: Byte) -> Self {
+        self.0[14] = v;
+        self
+    }
+    pub fn nth15(mut self, v: Byte) -> Self {
+        self.0[15] = v;
+        self
+    }
+    pub fn nth16(mut self, v: Byte) -> Self {
+        self.0[16] = v;
+        self
+    }
+    pub fn nth17(mut self, v: Byte) -> Self {
+        self.0[17] = v;
+        self
+    }
+    pub fn nth18(mut self, v: Byte) -> Self {
+        self.0[18] = v;
+        self
+    }
+    pub fn nth19(mut self, v: Byte) -> Self {
+        self.0[19] = v;
+        self
+    }
+    pub fn nth20(mut self, v: Byte) -> Self {
+        self.0[20] = v;
+        self
+    }
+    pub fn nth21(mut self, v: Byte) -> Self {
+        self.0[21] = v;
+        self
+    }
+    pub fn nth22(mut self, v: Byte) -> Self {
+        self.0[22] = v;
+        self
+    }
+    pub fn nth23(mut self, v: Byte) -> Self {
+        self.0[23] = v;
+        self
+    }
+    pub fn nth24(mut self, v: Byte) -> Self {
+        self.0[24] = v;
+        self
+    }
+    pub fn nth25(mut self, v: Byte) -> Self {
+        self.0[25] = v;
+        self
+    }
+    pub fn nth26(mut self, v: Byte) -> Self {
+        self.0[26] = v;
+        self
+    }
+    pub fn nth27(mut self, v: Byte) -> Self {
+        self.0[27] = v;
+        self
+    }
+    pub fn nth28(mut self, v: Byte) -> Self {
+        self.0[28] = v;
+        self
+    }
+    pub fn nth29(mut self, v: Byte) -> Self {
+        self.0[29] = v;
+        self
+    }
+    pub fn nth30(mut self, v: Byte) -> Self {
+        self.0[30] = v;
+        self
+    }
+    pub fn nth31(mut self, v: Byte) -> Self {
+        self.0[31] = v;
+        self
+    }
+    pub fn nth32(mut self, v: Byte) -> Self {
+        self.0[32] = v;
+        self
+    }
+    pub fn nth33(mut self, v: Byte) -> Self {
+        self.0[33] = v;
+        self
+    }
+    pub fn nth34(mut self, v: Byte) -> Self {
+        self.0[34] = v;
+        self
+    }
+    pub fn nth35(mut self, v: Byte) -> Self {
+        self.0[35] = v;
+        self
+    }
+    pub fn nth36(mut self, v: Byte) -> Self {
+        self.0[36] = v;
+        self
+    }
+    pub fn nth37(mut self, v: Byte) -> Self {
+        self.0[37] = v;
+        self
+    }
+    pub fn nth38(mut self, v: Byte) -> Self {
+        self.0[38] = v;
+        self
+    }
+    pub fn nth39(mut self, v: Byte) -> Self {
+        self.0[39] = v;
+        self
+    }
+    pub fn nth40(mut self, v: Byte) -> Self {
+        self.0[40] = v;
+        self
+    }
+    pub fn nth41(mut self, v: Byte) -> Self {
+        self.0[41] = v;
+        self
+    }
+    pub fn nth42(mut self, v: Byte) -> Self {
+        self.0[42] = v;
+        self
+    }
+    pub fn nth43(mut self, v: Byte) -> Self {
+        self.0[43] = v;
+        self
+    }
+    pub fn nth44(mut self, v: Byte) -> Self {
+        self.0[44] = v;
+        self
+    }
+    pub fn nth45(mut self, v: Byte) -> Self {
+        self.0[45] = v;
+        self
+    }
+    pub fn nth46(mut self, v: Byte) -> Self {
+        self.0[46] = v;
+        self
+    }
+    pub fn nth47(mut self, v: Byte) -> Self {
+        self.0[47] = v;
+        self
+    }
+    pub fn nth48(mut self, v: Byte) -> Self {
+        self.0[48] = v;
+        self
+    }
+    pub fn nth49(mut self, v: Byte) -> Self {
+        self.0[49] = v;
+        self
+    }
+    pub fn nth50(mut self, v: Byte) -> Self {
+        self.0[50] = v;
+        self
+    }
+    pub fn nth51(mut self, v: Byte) -> Self {
+        self.0[51] = v;
+        self
+    }
+    pub fn nth52(mut self, v: Byte) -> Self {
+        self.0[52] = v;
+        self
+    }
+    pub fn nth53(mut self, v: Byte) -> Self {
+        self.0[53] = v;
+        self
+    }
+    pub fn nth54(mut self, v: Byte) -> Self {
+        self.0[54] = v;
+        self
+    }
+    pub fn nth55(mut self, v: Byte) -> Self {
+        self.0[55] = v;
+        self
+    }
+    pub fn nth56(mut self, v: Byte) -> Self {
+        self.0[56] = v;
+        self
+    }
+    pub fn nth57(mut self, v: Byte) -> Self {
+        self.0[57] = v;
+        self
+    }
+    pub fn nth58(mut self, v: Byte) -> Self {
+        self.0[58] = v;
+        self
+    }
+    pub fn nth59(mut self, v: Byte) -> Self {
+        self.0[59] = v;
+        self
+    }
+    pub fn nth60(mut self, v: Byte) -> Self {
+        self.0[60] = v;
+        self
+    }
+    pub fn nth61(mut self, v: Byte) -> Self {
+        self.0[61] = v;
+        self
+    }
+    pub fn nth62(mut self, v: Byte) -> Self {
+        self.0[62] = v;
+        self
+    }
+    pub fn nth63(mut self, v: Byte) -> Self {
+        self.0[63] = v;
+        self
+    }
+    pub fn nth64(mut self, v: Byte) -> Self {
+        self.0[64] = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for Byte65Builder {
+    type Entity = Byte65;
+    const NAME: &'static str = "Byte65Builder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(self.0[0].as_slice())?;
+        writer.write_all(self.0[1].as_slice())?;
+        writer.write_all(self.0[2].as_slice())?;
+        writer.write_all(self.0[3].as_slice())?;
+        writer.write_all(self.0[4].as_slice())?;
+        writer.write_all(self.0[5].as_slice())?;
+        writer.write_all(self.0[6].as_slice())?;
+        writer.write_all(self.0[7].as_slice())?;
+        writer.write_all(self.0[8].as_slice())?;
+        writer.write_all(self.0[9].as_slice())?;
+        writer.write_all(self.0[10].as_slice())?;
+        writer.write_all(self.0[11].as_slice())?;
+        writer.write_all(self.0[12].as_slice())?;
+        writer.write_all(self.0[13].as_slice())?;
+        writer.write_all(self.0[14].as_slice())?;
+        writer.write_all(self.0[15].as_slice())?;
+        writer.write_all(self.0[16].as_slice())?;
+        writer.write_all(self.0[17].as_slice())?;
+        writer.write_all(self.0[18].as_slice())?;
+        writer.write_all(self.0[19].as_slice())?;
+        writer.write_all(self.0[20].as_slice())?;
+        writer.write_all(self.0[21].as_slice())?;
+        writer.write_all(self.0[22].as_slice())?;
+        writer.write_all(self.0[23].as_slice())?;
+        writer.write_all(self.0[24].as_slice())?;
+        writer.write_all(self.0[25].as_slice())?;
+        writer.write_all(self.0[26].as_slice())?;
+        writer.write_all(self.0[27].as_slice())?;
+        writer.write_all(self.0[28].as_slice())?;
+        writer.write_all(self.0[29].as_slice())?;
+        writer.write_all(self.0[30].as_slice())?;
+        writer.write_all(self.0[31].as_slice())?;
+        writer.write_all(self.0[32].as_slice())?;
+        writer.write_all(self.0[33].as_slice())?;
+        writer.write_all(self.0[34].as_slice())?;
+        writer.write_all(self.0[35].as_slice())?;
+        writer.write_all(self.0[36].as_slice())?;
+        writer.write_all(self.0[37].as_slice())?;
+        writer.write_all(self.0[38].as_slice())?;
+        writer.write_all(self.0[39].as_slice())?;
+        writer.write_all(self.0[40].as_slice())?;
+        writer.write_all(self.0[41].as_slice())?;
+        writer.write_all(self.0[42].as_slice())?;
+        writer.write_all(self.0[43].as_slice())?;
+        writer.write_all(self.0[44].as_slice())?;
+        writer.write_all(self.0[45].as_slice())?;
+        writer.write_all(self.0[46].as_slice())?;
+        writer.write_all(self.0[47].as_slice())?;
+        writer.write_all(self.0[48].as_slice())?;
+        writer.write_all(self.0[49].as_slice())?;
+        writer.write_all(self.0[50].as_slice())?;
+        writer.write_all(self.0[51].as_slice())?;
+        writer.write_all(self.0[52].as_slice())?;
+        writer.write_all(self.0[53].as_slice())?;
+        writer.write_all(self.0[54].as_slice())?;
+        writer.write_all(self.0[55].as_slice())?;
+        writer.write_all(self.0[56].as_slice())?;
+        writer.write_all(self.0[57].as_slice())?;
+        writer.write_all(self.0[58].as_slice())?;
+        writer.write_all(self.0[59].as_slice())?;
+        writer.write_all(self.0[60].as_slice())?;
+        writer.write_all(self.0[61].as_slice())?;
+        writer.write_all(self.0[62].as_slice())?;
+        writer.write_all(self.0[63].as_slice())?;
+        writer.write_all(self.0[64].as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        Byte65::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct Coupon(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Coupon {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for Coupon {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for Coupon {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "discount", self.discount())?;
+        write!(
+            f,
+            ", {}: {}",
+            "account_book_script_hash",
+            self.account_book_script_hash()
+        )?;
+        write!(f, ", {}: {}", "expire_since", self.expire_since())?;
+        write!(f, ", {}: {}", "nonce", self.nonce())?;
+        write!(f, ", {}: {}", "signature", self.signature())?;
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for Coupon {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        Coupon::new_unchecked(v)
+    }
+}
+impl Coupon {
+    const DEFAULT_VALUE: [u8; 153] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0,
+    ];
+    pub const TOTAL_SIZE: usize = 153;
+    pub const FIELD_SIZES: [usize; 5] = [16, 32, 8, 32, 65];
+    pub const FIELD_COUNT: usize = 5;
+    pub fn discount(&self) -> Uint128 {
+        Uint128::new_unchecked(self.0.slice(0..16))
+    }
+    pub fn account_book_script_hash(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(16..48))
+    }
+    pub fn expire_since(&self) -> Uint64 {
+        Uint64::new_unchecked(self.0.slice(48..56))
+    }
+    pub fn nonce(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(56..88))
+    }
+    pub fn signature(&self) -> Byte65 {
+        Byte65::new_unchecked(self.0.slice(88..153))
+    }
+    pub fn as_reader<'r>(&'r self) -> CouponReader<'r> {
+        CouponReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for Coupon {
+    type Builder = CouponBuilder;
+    const NAME: &'static str = "Coupon";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        Coupon(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        CouponReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        CouponReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .discount(self.discount())
+            .account_book_script_hash(self.account_book_script_hash())
+            .expire_since(self.expire_since())
+            .nonce(self.nonce())
+            .signature(self.signature())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct CouponReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for CouponReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for CouponReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for CouponReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "discount", self.discount())?;
+        write!(
+            f,
+            ", {}: {}",
+            "account_book_script_hash",
+            self.account_book_script_hash()
+        )?;
+        write!(f, ", {}: {}", "expire_since", self.expire_since())?;
+        write!(f, ", {}: {}", "nonce", self.nonce())?;
+        write!(f, ", {}: {}", "signature", self.signature())?;
+        write!(f, " }}")
+    }
+}
+impl<'r> CouponReader<'r> {
+    pub const TOTAL_SIZE: usize = 153;
+    pub const FIELD_SIZES: [usize; 5] = [16, 32, 8, 32, 65];
+    pub const FIELD_COUNT: usize = 5;
+    pub fn discount(&self) -> Uint128Reader<'r> {
+        Uint128Reader::new_unchecked(&self.as_slice()[0..16])
+    }
+    pub fn account_book_script_hash(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[16..48])
+    }
+    pub fn expire_since(&self) -> Uint64Reader<'r> {
+        Uint64Reader::new_unchecked(&self.as_slice()[48..56])
+    }
+    pub fn nonce(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[56..88])
+    }
+    pub fn signature(&self) -> Byte65Reader<'r> {
+        Byte65Reader::new_unchecked(&self.as_slice()[88..153])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for CouponReader<'r> {
+    type Entity = Coupon;
+    const NAME: &'static str = "CouponReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        CouponReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Clone, Debug, Default)]
+pub struct CouponBuilder {
+    pub(crate) discount: Uint128,
+    pub(crate) account_book_script_hash: Byte32,
+    pub(crate) expire_since: Uint64,
+    pub(crate) nonce: Byte32,
+    pub(crate) signature: Byte65,
+}
+impl CouponBuilder {
+    pub const TOTAL_SIZE: usize = 153;
+    pub const FIELD_SIZES: [usize; 5] = [16, 32, 8, 32, 65];
+    pub const FIELD_COUNT: usize = 5;
+    pub fn discount(mut self, v: Uint128) -> Self {
+        self.discount = v;
+        self
+    }
+    pub fn account_book_script_hash(mut self, v: Byte32) -> Self {
+        self.account_book_script_hash = v;
+        self
+    }
+    pub fn expire_since(mut self, v: Uint64) -> Self {
+        self.expire_since = v;
+        self
+    }
+    pub fn nonce(mut self, v: Byte32) -> Self {
+        self.nonce = v;
+        self
+    }
+    pub fn signature(mut self, v: Byte65) -> Self {
+        self.signature = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for CouponBuilder {
+    type Entity = Coupon;
+    const NAME: &'static str = "CouponBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(self.discount.as_slice())?;
+        writer.write_all(self.account_book_script_hash.as_slice())?;
+        writer.write_all(self.expire_since.as_slice())?;
+        writer.write_all(self.nonce.as_slice())?;
+        writer.write_all(self.signature.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        Coupon::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct BuyIntentData(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for BuyIntentData {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for BuyIntentData {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for BuyIntentData {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(
+            f,
+            "{}: {}",
+            "dob_selling_script_hash",
+            self.dob_selling_script_hash()
+        )?;
+        write!(f, ", {}: {}", "xudt_script_hash", self.xudt_script_hash())?;
+        write!(f, ", {}: {}", "price", self.price())?;
+        write!(f, ", {}: {}", "quantity", self.quantity())?;
+        write!(f, ", {}: {}", "max_price", self.max_price())?;
+        write!(f, ", {}: {}", "min_capacity", self.min_capacity())?;
+        write!(f, ", {}: {}", "fill_fee", self.fill_fee())?;
+        write!(f, ", {}: {}", "deposit", self.deposit())?;
+        write!(f, ", {}: {}", "refund_ratio", self.refund_ratio())?;
+        write!(
+            f,
+            ", {}: {}",
+            "forfeit_script_hash",
+            self.forfeit_script_hash()
+        )?;
+        write!(f, ", {}: {}", "expire_since", self.expire_since())?;
+        write!(f, ", {}: {}", "owner_script_hash", self.owner_script_hash())?;
+        write!(f, ", {}: {}", "coupon", self.coupon())?;
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for BuyIntentData {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        BuyIntentData::new_unchecked(v)
+    }
+}
+impl BuyIntentData {
+    const DEFAULT_VALUE: [u8; 358] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    pub const TOTAL_SIZE: usize = 358;
+    pub const FIELD_SIZES: [usize; 13] = [32, 32, 16, 4, 16, 8, 8, 16, 1, 32, 8, 32, 153];
+    pub const FIELD_COUNT: usize = 13;
+    pub fn dob_selling_script_hash(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(0..32))
+    }
+    pub fn xudt_script_hash(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(32..64))
+    }
+    pub fn price(&self) -> Uint128 {
+        Uint128::new_unchecked(self.0.slice(64..80))
+    }
+    pub fn quantity(&self) -> Uint32 {
+        Uint32::new_unchecked(self.0.slice(80..84))
+    }
+    pub fn max_price(&self) -> Uint128 {
+        Uint128::new_unchecked(self.0.slice(84..100))
+    }
+    pub fn min_capacity(&self) -> Uint64 {
+        Uint64::new_unchecked(self.0.slice(100..108))
+    }
+    pub fn fill_fee(&self) -> Uint64 {
+        Uint64::new_unchecked(self.0.slice(108..116))
+    }
+    pub fn deposit(&self) -> Uint128 {
+        Uint128::new_unchecked(self.0.slice(116..132))
+    }
+    pub fn refund_ratio(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(132..133))
+    }
+    pub fn forfeit_script_hash(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(133..165))
+    }
+    pub fn expire_since(&self) -> Uint64 {
+        Uint64::new_unchecked(self.0.slice(165..173))
+    }
+    pub fn owner_script_hash(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(173..205))
+    }
+    pub fn coupon(&self) -> Coupon {
+        Coupon::new_unchecked(self.0.slice(205..358))
+    }
+    pub fn as_reader<'r>(&'r self) -> BuyIntentDataReader<'r> {
+        BuyIntentDataReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for BuyIntentData {
+    type Builder = BuyIntentDataBuilder;
+    const NAME: &'static str = "BuyIntentData";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        BuyIntentData(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        BuyIntentDataReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        BuyIntentDataReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .dob_selling_script_hash(self.dob_selling_script_hash())
+            .xudt_script_hash(self.xudt_script_hash())
+            .price(self.price())
+            .quantity(self.quantity())
+            .max_price(self.max_price())
+            .min_capacity(self.min_capacity())
+            .fill_fee(self.fill_fee())
+            .deposit(self.deposit())
+            .refund_ratio(self.refund_ratio())
+            .forfeit_script_hash(self.forfeit_script_hash())
+            .expire_since(self.expire_since())
+            .owner_script_hash(self.owner_script_hash())
+            .coupon(self.coupon())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct BuyIntentDataReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for BuyIntentDataReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for BuyIntentDataReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for BuyIntentDataReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(
+            f,
+            "{}: {}",
+            "dob_selling_script_hash",
+            self.dob_selling_script_hash()
+        )?;
+        write!(f, ", {}: {}", "xudt_script_hash", self.xudt_script_hash())?;
+        write!(f, ", {}: {}", "price", self.price())?;
+        write!(f, ", {}: {}", "quantity", self.quantity())?;
+        write!(f, ", {}: {}", "max_price", self.max_price())?;
+        write!(f, ", {}: {}", "min_capacity", self.min_capacity())?;
+        write!(f, ", {}: {}", "fill_fee", self.fill_fee())?;
+        write!(f, ", {}: {}", "deposit", self.deposit())?;
+        write!(f, ", {}: {}", "refund_ratio", self.refund_ratio())?;
+        write!(
+            f,
+            ", {}: {}",
+            "forfeit_script_hash",
+            self.forfeit_script_hash()
+        )?;
+        write!(f, ", {}: {}", "expire_since", self.expire_since())?;
+        write!(f, ", {}: {}", "owner_script_hash", self.owner_script_hash())?;
+        write!(f, ", {}: {}", "coupon", self.coupon())?;
+        write!(f, " }}")
+    }
+}
+impl<'r> BuyIntentDataReader<'r> {
+    pub const TOTAL_SIZE: usize = 358;
+    pub const FIELD_SIZES: [usize; 13] = [32, 32, 16, 4, 16, 8, 8, 16, 1, 32, 8, 32, 153];
+    pub const FIELD_COUNT: usize = 13;
+    pub fn dob_selling_script_hash(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[0..32])
+    }
+    pub fn xudt_script_hash(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[32..64])
+    }
+    pub fn price(&self) -> Uint128Reader<'r> {
+        Uint128Reader::new_unchecked(&self.as_slice()[64..80])
+    }
+    pub fn quantity(&self) -> Uint32Reader<'r> {
+        Uint32Reader::new_unchecked(&self.as_slice()[80..84])
+    }
+    pub fn max_price(&self) -> Uint128Reader<'r> {
+        Uint128Reader::new_unchecked(&self.as_slice()[84..100])
+    }
+    pub fn min_capacity(&self) -> Uint64Reader<'r> {
+        Uint64Reader::new_unchecked(&self.as_slice()[100..108])
+    }
+    pub fn fill_fee(&self) -> Uint64Reader<'r> {
+        Uint64Reader::new_unchecked(&self.as_slice()[108..116])
+    }
+    pub fn deposit(&self) -> Uint128Reader<'r> {
+        Uint128Reader::new_unchecked(&self.as_slice()[116..132])
+    }
+    pub fn refund_ratio(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[132..133])
+    }
+    pub fn forfeit_script_hash(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[133..165])
+    }
+    pub fn expire_since(&self) -> Uint64Reader<'r> {
+        Uint64Reader::new_unchecked(&self.as_slice()[165..173])
+    }
+    pub fn owner_script_hash(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[173..205])
+    }
+    pub fn coupon(&self) -> CouponReader<'r> {
+        CouponReader::new_unchecked(&self.as_slice()[205..358])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for BuyIntentDataReader<'r> {
+    type Entity = BuyIntentData;
+    const NAME: &'static str = "BuyIntentDataReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        BuyIntentDataReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Clone, Debug, Default)]
+pub struct BuyIntentDataBuilder {
+    pub(crate) dob_selling_script_hash: Byte32,
+    pub(crate) xudt_script_hash: Byte32,
+    pub(crate) price: Uint128,
+    pub(crate) quantity: Uint32,
+    pub(crate) max_price: Uint128,
+    pub(crate) min_capacity: Uint64,
+    pub(crate) fill_fee: Uint64,
+    pub(crate) deposit: Uint128,
+    pub(crate) refund_ratio: Byte,
+    pub(crate) forfeit_script_hash: Byte32,
+    pub(crate) expire_since: Uint64,
+    pub(crate) owner_script_hash: Byte32,
+    pub(crate) coupon: Coupon,
+}
+impl BuyIntentDataBuilder {
+    pub const TOTAL_SIZE: usize = 358;
+    pub const FIELD_SIZES: [usize; 13] = [32, 32, 16, 4, 16, 8, 8, 16, 1, 32, 8, 32, 153];
+    pub const FIELD_COUNT: usize = 13;
+    pub fn dob_selling_script_hash(mut self, v: Byte32) -> Self {
+        self.dob_selling_script_hash = v;
+        self
+    }
+    pub fn xudt_script_hash(mut self, v: Byte32) -> Self {
+        self.xudt_script_hash = v;
+        self
+    }
+    pub fn price(mut self, v: Uint128) -> Self {
+        self.price = v;
+        self
+    }
+    pub fn quantity(mut self, v: Uint32) -> Self {
+        self.quantity = v;
+        self
+    }
+    pub fn max_price(mut self, v: Uint128) -> Self {
+        self.max_price = v;
+        self
+    }
+    pub fn min_capacity(mut self, v: Uint64) -> Self {
+        self.min_capacity = v;
+        self
+    }
+    pub fn fill_fee(mut self, v: Uint64) -> Self {
+        self.fill_fee = v;
+        self
+    }
+    pub fn deposit(mut self, v: Uint128) -> Self {
+        self.deposit = v;
+        self
+    }
+    pub fn refund_ratio(mut self, v: Byte) -> Self {
+        self.refund_ratio = v;
+        self
+    }
+    pub fn forfeit_script_hash(mut self, v: Byte32) -> Self {
+        self.forfeit_script_hash = v;
+        self
+    }
+    pub fn expire_since(mut self, v: Uint64) -> Self {
+        self.expire_since = v;
+        self
+    }
+    pub fn owner_script_hash(mut self, v: Byte32) -> Self {
+        self.owner_script_hash = v;
+        self
+    }
+    pub fn coupon(mut self, v: Coupon) -> Self {
+        self.coupon = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for BuyIntentDataBuilder {
+    type Entity = BuyIntentData;
+    const NAME: &'static str = "BuyIntentDataBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(self.dob_selling_script_hash.as_slice())?;
+        writer.write_all(self.xudt_script_hash.as_slice())?;
+        writer.write_all(self.price.as_slice())?;
+        writer.write_all(self.quantity.as_slice())?;
+        writer.write_all(self.max_price.as_slice())?;
+        writer.write_all(self.min_capacity.as_slice())?;
+        writer.write_all(self.fill_fee.as_slice())?;
+        writer.write_all(self.deposit.as_slice())?;
+        writer.write_all(self.refund_ratio.as_slice())?;
+        writer.write_all(self.forfeit_script_hash.as_slice())?;
+        writer.write_all(self.expire_since.as_slice())?;
+        writer.write_all(self.owner_script_hash.as_slice())?;
+        writer.write_all(self.coupon.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        BuyIntentData::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct DobSellingData(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for DobSellingData {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for DobSellingData {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for DobSellingData {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(
+            f,
+            "{}: {}",
+            "account_book_script_hash",
+            self.account_book_script_hash()
+        )?;
+        write!(f, ", {}: {}", "spore_code_hash", self.spore_code_hash())?;
+        write!(f, ", {}: {}", "spore_data_hash", self.spore_data_hash())?;
+        write!(
+            f,
+            ", {}: {}",
+            "buy_intent_code_hash",
+            self.buy_intent_code_hash()
+        )?;
+        write!(f, ", {}: {}", "owner_script_hash", self.owner_script_hash())?;
+        write!(
+            f,
+            ", {}: {}",
+            "spore_lock_script_hash",
+            self.spore_lock_script_hash()
+        )?;
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for DobSellingData {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        DobSellingData::new_unchecked(v)
+    }
+}
+impl DobSellingData {
+    const DEFAULT_VALUE: [u8; 192] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    pub const TOTAL_SIZE: usize = 192;
+    pub const FIELD_SIZES: [usize; 6] = [32, 32, 32, 32, 32, 32];
+    pub const FIELD_COUNT: usize = 6;
+    pub fn account_book_script_hash(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(0..32))
+    }
+    pub fn spore_code_hash(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(32..64))
+    }
+    pub fn spore_data_hash(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(64..96))
+    }
+    pub fn buy_intent_code_hash(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(96..128))
+    }
+    pub fn owner_script_hash(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(128..160))
+    }
+    pub fn spore_lock_script_hash(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(160..192))
+    }
+    pub fn as_reader<'r>(&'r self) -> DobSellingDataReader<'r> {
+        DobSellingDataReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for DobSellingData {
+    type Builder = DobSellingDataBuilder;
+    const NAME: &'static str = "DobSellingData";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        DobSellingData(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        DobSellingDataReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        DobSellingDataReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .account_book_script_hash(self.account_book_script_hash())
+            .spore_code_hash(self.spore_code_hash())
+            .spore_data_hash(self.spore_data_hash())
+            .buy_intent_code_hash(self.buy_intent_code_hash())
+            .owner_script_hash(self.owner_script_hash())
+            .spore_lock_script_hash(self.spore_lock_script_hash())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct DobSellingDataReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for DobSellingDataReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for DobSellingDataReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for DobSellingDataReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(
+            f,
+            "{}: {}",
+            "account_book_script_hash",
+            self.account_book_script_hash()
+        )?;
+        write!(f, ", {}: {}", "spore_code_hash", self.spore_code_hash())?;
+        write!(f, ", {}: {}", "spore_data_hash", self.spore_data_hash())?;
+        write!(
+            f,
+            ", {}: {}",
+            "buy_intent_code_hash",
+            self.buy_intent_code_hash()
+        )?;
+        write!(f, ", {}: {}", "owner_script_hash", self.owner_script_hash())?;
+        write!(
+            f,
+            ", {}: {}",
+            "spore_lock_script_hash",
+            self.spore_lock_script_hash()
+        )?;
+        write!(f, " }}")
+    }
+}
+impl<'r> DobSellingDataReader<'r> {
+    pub const TOTAL_SIZE: usize = 192;
+    pub const FIELD_SIZES: [usize; 6] = [32, 32, 32, 32, 32, 32];
+    pub const FIELD_COUNT: usize = 6;
+    pub fn account_book_script_hash(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[0..32])
+    }
+    pub fn spore_code_hash(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[32..64])
+    }
+    pub fn spore_data_hash(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[64..96])
+    }
+    pub fn buy_intent_code_hash(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[96..128])
+    }
+    pub fn owner_script_hash(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[128..160])
+    }
+    pub fn spore_lock_script_hash(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[160..192])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for DobSellingDataReader<'r> {
+    type Entity = DobSellingData;
+    const NAME: &'static str = "DobSellingDataReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        DobSellingDataReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Clone, Debug, Default)]
+pub struct DobSellingDataBuilder {
+    pub(crate) account_book_script_hash: Byte32,
+    pub(crate) spore_code_hash: Byte32,
+    pub(crate) spore_data_hash: Byte32,
+    pub(crate) buy_intent_code_hash: Byte32,
+    pub(crate) owner_script_hash: Byte32,
+    pub(crate) spore_lock_script_hash: Byte32,
+}
+impl DobSellingDataBuilder {
+    pub const TOTAL_SIZE: usize = 192;
+    pub const FIELD_SIZES: [usize; 6] = [32, 32, 32, 32, 32, 32];
+    pub const FIELD_COUNT: usize = 6;
+    pub fn account_book_script_hash(mut self, v: Byte32) -> Self {
+        self.account_book_script_hash = v;
+        self
+    }
+    pub fn spore_code_hash(mut self, v: Byte32) -> Self {
+        self.spore_code_hash = v;
+        self
+    }
+    pub fn spore_data_hash(mut self, v: Byte32) -> Self {
+        self.spore_data_hash = v;
+        self
+    }
+    pub fn buy_intent_code_hash(mut self, v: Byte32) -> Self {
+        self.buy_intent_code_hash = v;
+        self
+    }
+    pub fn owner_script_hash(mut self, v: Byte32) -> Self {
+        self.owner_script_hash = v;
+        self
+    }
+    pub fn spore_lock_script_hash(mut self, v: Byte32) -> Self {
+        self.spore_lock_script_hash = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for DobSellingDataBuilder {
+    type Entity = DobSellingData;
+    const NAME: &'static str = "DobSellingDataBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(self.account_book_script_hash.as_slice())?;
+        writer.write_all(self.spore_code_hash.as_slice())?;
+        writer.write_all(self.spore_data_hash.as_slice())?;
+        writer.write_all(self.buy_intent_code_hash.as_slice())?;
+        writer.write_all(self.owner_script_hash.as_slice())?;
+        writer.write_all(self.spore_lock_script_hash.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        DobSellingData::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct WithdrawalSporeInfo(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for WithdrawalSporeInfo {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for WithdrawalSporeInfo {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for WithdrawalSporeInfo {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "spore_code_hash", self.spore_code_hash())?;
+        write!(f, ", {}: {}", "spore_level", self.spore_level())?;
+        write!(f, ", {}: {}", "spore_id", self.spore_id())?;
+        write!(f, ", {}: {}", "cluster_id", self.cluster_id())?;
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for WithdrawalSporeInfo {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        WithdrawalSporeInfo::new_unchecked(v)
+    }
+}
+impl WithdrawalSporeInfo {
+    const DEFAULT_VALUE: [u8; 97] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0,
+    ];
+    pub const TOTAL_SIZE: usize = 97;
+    pub const FIELD_SIZES: [usize; 4] = [32, 1, 32, 32];
+    pub const FIELD_COUNT: usize = 4;
+    pub fn spore_code_hash(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(0..32))
+    }
+    pub fn spore_level(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(32..33))
+    }
+    pub fn spore_id(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(33..65))
+    }
+    pub fn cluster_id(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(65..97))
+    }
+    pub fn as_reader<'r>(&'r self) -> WithdrawalSporeInfoReader<'r> {
+        WithdrawalSporeInfoReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for WithdrawalSporeInfo {
+    type Builder = WithdrawalSporeInfoBuilder;
+    const NAME: &'static str = "WithdrawalSporeInfo";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        WithdrawalSporeInfo(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        WithdrawalSporeInfoReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        WithdrawalSporeInfoReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .spore_code_hash(self.spore_code_hash())
+            .spore_level(self.spore_level())
+            .spore_id(self.spore_id())
+            .cluster_id(self.cluster_id())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct WithdrawalSporeInfoReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for WithdrawalSporeInfoReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for WithdrawalSporeInfoReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for WithdrawalSporeInfoReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "spore_code_hash", self.spore_code_hash())?;
+        write!(f, ", {}: {}", "spore_level", self.spore_level())?;
+        write!(f, ", {}: {}", "spore_id", self.spore_id())?;
+        write!(f, ", {}: {}", "cluster_id", self.cluster_id())?;
+        write!(f, " }}")
+    }
+}
+impl<'r> WithdrawalSporeInfoReader<'r> {
+    pub const TOTAL_SIZE: usize = 97;
+    pub const FIELD_SIZES: [usize; 4] = [32, 1, 32, 32];
+    pub const FIELD_COUNT: usize = 4;
+    pub fn spore_code_hash(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[0..32])
+    }
+    pub fn spore_level(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[32..33])
+    }
+    pub fn spore_id(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[33..65])
+    }
+    pub fn cluster_id(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[65..97])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for WithdrawalSporeInfoReader<'r> {
+    type Entity = WithdrawalSporeInfo;
+    const NAME: &'static str = "WithdrawalSporeInfoReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
         WithdrawalSporeInfoReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
     }
-    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Clone, Debug, Default)]
+pub struct WithdrawalSporeInfoBuilder {
+    pub(crate) spore_code_hash: Byte32,
+    pub(crate) spore_level: Byte,
+    pub(crate) spore_id: Byte32,
+    pub(crate) cluster_id: Byte32,
+}
+impl WithdrawalSporeInfoBuilder {
+    pub const TOTAL_SIZE: usize = 97;
+    pub const FIELD_SIZES: [usize; 4] = [32, 1, 32, 32];
+    pub const FIELD_COUNT: usize = 4;
+    pub fn spore_code_hash(mut self, v: Byte32) -> Self {
+        self.spore_code_hash = v;
+        self
+    }
+    pub fn spore_level(mut self, v: Byte) -> Self {
+        self.spore_level = v;
+        self
+    }
+    pub fn spore_id(mut self, v: Byte32) -> Self {
+        self.spore_id = v;
+        self
+    }
+    pub fn cluster_id(mut self, v: Byte32) -> Self {
+        self.cluster_id = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for WithdrawalSporeInfoBuilder {
+    type Entity = WithdrawalSporeInfo;
+    const NAME: &'static str = "WithdrawalSporeInfoBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(self.spore_code_hash.as_slice())?;
+        writer.write_all(self.spore_level.as_slice())?;
+        writer.write_all(self.spore_id.as_slice())?;
+        writer.write_all(self.cluster_id.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        WithdrawalSporeInfo::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct WithdrawalSporeInfoOpt(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for WithdrawalSporeInfoOpt {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for WithdrawalSporeInfoOpt {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for WithdrawalSporeInfoOpt {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        if let Some(v) = self.to_opt() {
+            write!(f, "{}(Some({}))", Self::NAME, v)
+        } else {
+            write!(f, "{}(None)", Self::NAME)
+        }
+    }
+}
+impl ::core::default::Default for WithdrawalSporeInfoOpt {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        WithdrawalSporeInfoOpt::new_unchecked(v)
+    }
+}
+impl WithdrawalSporeInfoOpt {
+    const DEFAULT_VALUE: [u8; 0] = [];
+    pub fn is_none(&self) -> bool {
+        self.0.is_empty()
+    }
+    pub fn is_some(&self) -> bool {
+        !self.0.is_empty()
+    }
+    pub fn to_opt(&self) -> Option<WithdrawalSporeInfo> {
+        if self.is_none() {
+            None
+        } else {
+            Some(WithdrawalSporeInfo::new_unchecked(self.0.clone()))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> WithdrawalSporeInfoOptReader<'r> {
+        WithdrawalSporeInfoOptReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for WithdrawalSporeInfoOpt {
+    type Builder = WithdrawalSporeInfoOptBuilder;
+    const NAME: &'static str = "WithdrawalSporeInfoOpt";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        WithdrawalSporeInfoOpt(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        WithdrawalSporeInfoOptReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        WithdrawalSporeInfoOptReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().set(self.to_opt())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct WithdrawalSporeInfoOptReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for WithdrawalSporeInfoOptReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for WithdrawalSporeInfoOptReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for WithdrawalSporeInfoOptReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        if let Some(v) = self.to_opt() {
+            write!(f, "{}(Some({}))", Self::NAME, v)
+        } else {
+            write!(f, "{}(None)", Self::NAME)
+        }
+    }
+}
+impl<'r> WithdrawalSporeInfoOptReader<'r> {
+    pub fn is_none(&self) -> bool {
+        self.0.is_empty()
+    }
+    pub fn is_some(&self) -> bool {
+        !self.0.is_empty()
+    }
+    pub fn to_opt(&self) -> Option<WithdrawalSporeInfoReader<'r>> {
+        if self.is_none() {
+            None
+        } else {
+            Some(WithdrawalSporeInfoReader::new_unchecked(self.as_slice()))
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for WithdrawalSporeInfoOptReader<'r> {
+    type Entity = WithdrawalSporeInfoOpt;
+    const NAME: &'static str = "WithdrawalSporeInfoOptReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        WithdrawalSporeInfoOptReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        if !slice.is_empty() {
+            WithdrawalSporeInfoReader::verify(&slice[..], compatible)?;
+        }
+        Ok(())
+    }
+}
+#[derive(Clone, Debug, Default)]
+pub struct WithdrawalSporeInfoOptBuilder(pub(crate) Option<WithdrawalSporeInfo>);
+impl WithdrawalSporeInfoOptBuilder {
+    pub fn set(mut self, v: Option<WithdrawalSporeInfo>) -> Self {
+        self.0 = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for WithdrawalSporeInfoOptBuilder {
+    type Entity = WithdrawalSporeInfoOpt;
+    const NAME: &'static str = "WithdrawalSporeInfoOptBuilder";
+    fn expected_length(&self) -> usize {
+        self.0
+            .as_ref()
+            .map(|ref inner| inner.as_slice().len())
+            .unwrap_or(0)
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        self.0
+            .as_ref()
+            .map(|ref inner| writer.write_all(inner.as_slice()))
+            .unwrap_or(Ok(()))
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        WithdrawalSporeInfoOpt::new_unchecked(inner.into())
+    }
+}
+impl From<WithdrawalSporeInfo> for WithdrawalSporeInfoOpt {
+    fn from(value: WithdrawalSporeInfo) -> Self {
+        Self::new_builder().set(Some(value)).build()
+    }
+}
+#[derive(Clone)]
+pub struct WithdrawalSporeInfoVec(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for WithdrawalSporeInfoVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for WithdrawalSporeInfoVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for WithdrawalSporeInfoVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl ::core::default::Default for WithdrawalSporeInfoVec {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        WithdrawalSporeInfoVec::new_unchecked(v)
+    }
+}
+impl WithdrawalSporeInfoVec {
+    const DEFAULT_VALUE: [u8; 4] = [0, 0, 0, 0];
+    pub const ITEM_SIZE: usize = 97;
+    pub fn total_size(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
+    }
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<WithdrawalSporeInfo> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> WithdrawalSporeInfo {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        WithdrawalSporeInfo::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn as_reader<'r>(&'r self) -> WithdrawalSporeInfoVecReader<'r> {
+        WithdrawalSporeInfoVecReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for WithdrawalSporeInfoVec {
+    type Builder = WithdrawalSporeInfoVecBuilder;
+    const NAME: &'static str = "WithdrawalSporeInfoVec";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        WithdrawalSporeInfoVec(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        WithdrawalSporeInfoVecReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        WithdrawalSporeInfoVecReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().extend(self.into_iter())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct WithdrawalSporeInfoVecReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for WithdrawalSporeInfoVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for WithdrawalSporeInfoVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for WithdrawalSporeInfoVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl<'r> WithdrawalSporeInfoVecReader<'r> {
+    pub const ITEM_SIZE: usize = 97;
+    pub fn total_size(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
+    }
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<WithdrawalSporeInfoReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> WithdrawalSporeInfoReader<'r> {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        WithdrawalSporeInfoReader::new_unchecked(&self.as_slice()[start..end])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for WithdrawalSporeInfoVecReader<'r> {
+    type Entity = WithdrawalSporeInfoVec;
+    const NAME: &'static str = "WithdrawalSporeInfoVecReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        WithdrawalSporeInfoVecReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let item_count = molecule::unpack_number(slice) as usize;
+        if item_count == 0 {
+            if slice_len != molecule::NUMBER_SIZE {
+                return ve!(Self, TotalSizeNotMatch, molecule::NUMBER_SIZE, slice_len);
+            }
+            return Ok(());
+        }
+        let total_size = molecule::NUMBER_SIZE + Self::ITEM_SIZE * item_count;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Clone, Debug, Default)]
+pub struct WithdrawalSporeInfoVecBuilder(pub(crate) Vec<WithdrawalSporeInfo>);
+impl WithdrawalSporeInfoVecBuilder {
+    pub const ITEM_SIZE: usize = 97;
+    pub fn set(mut self, v: Vec<WithdrawalSporeInfo>) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn push(mut self, v: WithdrawalSporeInfo) -> Self {
+        self.0.push(v);
+        self
+    }
+    pub fn extend<T: ::core::iter::IntoIterator<Item = WithdrawalSporeInfo>>(
+        mut self,
+        iter: T,
+    ) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
+        self
+    }
+    pub fn replace(&mut self, index: usize, v: WithdrawalSporeInfo) -> Option<WithdrawalSporeInfo> {
+        self.0
+            .get_mut(index)
+            .map(|item| ::core::mem::replace(item, v))
+    }
+}
+impl molecule::prelude::Builder for WithdrawalSporeInfoVecBuilder {
+    type Entity = WithdrawalSporeInfoVec;
+    const NAME: &'static str = "WithdrawalSporeInfoVecBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.0.len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(&molecule::pack_number(self.0.len() as molecule::Number))?;
+        for inner in &self.0[..] {
+            writer.write_all(inner.as_slice())?;
+        }
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        WithdrawalSporeInfoVec::new_unchecked(inner.into())
+    }
+}
+pub struct WithdrawalSporeInfoVecIterator(WithdrawalSporeInfoVec, usize, usize);
+impl ::core::iter::Iterator for WithdrawalSporeInfoVecIterator {
+    type Item = WithdrawalSporeInfo;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::core::iter::ExactSizeIterator for WithdrawalSporeInfoVecIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for WithdrawalSporeInfoVec {
+    type Item = WithdrawalSporeInfo;
+    type IntoIter = WithdrawalSporeInfoVecIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        WithdrawalSporeInfoVecIterator(self, 0, len)
+    }
+}
+impl<'r> WithdrawalSporeInfoVecReader<'r> {
+    pub fn iter<'t>(&'t self) -> WithdrawalSporeInfoVecReaderIterator<'t, 'r> {
+        WithdrawalSporeInfoVecReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct WithdrawalSporeInfoVecReaderIterator<'t, 'r>(
+    &'t WithdrawalSporeInfoVecReader<'r>,
+    usize,
+    usize,
+);
+impl<'t: 'r, 'r> ::core::iter::Iterator for WithdrawalSporeInfoVecReaderIterator<'t, 'r> {
+    type Item = WithdrawalSporeInfoReader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for WithdrawalSporeInfoVecReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::FromIterator<WithdrawalSporeInfo> for WithdrawalSporeInfoVec {
+    fn from_iter<T: IntoIterator<Item = WithdrawalSporeInfo>>(iter: T) -> Self {
+        Self::new_builder().extend(iter).build()
+    }
+}
+#[derive(Clone)]
+pub struct CoAuthor(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for CoAuthor {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for CoAuthor {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for CoAuthor {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "lock_script_hash", self.lock_script_hash())?;
+        write!(f, ", {}: {}", "weight", self.weight())?;
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for CoAuthor {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        CoAuthor::new_unchecked(v)
+    }
+}
+impl CoAuthor {
+    const DEFAULT_VALUE: [u8; 33] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0,
+    ];
+    pub const TOTAL_SIZE: usize = 33;
+    pub const FIELD_SIZES: [usize; 2] = [32, 1];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn lock_script_hash(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(0..32))
+    }
+    pub fn weight(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(32..33))
+    }
+    pub fn as_reader<'r>(&'r self) -> CoAuthorReader<'r> {
+        CoAuthorReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for CoAuthor {
+    type Builder = CoAuthorBuilder;
+    const NAME: &'static str = "CoAuthor";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        CoAuthor(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        CoAuthorReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        CoAuthorReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .lock_script_hash(self.lock_script_hash())
+            .weight(self.weight())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct CoAuthorReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for CoAuthorReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for CoAuthorReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for CoAuthorReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "lock_script_hash", self.lock_script_hash())?;
+        write!(f, ", {}: {}", "weight", self.weight())?;
+        write!(f, " }}")
+    }
+}
+impl<'r> CoAuthorReader<'r> {
+    pub const TOTAL_SIZE: usize = 33;
+    pub const FIELD_SIZES: [usize; 2] = [32, 1];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn lock_script_hash(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[0..32])
+    }
+    pub fn weight(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[32..33])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for CoAuthorReader<'r> {
+    type Entity = CoAuthor;
+    const NAME: &'static str = "CoAuthorReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        CoAuthorReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Clone, Debug, Default)]
+pub struct CoAuthorBuilder {
+    pub(crate) lock_script_hash: Byte32,
+    pub(crate) weight: Byte,
+}
+impl CoAuthorBuilder {
+    pub const TOTAL_SIZE: usize = 33;
+    pub const FIELD_SIZES: [usize; 2] = [32, 1];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn lock_script_hash(mut self, v: Byte32) -> Self {
+        self.lock_script_hash = v;
+        self
+    }
+    pub fn weight(mut self, v: Byte) -> Self {
+        self.weight = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for CoAuthorBuilder {
+    type Entity = CoAuthor;
+    const NAME: &'static str = "CoAuthorBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(self.lock_script_hash.as_slice())?;
+        writer.write_all(self.weight.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        CoAuthor::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct CoAuthorVec(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for CoAuthorVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for CoAuthorVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for CoAuthorVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl ::core::default::Default for CoAuthorVec {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        CoAuthorVec::new_unchecked(v)
+    }
+}
+impl CoAuthorVec {
+    const DEFAULT_VALUE: [u8; 4] = [0, 0, 0, 0];
+    pub const ITEM_SIZE: usize = 33;
+    pub fn total_size(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
+    }
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<CoAuthor> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> CoAuthor {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        CoAuthor::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn as_reader<'r>(&'r self) -> CoAuthorVecReader<'r> {
+        CoAuthorVecReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for CoAuthorVec {
+    type Builder = CoAuthorVecBuilder;
+    const NAME: &'static str = "CoAuthorVec";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        CoAuthorVec(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        CoAuthorVecReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        CoAuthorVecReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().extend(self.into_iter())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct CoAuthorVecReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for CoAuthorVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for CoAuthorVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for CoAuthorVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl<'r> CoAuthorVecReader<'r> {
+    pub const ITEM_SIZE: usize = 33;
+    pub fn total_size(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
+    }
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<CoAuthorReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> CoAuthorReader<'r> {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        CoAuthorReader::new_unchecked(&self.as_slice()[start..end])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for CoAuthorVecReader<'r> {
+    type Entity = CoAuthorVec;
+    const NAME: &'static str = "CoAuthorVecReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        CoAuthorVecReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let item_count = molecule::unpack_number(slice) as usize;
+        if item_count == 0 {
+            if slice_len != molecule::NUMBER_SIZE {
+                return ve!(Self, TotalSizeNotMatch, molecule::NUMBER_SIZE, slice_len);
+            }
+            return Ok(());
+        }
+        let total_size = molecule::NUMBER_SIZE + Self::ITEM_SIZE * item_count;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Clone, Debug, Default)]
+pub struct CoAuthorVecBuilder(pub(crate) Vec<CoAuthor>);
+impl CoAuthorVecBuilder {
+    pub const ITEM_SIZE: usize = 33;
+    pub fn set(mut self, v: Vec<CoAuthor>) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn push(mut self, v: CoAuthor) -> Self {
+        self.0.push(v);
+        self
+    }
+    pub fn extend<T: ::core::iter::IntoIterator<Item = CoAuthor>>(mut self, iter: T) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
+        self
+    }
+    pub fn replace(&mut self, index: usize, v: CoAuthor) -> Option<CoAuthor> {
+        self.0
+            .get_mut(index)
+            .map(|item| ::core::mem::replace(item, v))
+    }
+}
+impl molecule::prelude::Builder for CoAuthorVecBuilder {
+    type Entity = CoAuthorVec;
+    const NAME: &'static str = "CoAuthorVecBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.0.len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(&molecule::pack_number(self.0.len() as molecule::Number))?;
+        for inner in &self.0[..] {
+            writer.write_all(inner.as_slice())?;
+        }
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        CoAuthorVec::new_unchecked(inner.into())
+    }
+}
+pub struct CoAuthorVecIterator(CoAuthorVec, usize, usize);
+impl ::core::iter::Iterator for CoAuthorVecIterator {
+    type Item = CoAuthor;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::core::iter::ExactSizeIterator for CoAuthorVecIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for CoAuthorVec {
+    type Item = CoAuthor;
+    type IntoIter = CoAuthorVecIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        CoAuthorVecIterator(self, 0, len)
+    }
+}
+impl<'r> CoAuthorVecReader<'r> {
+    pub fn iter<'t>(&'t self) -> CoAuthorVecReaderIterator<'t, 'r> {
+        CoAuthorVecReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct CoAuthorVecReaderIterator<'t, 'r>(&'t CoAuthorVecReader<'r>, usize, usize);
+impl<'t: 'r, 'r> ::core::iter::Iterator for CoAuthorVecReaderIterator<'t, 'r> {
+    type Item = CoAuthorReader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for CoAuthorVecReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::FromIterator<CoAuthor> for CoAuthorVec {
+    fn from_iter<T: IntoIterator<Item = CoAuthor>>(iter: T) -> Self {
+        Self::new_builder().extend(iter).build()
+    }
+}
+#[derive(Clone)]
+pub struct LevelRange(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for LevelRange {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for LevelRange {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for LevelRange {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "min", self.min())?;
+        write!(f, ", {}: {}", "max", self.max())?;
+        write!(f, ", {}: {}", "level", self.level())?;
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for LevelRange {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        LevelRange::new_unchecked(v)
+    }
+}
+impl LevelRange {
+    const DEFAULT_VALUE: [u8; 3] = [0, 0, 0];
+    pub const TOTAL_SIZE: usize = 3;
+    pub const FIELD_SIZES: [usize; 3] = [1, 1, 1];
+    pub const FIELD_COUNT: usize = 3;
+    pub fn min(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(0..1))
+    }
+    pub fn max(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(1..2))
+    }
+    pub fn level(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(2..3))
+    }
+    pub fn as_reader<'r>(&'r self) -> LevelRangeReader<'r> {
+        LevelRangeReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for LevelRange {
+    type Builder = LevelRangeBuilder;
+    const NAME: &'static str = "LevelRange";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        LevelRange(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        LevelRangeReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        LevelRangeReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .min(self.min())
+            .max(self.max())
+            .level(self.level())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct LevelRangeReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for LevelRangeReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for LevelRangeReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for LevelRangeReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "min", self.min())?;
+        write!(f, ", {}: {}", "max", self.max())?;
+        write!(f, ", {}: {}", "level", self.level())?;
+        write!(f, " }}")
+    }
+}
+impl<'r> LevelRangeReader<'r> {
+    pub const TOTAL_SIZE: usize = 3;
+    pub const FIELD_SIZES: [usize; 3] = [1, 1, 1];
+    pub const FIELD_COUNT: usize = 3;
+    pub fn min(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[0..1])
+    }
+    pub fn max(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[1..2])
+    }
+    pub fn level(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[2..3])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for LevelRangeReader<'r> {
+    type Entity = LevelRange;
+    const NAME: &'static str = "LevelRangeReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        LevelRangeReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Clone, Debug, Default)]
+pub struct LevelRangeBuilder {
+    pub(crate) min: Byte,
+    pub(crate) max: Byte,
+    pub(crate) level: Byte,
+}
+impl LevelRangeBuilder {
+    pub const TOTAL_SIZE: usize = 3;
+    pub const FIELD_SIZES: [usize; 3] = [1, 1, 1];
+    pub const FIELD_COUNT: usize = 3;
+    pub fn min(mut self, v: Byte) -> Self {
+        self.min = v;
+        self
+    }
+    pub fn max(mut self, v: Byte) -> Self {
+        self.max = v;
+        self
+    }
+    pub fn level(mut self, v: Byte) -> Self {
+        self.level = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for LevelRangeBuilder {
+    type Entity = LevelRange;
+    const NAME: &'static str = "LevelRangeBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(self.min.as_slice())?;
+        writer.write_all(self.max.as_slice())?;
+        writer.write_all(self.level.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        LevelRange::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct LevelRangeVec(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for LevelRangeVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for LevelRangeVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for LevelRangeVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl ::core::default::Default for LevelRangeVec {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        LevelRangeVec::new_unchecked(v)
+    }
+}
+impl LevelRangeVec {
+    const DEFAULT_VALUE: [u8; 4] = [0, 0, 0, 0];
+    pub const ITEM_SIZE: usize = 3;
+    pub fn total_size(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
+    }
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<LevelRange> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> LevelRange {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        LevelRange::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn as_reader<'r>(&'r self) -> LevelRangeVecReader<'r> {
+        LevelRangeVecReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for LevelRangeVec {
+    type Builder = LevelRangeVecBuilder;
+    const NAME: &'static str = "LevelRangeVec";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        LevelRangeVec(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        LevelRangeVecReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        LevelRangeVecReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().extend(self.into_iter())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct LevelRangeVecReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for LevelRangeVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for LevelRangeVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for LevelRangeVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl<'r> LevelRangeVecReader<'r> {
+    pub const ITEM_SIZE: usize = 3;
+    pub fn total_size(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
+    }
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<LevelRangeReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> LevelRangeReader<'r> {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        LevelRangeReader::new_unchecked(&self.as_slice()[start..end])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for LevelRangeVecReader<'r> {
+    type Entity = LevelRangeVec;
+    const NAME: &'static str = "LevelRangeVecReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        LevelRangeVecReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let item_count = molecule::unpack_number(slice) as usize;
+        if item_count == 0 {
+            if slice_len != molecule::NUMBER_SIZE {
+                return ve!(Self, TotalSizeNotMatch, molecule::NUMBER_SIZE, slice_len);
+            }
+            return Ok(());
+        }
+        let total_size = molecule::NUMBER_SIZE + Self::ITEM_SIZE * item_count;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Clone, Debug, Default)]
+pub struct LevelRangeVecBuilder(pub(crate) Vec<LevelRange>);
+impl LevelRangeVecBuilder {
+    pub const ITEM_SIZE: usize = 3;
+    pub fn set(mut self, v: Vec<LevelRange>) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn push(mut self, v: LevelRange) -> Self {
+        self.0.push(v);
+        self
+    }
+    pub fn extend<T: ::core::iter::IntoIterator<Item = LevelRange>>(mut self, iter: T) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
+        self
+    }
+    pub fn replace(&mut self, index: usize, v: LevelRange) -> Option<LevelRange> {
+        self.0
+            .get_mut(index)
+            .map(|item| ::core::mem::replace(item, v))
+    }
+}
+impl molecule::prelude::Builder for LevelRangeVecBuilder {
+    type Entity = LevelRangeVec;
+    const NAME: &'static str = "LevelRangeVecBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.0.len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(&molecule::pack_number(self.0.len() as molecule::Number))?;
+        for inner in &self.0[..] {
+            writer.write_all(inner.as_slice())?;
+        }
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        LevelRangeVec::new_unchecked(inner.into())
+    }
+}
+pub struct LevelRangeVecIterator(LevelRangeVec, usize, usize);
+impl ::core::iter::Iterator for LevelRangeVecIterator {
+    type Item = LevelRange;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::core::iter::ExactSizeIterator for LevelRangeVecIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for LevelRangeVec {
+    type Item = LevelRange;
+    type IntoIter = LevelRangeVecIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        LevelRangeVecIterator(self, 0, len)
+    }
+}
+impl<'r> LevelRangeVecReader<'r> {
+    pub fn iter<'t>(&'t self) -> LevelRangeVecReaderIterator<'t, 'r> {
+        LevelRangeVecReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct LevelRangeVecReaderIterator<'t, 'r>(&'t LevelRangeVecReader<'r>, usize, usize);
+impl<'t: 'r, 'r> ::core::iter::Iterator for LevelRangeVecReaderIterator<'t, 'r> {
+    type Item = LevelRangeReader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for LevelRangeVecReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::FromIterator<LevelRange> for LevelRangeVec {
+    fn from_iter<T: IntoIterator<Item = LevelRange>>(iter: T) -> Self {
+        Self::new_builder().extend(iter).build()
+    }
+}
+#[derive(Clone)]
+pub struct LevelRule(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for LevelRule {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for LevelRule {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for LevelRule {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "dna_byte_index", self.dna_byte_index())?;
+        write!(f, ", {}: {}", "dna_bit_mask", self.dna_bit_mask())?;
+        write!(f, ", {}: {}", "ranges", self.ranges())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for LevelRule {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        LevelRule::new_unchecked(v)
+    }
+}
+impl LevelRule {
+    const DEFAULT_VALUE: [u8; 22] = [
+        22, 0, 0, 0, 16, 0, 0, 0, 17, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    pub const FIELD_COUNT: usize = 3;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn dna_byte_index(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn dna_bit_mask(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn ranges(&self) -> LevelRangeVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[16..]) as usize;
+            LevelRangeVec::new_unchecked(self.0.slice(start..end))
+        } else {
+            LevelRangeVec::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> LevelRuleReader<'r> {
+        LevelRuleReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for LevelRule {
+    type Builder = LevelRuleBuilder;
+    const NAME: &'static str = "LevelRule";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        LevelRule(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        LevelRuleReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        LevelRuleReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .dna_byte_index(self.dna_byte_index())
+            .dna_bit_mask(self.dna_bit_mask())
+            .ranges(self.ranges())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct LevelRuleReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for LevelRuleReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for LevelRuleReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for LevelRuleReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "dna_byte_index", self.dna_byte_index())?;
+        write!(f, ", {}: {}", "dna_bit_mask", self.dna_bit_mask())?;
+        write!(f, ", {}: {}", "ranges", self.ranges())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> LevelRuleReader<'r> {
+    pub const FIELD_COUNT: usize = 3;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn dna_byte_index(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn dna_bit_mask(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn ranges(&self) -> LevelRangeVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[16..]) as usize;
+            LevelRangeVecReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            LevelRangeVecReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for LevelRuleReader<'r> {
+    type Entity = LevelRule;
+    const NAME: &'static str = "LevelRuleReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        LevelRuleReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        ByteReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        ByteReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        LevelRangeVecReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Clone, Debug, Default)]
+pub struct LevelRuleBuilder {
+    pub(crate) dna_byte_index: Byte,
+    pub(crate) dna_bit_mask: Byte,
+    pub(crate) ranges: LevelRangeVec,
+}
+impl LevelRuleBuilder {
+    pub const FIELD_COUNT: usize = 3;
+    pub fn dna_byte_index(mut self, v: Byte) -> Self {
+        self.dna_byte_index = v;
+        self
+    }
+    pub fn dna_bit_mask(mut self, v: Byte) -> Self {
+        self.dna_bit_mask = v;
+        self
+    }
+    pub fn ranges(mut self, v: LevelRangeVec) -> Self {
+        self.ranges = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for LevelRuleBuilder {
+    type Entity = LevelRule;
+    const NAME: &'static str = "LevelRuleBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.dna_byte_index.as_slice().len()
+            + self.dna_bit_mask.as_slice().len()
+            + self.ranges.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.dna_byte_index.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.dna_bit_mask.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.ranges.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.dna_byte_index.as_slice())?;
+        writer.write_all(self.dna_bit_mask.as_slice())?;
+        writer.write_all(self.ranges.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        LevelRule::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct LevelRuleOpt(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for LevelRuleOpt {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for LevelRuleOpt {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for LevelRuleOpt {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        if let Some(v) = self.to_opt() {
+            write!(f, "{}(Some({}))", Self::NAME, v)
+        } else {
+            write!(f, "{}(None)", Self::NAME)
+        }
+    }
+}
+impl ::core::default::Default for LevelRuleOpt {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        LevelRuleOpt::new_unchecked(v)
+    }
+}
+impl LevelRuleOpt {
+    const DEFAULT_VALUE: [u8; 0] = [];
+    pub fn is_none(&self) -> bool {
+        self.0.is_empty()
+    }
+    pub fn is_some(&self) -> bool {
+        !self.0.is_empty()
+    }
+    pub fn to_opt(&self) -> Option<LevelRule> {
+        if self.is_none() {
+            None
+        } else {
+            Some(LevelRule::new_unchecked(self.0.clone()))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> LevelRuleOptReader<'r> {
+        LevelRuleOptReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for LevelRuleOpt {
+    type Builder = LevelRuleOptBuilder;
+    const NAME: &'static str = "LevelRuleOpt";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        LevelRuleOpt(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        LevelRuleOptReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        LevelRuleOptReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().set(self.to_opt())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct LevelRuleOptReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for LevelRuleOptReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for LevelRuleOptReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for LevelRuleOptReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        if let Some(v) = self.to_opt() {
+            write!(f, "{}(Some({}))", Self::NAME, v)
+        } else {
+            write!(f, "{}(None)", Self::NAME)
+        }
+    }
+}
+impl<'r> LevelRuleOptReader<'r> {
+    pub fn is_none(&self) -> bool {
+        self.0.is_empty()
+    }
+    pub fn is_some(&self) -> bool {
+        !self.0.is_empty()
+    }
+    pub fn to_opt(&self) -> Option<LevelRuleReader<'r>> {
+        if self.is_none() {
+            None
+        } else {
+            Some(LevelRuleReader::new_unchecked(self.as_slice()))
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for LevelRuleOptReader<'r> {
+    type Entity = LevelRuleOpt;
+    const NAME: &'static str = "LevelRuleOptReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        LevelRuleOptReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        if !slice.is_empty() {
+            LevelRuleReader::verify(&slice[..], compatible)?;
+        }
+        Ok(())
+    }
+}
+#[derive(Clone, Debug, Default)]
+pub struct LevelRuleOptBuilder(pub(crate) Option<LevelRule>);
+impl LevelRuleOptBuilder {
+    pub fn set(mut self, v: Option<LevelRule>) -> Self {
+        self.0 = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for LevelRuleOptBuilder {
+    type Entity = LevelRuleOpt;
+    const NAME: &'static str = "LevelRuleOptBuilder";
+    fn expected_length(&self) -> usize {
+        self.0
+            .as_ref()
+            .map(|ref inner| inner.as_slice().len())
+            .unwrap_or(0)
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        self.0
+            .as_ref()
+            .map(|ref inner| writer.write_all(inner.as_slice()))
+            .unwrap_or(Ok(()))
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        LevelRuleOpt::new_unchecked(inner.into())
+    }
+}
+impl From<LevelRule> for LevelRuleOpt {
+    fn from(value: LevelRule) -> Self {
+        Self::new_builder().set(Some(value)).build()
+    }
+}
+#[derive(Clone)]
+pub struct WithdrawalBuyer(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for WithdrawalBuyer {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for WithdrawalBuyer {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for WithdrawalBuyer {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}(", Self::NAME)?;
+        self.to_enum().display_inner(f)?;
+        write!(f, ")")
+    }
+}
+impl ::core::default::Default for WithdrawalBuyer {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        WithdrawalBuyer::new_unchecked(v)
+    }
+}
+impl WithdrawalBuyer {
+    const DEFAULT_VALUE: [u8; 101] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    pub const ITEMS_COUNT: usize = 2;
+    pub fn item_id(&self) -> molecule::Number {
+        molecule::unpack_number(self.as_slice())
+    }
+    pub fn to_enum(&self) -> WithdrawalBuyerUnion {
+        let inner = self.0.slice(molecule::NUMBER_SIZE..);
+        match self.item_id() {
+            0 => WithdrawalSporeInfo::new_unchecked(inner).into(),
+            1 => Byte32::new_unchecked(inner).into(),
+            _ => panic!("{}: invalid data", Self::NAME),
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> WithdrawalBuyerReader<'r> {
+        WithdrawalBuyerReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for WithdrawalBuyer {
+    type Builder = WithdrawalBuyerBuilder;
+    const NAME: &'static str = "WithdrawalBuyer";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        WithdrawalBuyer(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        WithdrawalBuyerReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        WithdrawalBuyerReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().set(self.to_enum())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct WithdrawalBuyerReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for WithdrawalBuyerReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for WithdrawalBuyerReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for WithdrawalBuyerReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}(", Self::NAME)?;
+        self.to_enum().display_inner(f)?;
+        write!(f, ")")
+    }
+}
+impl<'r> WithdrawalBuyerReader<'r> {
+    pub const ITEMS_COUNT: usize = 2;
+    pub fn item_id(&self) -> molecule::Number {
+        molecule::unpack_number(self.as_slice())
+    }
+    pub fn to_enum(&self) -> WithdrawalBuyerUnionReader<'r> {
+        let inner = &self.as_slice()[molecule::NUMBER_SIZE..];
+        match self.item_id() {
+            0 => WithdrawalSporeInfoReader::new_unchecked(inner).into(),
+            1 => Byte32Reader::new_unchecked(inner).into(),
+            _ => panic!("{}: invalid data", Self::NAME),
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for WithdrawalBuyerReader<'r> {
+    type Entity = WithdrawalBuyer;
+    const NAME: &'static str = "WithdrawalBuyerReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        WithdrawalBuyerReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let item_id = molecule::unpack_number(slice);
+        let inner_slice = &slice[molecule::NUMBER_SIZE..];
+        match item_id {
+            0 => WithdrawalSporeInfoReader::verify(inner_slice, compatible),
+            1 => Byte32Reader::verify(inner_slice, compatible),
+            _ => ve!(Self, UnknownItem, Self::ITEMS_COUNT, item_id),
+        }?;
+        Ok(())
+    }
+}
+#[derive(Clone, Debug, Default)]
+pub struct WithdrawalBuyerBuilder(pub(crate) WithdrawalBuyerUnion);
+impl WithdrawalBuyerBuilder {
+    pub const ITEMS_COUNT: usize = 2;
+    pub fn set<I>(mut self, v: I) -> Self
+    where
+        I: ::core::convert::Into<WithdrawalBuyerUnion>,
+    {
+        self.0 = v.into();
+        self
+    }
+}
+impl molecule::prelude::Builder for WithdrawalBuyerBuilder {
+    type Entity = WithdrawalBuyer;
+    const NAME: &'static str = "WithdrawalBuyerBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE + self.0.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(&molecule::pack_number(self.0.item_id()))?;
+        writer.write_all(self.0.as_slice())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        WithdrawalBuyer::new_unchecked(inner.into())
+    }
+}
+#[derive(Debug, Clone)]
+pub enum WithdrawalBuyerUnion {
+    WithdrawalSporeInfo(WithdrawalSporeInfo),
+    Byte32(Byte32),
+}
+#[derive(Debug, Clone, Copy)]
+pub enum WithdrawalBuyerUnionReader<'r> {
+    WithdrawalSporeInfo(WithdrawalSporeInfoReader<'r>),
+    Byte32(Byte32Reader<'r>),
+}
+impl ::core::default::Default for WithdrawalBuyerUnion {
+    fn default() -> Self {
+        WithdrawalBuyerUnion::WithdrawalSporeInfo(::core::default::Default::default())
+    }
+}
+impl ::core::fmt::Display for WithdrawalBuyerUnion {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            WithdrawalBuyerUnion::WithdrawalSporeInfo(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, WithdrawalSporeInfo::NAME, item)
+            }
+            WithdrawalBuyerUnion::Byte32(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, Byte32::NAME, item)
+            }
+        }
+    }
+}
+impl<'r> ::core::fmt::Display for WithdrawalBuyerUnionReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            WithdrawalBuyerUnionReader::WithdrawalSporeInfo(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, WithdrawalSporeInfo::NAME, item)
+            }
+            WithdrawalBuyerUnionReader::Byte32(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, Byte32::NAME, item)
+            }
+        }
+    }
+}
+impl WithdrawalBuyerUnion {
+    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            WithdrawalBuyerUnion::WithdrawalSporeInfo(ref item) => write!(f, "{}", item),
+            WithdrawalBuyerUnion::Byte32(ref item) => write!(f, "{}", item),
+        }
+    }
+}
+impl<'r> WithdrawalBuyerUnionReader<'r> {
+    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            WithdrawalBuyerUnionReader::WithdrawalSporeInfo(ref item) => write!(f, "{}", item),
+            WithdrawalBuyerUnionReader::Byte32(ref item) => write!(f, "{}", item),
+        }
+    }
+}
+impl ::core::convert::From<WithdrawalSporeInfo> for WithdrawalBuyerUnion {
+    fn from(item: WithdrawalSporeInfo) -> Self {
+        WithdrawalBuyerUnion::WithdrawalSporeInfo(item)
+    }
+}
+impl ::core::convert::From<Byte32> for WithdrawalBuyerUnion {
+    fn from(item: Byte32) -> Self {
+        WithdrawalBuyerUnion::Byte32(item)
+    }
+}
+impl<'r> ::core::convert::From<WithdrawalSporeInfoReader<'r>> for WithdrawalBuyerUnionReader<'r> {
+    fn from(item: WithdrawalSporeInfoReader<'r>) -> Self {
+        WithdrawalBuyerUnionReader::WithdrawalSporeInfo(item)
+    }
+}
+impl<'r> ::core::convert::From<Byte32Reader<'r>> for WithdrawalBuyerUnionReader<'r> {
+    fn from(item: Byte32Reader<'r>) -> Self {
+        WithdrawalBuyerUnionReader::Byte32(item)
+    }
+}
+impl WithdrawalBuyerUnion {
+    pub const NAME: &'static str = "WithdrawalBuyerUnion";
+    pub fn as_bytes(&self) -> molecule::bytes::Bytes {
+        match self {
+            WithdrawalBuyerUnion::WithdrawalSporeInfo(item) => item.as_bytes(),
+            WithdrawalBuyerUnion::Byte32(item) => item.as_bytes(),
+        }
+    }
+    pub fn as_slice(&self) -> &[u8] {
+        match self {
+            WithdrawalBuyerUnion::WithdrawalSporeInfo(item) => item.as_slice(),
+            WithdrawalBuyerUnion::Byte32(item) => item.as_slice(),
+        }
+    }
+    pub fn item_id(&self) -> molecule::Number {
+        match self {
+            WithdrawalBuyerUnion::WithdrawalSporeInfo(_) => 0,
+            WithdrawalBuyerUnion::Byte32(_) => 1,
+        }
+    }
+    pub fn item_name(&self) -> &str {
+        match self {
+            WithdrawalBuyerUnion::WithdrawalSporeInfo(_) => "WithdrawalSporeInfo",
+            WithdrawalBuyerUnion::Byte32(_) => "Byte32",
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> WithdrawalBuyerUnionReader<'r> {
+        match self {
+            WithdrawalBuyerUnion::WithdrawalSporeInfo(item) => item.as_reader().into(),
+            WithdrawalBuyerUnion::Byte32(item) => item.as_reader().into(),
+        }
+    }
+}
+impl<'r> WithdrawalBuyerUnionReader<'r> {
+    pub const NAME: &'r str = "WithdrawalBuyerUnionReader";
+    pub fn as_slice(&self) -> &'r [u8] {
+        match self {
+            WithdrawalBuyerUnionReader::WithdrawalSporeInfo(item) => item.as_slice(),
+            WithdrawalBuyerUnionReader::Byte32(item) => item.as_slice(),
+        }
+    }
+    pub fn item_id(&self) -> molecule::Number {
+        match self {
+            WithdrawalBuyerUnionReader::WithdrawalSporeInfo(_) => 0,
+            WithdrawalBuyerUnionReader::Byte32(_) => 1,
+        }
+    }
+    pub fn item_name(&self) -> &str {
+        match self {
+            WithdrawalBuyerUnionReader::WithdrawalSporeInfo(_) => "WithdrawalSporeInfo",
+            WithdrawalBuyerUnionReader::Byte32(_) => "Byte32",
+        }
+    }
+}
+impl From<WithdrawalSporeInfo> for WithdrawalBuyer {
+    fn from(value: WithdrawalSporeInfo) -> Self {
+        Self::new_builder().set(value).build()
+    }
+}
+impl From<Byte32> for WithdrawalBuyer {
+    fn from(value: Byte32) -> Self {
+        Self::new_builder().set(value).build()
+    }
+}
+#[derive(Clone)]
+pub struct WithdrawalIntentData(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for WithdrawalIntentData {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for WithdrawalIntentData {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for WithdrawalIntentData {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "xudt_script_hash", self.xudt_script_hash())?;
+        write!(
+            f,
+            ", {}: {}",
+            "xudt_lock_script_hash",
+            self.xudt_lock_script_hash()
+        )?;
+        write!(f, ", {}: {}", "buyer", self.buyer())?;
+        write!(f, ", {}: {}", "expire_since", self.expire_since())?;
+        write!(f, ", {}: {}", "owner_script_hash", self.owner_script_hash())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for WithdrawalIntentData {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        WithdrawalIntentData::new_unchecked(v)
+    }
+}
+impl WithdrawalIntentData {
+    const DEFAULT_VALUE: [u8; 229] = [
+        229, 0, 0, 0, 24, 0, 0, 0, 56, 0, 0, 0, 88, 0, 0, 0, 189, 0, 0, 0, 197, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    pub const FIELD_COUNT: usize = 5;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn xudt_script_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn xudt_lock_script_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn buyer(&self) -> WithdrawalBuyer {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        WithdrawalBuyer::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn expire_since(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn owner_script_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[24..]) as usize;
+            Byte32::new_unchecked(self.0.slice(start..end))
+        } else {
+            Byte32::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> WithdrawalIntentDataReader<'r> {
+        WithdrawalIntentDataReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for WithdrawalIntentData {
+    type Builder = WithdrawalIntentDataBuilder;
+    const NAME: &'static str = "WithdrawalIntentData";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        WithdrawalIntentData(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        WithdrawalIntentDataReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        WithdrawalIntentDataReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .xudt_script_hash(self.xudt_script_hash())
+            .xudt_lock_script_hash(self.xudt_lock_script_hash())
+            .buyer(self.buyer())
+            .expire_since(self.expire_since())
+            .owner_script_hash(self.owner_script_hash())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct WithdrawalIntentDataReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for WithdrawalIntentDataReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for WithdrawalIntentDataReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for WithdrawalIntentDataReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "xudt_script_hash", self.xudt_script_hash())?;
+        write!(
+            f,
+            ", {}: {}",
+            "xudt_lock_script_hash",
+            self.xudt_lock_script_hash()
+        )?;
+        write!(f, ", {}: {}", "buyer", self.buyer())?;
+        write!(f, ", {}: {}", "expire_since", self.expire_since())?;
+        write!(f, ", {}: {}", "owner_script_hash", self.owner_script_hash())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> WithdrawalIntentDataReader<'r> {
+    pub const FIELD_COUNT: usize = 5;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn xudt_script_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn xudt_lock_script_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn buyer(&self) -> WithdrawalBuyerReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        WithdrawalBuyerReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn expire_since(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn owner_script_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[24..]) as usize;
+            Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            Byte32Reader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for WithdrawalIntentDataReader<'r> {
+    type Entity = WithdrawalIntentData;
+    const NAME: &'static str = "WithdrawalIntentDataReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        WithdrawalIntentDataReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        Byte32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        WithdrawalBuyerReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Clone, Debug, Default)]
+pub struct WithdrawalIntentDataBuilder {
+    pub(crate) xudt_script_hash: Byte32,
+    pub(crate) xudt_lock_script_hash: Byte32,
+    pub(crate) buyer: WithdrawalBuyer,
+    pub(crate) expire_since: Uint64,
+    pub(crate) owner_script_hash: Byte32,
+}
+impl WithdrawalIntentDataBuilder {
+    pub const FIELD_COUNT: usize = 5;
+    pub fn xudt_script_hash(mut self, v: Byte32) -> Self {
+        self.xudt_script_hash = v;
+        self
+    }
+    pub fn xudt_lock_script_hash(mut self, v: Byte32) -> Self {
+        self.xudt_lock_script_hash = v;
+        self
+    }
+    pub fn buyer(mut self, v: WithdrawalBuyer) -> Self {
+        self.buyer = v;
+        self
+    }
+    pub fn expire_since(mut self, v: Uint64) -> Self {
+        self.expire_since = v;
+        self
+    }
+    pub fn owner_script_hash(mut self, v: Byte32) -> Self {
+        self.owner_script_hash = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for WithdrawalIntentDataBuilder {
+    type Entity = WithdrawalIntentData;
+    const NAME: &'static str = "WithdrawalIntentDataBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.xudt_script_hash.as_slice().len()
+            + self.xudt_lock_script_hash.as_slice().len()
+            + self.buyer.as_slice().len()
+            + self.expire_since.as_slice().len()
+            + self.owner_script_hash.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.xudt_script_hash.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.xudt_lock_script_hash.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.buyer.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.expire_since.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.owner_script_hash.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.xudt_script_hash.as_slice())?;
+        writer.write_all(self.xudt_lock_script_hash.as_slice())?;
+        writer.write_all(self.buyer.as_slice())?;
+        writer.write_all(self.expire_since.as_slice())?;
+        writer.write_all(self.owner_script_hash.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        WithdrawalIntentData::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct MergeSporeInfo(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for MergeSporeInfo {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for MergeSporeInfo {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for MergeSporeInfo {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "burned_spores", self.burned_spores())?;
+        write!(
+            f,
+            ", {}: {}",
+            "burned_withdrawn_udt",
+            self.burned_withdrawn_udt()
+        )?;
+        write!(f, ", {}: {}", "minted_spore", self.minted_spore())?;
+        write!(
+            f,
+            ", {}: {}",
+            "xudt_lock_script_hash",
+            self.xudt_lock_script_hash()
+        )?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for MergeSporeInfo {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        MergeSporeInfo::new_unchecked(v)
+    }
+}
+impl MergeSporeInfo {
+    const DEFAULT_VALUE: [u8; 157] = [
+        157, 0, 0, 0, 20, 0, 0, 0, 24, 0, 0, 0, 28, 0, 0, 0, 125, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    pub const FIELD_COUNT: usize = 4;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn burned_spores(&self) -> WithdrawalSporeInfoVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        WithdrawalSporeInfoVec::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn burned_withdrawn_udt(&self) -> Uint128OptVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Uint128OptVec::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn minted_spore(&self) -> WithdrawalSporeInfo {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        WithdrawalSporeInfo::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn xudt_lock_script_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            Byte32::new_unchecked(self.0.slice(start..end))
+        } else {
+            Byte32::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> MergeSporeInfoReader<'r> {
+        MergeSporeInfoReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for MergeSporeInfo {
+    type Builder = MergeSporeInfoBuilder;
+    const NAME: &'static str = "MergeSporeInfo";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        MergeSporeInfo(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        MergeSporeInfoReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        MergeSporeInfoReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .burned_spores(self.burned_spores())
+            .burned_withdrawn_udt(self.burned_withdrawn_udt())
+            .minted_spore(self.minted_spore())
+            .xudt_lock_script_hash(self.xudt_lock_script_hash())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct MergeSporeInfoReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for MergeSporeInfoReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for MergeSporeInfoReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for MergeSporeInfoReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "burned_spores", self.burned_spores())?;
+        write!(
+            f,
+            ", {}: {}",
+            "burned_withdrawn_udt",
+            self.burned_withdrawn_udt()
+        )?;
+        write!(f, ", {}: {}", "minted_spore", self.minted_spore())?;
+        write!(
+            f,
+            ", {}: {}",
+            "xudt_lock_script_hash",
+            self.xudt_lock_script_hash()
+        )?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> MergeSporeInfoReader<'r> {
+    pub const FIELD_COUNT: usize = 4;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn burned_spores(&self) -> WithdrawalSporeInfoVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        WithdrawalSporeInfoVecReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn burned_withdrawn_udt(&self) -> Uint128OptVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Uint128OptVecReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn minted_spore(&self) -> WithdrawalSporeInfoReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        WithdrawalSporeInfoReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn xudt_lock_script_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            Byte32Reader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for MergeSporeInfoReader<'r> {
+    type Entity = MergeSporeInfo;
+    const NAME: &'static str = "MergeSporeInfoReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        MergeSporeInfoReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
         use molecule::verification_error as ve;
         let slice_len = slice.len();
-        if slice_len != Self::TOTAL_SIZE {
-            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
         }
+        WithdrawalSporeInfoVecReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Uint128OptVecReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        WithdrawalSporeInfoReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
         Ok(())
     }
 }
 #[derive(Clone, Debug, Default)]
-pub struct WithdrawalSporeInfoBuilder {
-    pub(crate) spore_code_hash: Byte32,
-    pub(crate) spore_level: Byte,
-    pub(crate) spore_id: Byte32,
-    pub(crate) cluster_id: Byte32,
+pub struct MergeSporeInfoBuilder {
+    pub(crate) burned_spores: WithdrawalSporeInfoVec,
+    pub(crate) burned_withdrawn_udt: Uint128OptVec,
+    pub(crate) minted_spore: WithdrawalSporeInfo,
+    pub(crate) xudt_lock_script_hash: Byte32,
 }
-impl WithdrawalSporeInfoBuilder {
-    pub const TOTAL_SIZE: usize = 97;
-    pub const FIELD_SIZES: [usize; 4] = [32, 1, 32, 32];
+impl MergeSporeInfoBuilder {
     pub const FIELD_COUNT: usize = 4;
-    pub fn spore_code_hash(mut self, v: Byte32) -> Self {
-        self.spore_code_hash = v;
+    pub fn burned_spores(mut self, v: WithdrawalSporeInfoVec) -> Self {
+        self.burned_spores = v;
         self
     }
-    pub fn spore_level(mut self, v: Byte) -> Self {
-        self.spore_level = v;
+    pub fn burned_withdrawn_udt(mut self, v: Uint128OptVec) -> Self {
+        self.burned_withdrawn_udt = v;
         self
     }
-    pub fn spore_id(mut self, v: Byte32) -> Self {
-        self.spore_id = v;
+    pub fn minted_spore(mut self, v: WithdrawalSporeInfo) -> Self {
+        self.minted_spore = v;
         self
     }
-    pub fn cluster_id(mut self, v: Byte32) -> Self {
-        self.cluster_id = v;
+    pub fn xudt_lock_script_hash(mut self, v: Byte32) -> Self {
+        self.xudt_lock_script_hash = v;
         self
     }
 }
-impl molecule::prelude::Builder for WithdrawalSporeInfoBuilder {
-    type Entity = WithdrawalSporeInfo;
-    const NAME: &'static str = "WithdrawalSporeInfoBuilder";
+impl molecule::prelude::Builder for MergeSporeInfoBuilder {
+    type Entity = MergeSporeInfo;
+    const NAME: &'static str = "MergeSporeInfoBuilder";
     fn expected_length(&self) -> usize {
-        Self::TOTAL_SIZE
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.burned_spores.as_slice().len()
+            + self.burned_withdrawn_udt.as_slice().len()
+            + self.minted_spore.as_slice().len()
+            + self.xudt_lock_script_hash.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        writer.write_all(self.spore_code_hash.as_slice())?;
-        writer.write_all(self.spore_level.as_slice())?;
-        writer.write_all(self.spore_id.as_slice())?;
-        writer.write_all(self.cluster_id.as_slice())?;
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.burned_spores.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.burned_withdrawn_udt.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.minted_spore.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.xudt_lock_script_hash.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.burned_spores.as_slice())?;
+        writer.write_all(self.burned_withdrawn_udt.as_slice())?;
+        writer.write_all(self.minted_spore.as_slice())?;
+        writer.write_all(self.xudt_lock_script_hash.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        WithdrawalSporeInfo::new_unchecked(inner.into())
+        MergeSporeInfo::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct WithdrawalBuyer(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for WithdrawalBuyer {
+pub struct MergeSporeInfoOpt(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for MergeSporeInfoOpt {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -893,52 +5678,50 @@ impl ::core::fmt::LowerHex for WithdrawalBuyer {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for WithdrawalBuyer {
+impl ::core::fmt::Debug for MergeSporeInfoOpt {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for WithdrawalBuyer {
+impl ::core::fmt::Display for MergeSporeInfoOpt {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}(", Self::NAME)?;
-        self.to_enum().display_inner(f)?;
-        write!(f, ")")
+        if let Some(v) = self.to_opt() {
+            write!(f, "{}(Some({}))", Self::NAME, v)
+        } else {
+            write!(f, "{}(None)", Self::NAME)
+        }
     }
 }
-impl ::core::default::Default for WithdrawalBuyer {
+impl ::core::default::Default for MergeSporeInfoOpt {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        WithdrawalBuyer::new_unchecked(v)
+        MergeSporeInfoOpt::new_unchecked(v)
     }
 }
-impl WithdrawalBuyer {
-    const DEFAULT_VALUE: [u8; 101] = [
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    ];
-    pub const ITEMS_COUNT: usize = 2;
-    pub fn item_id(&self) -> molecule::Number {
-        molecule::unpack_number(self.as_slice())
+impl MergeSporeInfoOpt {
+    const DEFAULT_VALUE: [u8; 0] = [];
+    pub fn is_none(&self) -> bool {
+        self.0.is_empty()
     }
-    pub fn to_enum(&self) -> WithdrawalBuyerUnion {
-        let inner = self.0.slice(molecule::NUMBER_SIZE..);
-        match self.item_id() {
-            0 => WithdrawalSporeInfo::new_unchecked(inner).into(),
-            1 => Byte32::new_unchecked(inner).into(),
-            _ => panic!("{}: invalid data", Self::NAME),
+    pub fn is_some(&self) -> bool {
+        !self.0.is_empty()
+    }
+    pub fn to_opt(&self) -> Option<MergeSporeInfo> {
+        if self.is_none() {
+            None
+        } else {
+            Some(MergeSporeInfo::new_unchecked(self.0.clone()))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> WithdrawalBuyerReader<'r> {
-        WithdrawalBuyerReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> MergeSporeInfoOptReader<'r> {
+        MergeSporeInfoOptReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for WithdrawalBuyer {
-    type Builder = WithdrawalBuyerBuilder;
-    const NAME: &'static str = "WithdrawalBuyer";
+impl molecule::prelude::Entity for MergeSporeInfoOpt {
+    type Builder = MergeSporeInfoOptBuilder;
+    const NAME: &'static str = "MergeSporeInfoOpt";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        WithdrawalBuyer(data)
+        MergeSporeInfoOpt(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -947,21 +5730,21 @@ impl molecule::prelude::Entity for WithdrawalBuyer {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        WithdrawalBuyerReader::from_slice(slice).map(|reader| reader.to_entity())
+        MergeSporeInfoOptReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        WithdrawalBuyerReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        MergeSporeInfoOptReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder().set(self.to_enum())
+        Self::new_builder().set(self.to_opt())
     }
 }
 #[derive(Clone, Copy)]
-pub struct WithdrawalBuyerReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for WithdrawalBuyerReader<'r> {
+pub struct MergeSporeInfoOptReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for MergeSporeInfoOptReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -970,231 +5753,92 @@ impl<'r> ::core::fmt::LowerHex for WithdrawalBuyerReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for WithdrawalBuyerReader<'r> {
+impl<'r> ::core::fmt::Debug for MergeSporeInfoOptReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for WithdrawalBuyerReader<'r> {
+impl<'r> ::core::fmt::Display for MergeSporeInfoOptReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}(", Self::NAME)?;
-        self.to_enum().display_inner(f)?;
-        write!(f, ")")
+        if let Some(v) = self.to_opt() {
+            write!(f, "{}(Some({}))", Self::NAME, v)
+        } else {
+            write!(f, "{}(None)", Self::NAME)
+        }
     }
 }
-impl<'r> WithdrawalBuyerReader<'r> {
-    pub const ITEMS_COUNT: usize = 2;
-    pub fn item_id(&self) -> molecule::Number {
-        molecule::unpack_number(self.as_slice())
+impl<'r> MergeSporeInfoOptReader<'r> {
+    pub fn is_none(&self) -> bool {
+        self.0.is_empty()
     }
-    pub fn to_enum(&self) -> WithdrawalBuyerUnionReader<'r> {
-        let inner = &self.as_slice()[molecule::NUMBER_SIZE..];
-        match self.item_id() {
-            0 => WithdrawalSporeInfoReader::new_unchecked(inner).into(),
-            1 => Byte32Reader::new_unchecked(inner).into(),
-            _ => panic!("{}: invalid data", Self::NAME),
+    pub fn is_some(&self) -> bool {
+        !self.0.is_empty()
+    }
+    pub fn to_opt(&self) -> Option<MergeSporeInfoReader<'r>> {
+        if self.is_none() {
+            None
+        } else {
+            Some(MergeSporeInfoReader::new_unchecked(self.as_slice()))
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for WithdrawalBuyerReader<'r> {
-    type Entity = WithdrawalBuyer;
-    const NAME: &'static str = "WithdrawalBuyerReader";
+impl<'r> molecule::prelude::Reader<'r> for MergeSporeInfoOptReader<'r> {
+    type Entity = MergeSporeInfoOpt;
+    const NAME: &'static str = "MergeSporeInfoOptReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        WithdrawalBuyerReader(slice)
+        MergeSporeInfoOptReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
     }
     fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
-        use molecule::verification_error as ve;
-        let slice_len = slice.len();
-        if slice_len < molecule::NUMBER_SIZE {
-            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        if !slice.is_empty() {
+            MergeSporeInfoReader::verify(&slice[..], compatible)?;
         }
-        let item_id = molecule::unpack_number(slice);
-        let inner_slice = &slice[molecule::NUMBER_SIZE..];
-        match item_id {
-            0 => WithdrawalSporeInfoReader::verify(inner_slice, compatible),
-            1 => Byte32Reader::verify(inner_slice, compatible),
-            _ => ve!(Self, UnknownItem, Self::ITEMS_COUNT, item_id),
-        }?;
         Ok(())
     }
 }
 #[derive(Clone, Debug, Default)]
-pub struct WithdrawalBuyerBuilder(pub(crate) WithdrawalBuyerUnion);
-impl WithdrawalBuyerBuilder {
-    pub const ITEMS_COUNT: usize = 2;
-    pub fn set<I>(mut self, v: I) -> Self
-    where
-        I: ::core::convert::Into<WithdrawalBuyerUnion>,
-    {
-        self.0 = v.into();
+pub struct MergeSporeInfoOptBuilder(pub(crate) Option<MergeSporeInfo>);
+impl MergeSporeInfoOptBuilder {
+    pub fn set(mut self, v: Option<MergeSporeInfo>) -> Self {
+        self.0 = v;
         self
     }
 }
-impl molecule::prelude::Builder for WithdrawalBuyerBuilder {
-    type Entity = WithdrawalBuyer;
-    const NAME: &'static str = "WithdrawalBuyerBuilder";
+impl molecule::prelude::Builder for MergeSporeInfoOptBuilder {
+    type Entity = MergeSporeInfoOpt;
+    const NAME: &'static str = "MergeSporeInfoOptBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE + self.0.as_slice().len()
+        self.0
+            .as_ref()
+            .map(|ref inner| inner.as_slice().len())
+            .unwrap_or(0)
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        writer.write_all(&molecule::pack_number(self.0.item_id()))?;
-        writer.write_all(self.0.as_slice())
+        self.0
+            .as_ref()
+            .map(|ref inner| writer.write_all(inner.as_slice()))
+            .unwrap_or(Ok(()))
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        WithdrawalBuyer::new_unchecked(inner.into())
-    }
-}
-#[derive(Debug, Clone)]
-pub enum WithdrawalBuyerUnion {
-    WithdrawalSporeInfo(WithdrawalSporeInfo),
-    Byte32(Byte32),
-}
-#[derive(Debug, Clone, Copy)]
-pub enum WithdrawalBuyerUnionReader<'r> {
-    WithdrawalSporeInfo(WithdrawalSporeInfoReader<'r>),
-    Byte32(Byte32Reader<'r>),
-}
-impl ::core::default::Default for WithdrawalBuyerUnion {
-    fn default() -> Self {
-        WithdrawalBuyerUnion::WithdrawalSporeInfo(::core::default::Default::default())
-    }
-}
-impl ::core::fmt::Display for WithdrawalBuyerUnion {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        match self {
-            WithdrawalBuyerUnion::WithdrawalSporeInfo(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, WithdrawalSporeInfo::NAME, item)
-            }
-            WithdrawalBuyerUnion::Byte32(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, Byte32::NAME, item)
-            }
-        }
-    }
-}
-impl<'r> ::core::fmt::Display for WithdrawalBuyerUnionReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        match self {
-            WithdrawalBuyerUnionReader::WithdrawalSporeInfo(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, WithdrawalSporeInfo::NAME, item)
-            }
-            WithdrawalBuyerUnionReader::Byte32(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, Byte32::NAME, item)
-            }
-        }
-    }
-}
-impl WithdrawalBuyerUnion {
-    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        match self {
-            WithdrawalBuyerUnion::WithdrawalSporeInfo(ref item) => write!(f, "{}", item),
-            WithdrawalBuyerUnion::Byte32(ref item) => write!(f, "{}", item),
-        }
-    }
-}
-impl<'r> WithdrawalBuyerUnionReader<'r> {
-    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        match self {
-            WithdrawalBuyerUnionReader::WithdrawalSporeInfo(ref item) => write!(f, "{}", item),
-            WithdrawalBuyerUnionReader::Byte32(ref item) => write!(f, "{}", item),
-        }
-    }
-}
-impl ::core::convert::From<WithdrawalSporeInfo> for WithdrawalBuyerUnion {
-    fn from(item: WithdrawalSporeInfo) -> Self {
-        WithdrawalBuyerUnion::WithdrawalSporeInfo(item)
-    }
-}
-impl ::core::convert::From<Byte32> for WithdrawalBuyerUnion {
-    fn from(item: Byte32) -> Self {
-        WithdrawalBuyerUnion::Byte32(item)
-    }
-}
-impl<'r> ::core::convert::From<WithdrawalSporeInfoReader<'r>> for WithdrawalBuyerUnionReader<'r> {
-    fn from(item: WithdrawalSporeInfoReader<'r>) -> Self {
-        WithdrawalBuyerUnionReader::WithdrawalSporeInfo(item)
-    }
-}
-impl<'r> ::core::convert::From<Byte32Reader<'r>> for WithdrawalBuyerUnionReader<'r> {
-    fn from(item: Byte32Reader<'r>) -> Self {
-        WithdrawalBuyerUnionReader::Byte32(item)
-    }
-}
-impl WithdrawalBuyerUnion {
-    pub const NAME: &'static str = "WithdrawalBuyerUnion";
-    pub fn as_bytes(&self) -> molecule::bytes::Bytes {
-        match self {
-            WithdrawalBuyerUnion::WithdrawalSporeInfo(item) => item.as_bytes(),
-            WithdrawalBuyerUnion::Byte32(item) => item.as_bytes(),
-        }
-    }
-    pub fn as_slice(&self) -> &[u8] {
-        match self {
-            WithdrawalBuyerUnion::WithdrawalSporeInfo(item) => item.as_slice(),
-            WithdrawalBuyerUnion::Byte32(item) => item.as_slice(),
-        }
-    }
-    pub fn item_id(&self) -> molecule::Number {
-        match self {
-            WithdrawalBuyerUnion::WithdrawalSporeInfo(_) => 0,
-            WithdrawalBuyerUnion::Byte32(_) => 1,
-        }
-    }
-    pub fn item_name(&self) -> &str {
-        match self {
-            WithdrawalBuyerUnion::WithdrawalSporeInfo(_) => "WithdrawalSporeInfo",
-            WithdrawalBuyerUnion::Byte32(_) => "Byte32",
-        }
-    }
-    pub fn as_reader<'r>(&'r self) -> WithdrawalBuyerUnionReader<'r> {
-        match self {
-            WithdrawalBuyerUnion::WithdrawalSporeInfo(item) => item.as_reader().into(),
-            WithdrawalBuyerUnion::Byte32(item) => item.as_reader().into(),
-        }
-    }
-}
-impl<'r> WithdrawalBuyerUnionReader<'r> {
-    pub const NAME: &'r str = "WithdrawalBuyerUnionReader";
-    pub fn as_slice(&self) -> &'r [u8] {
-        match self {
-            WithdrawalBuyerUnionReader::WithdrawalSporeInfo(item) => item.as_slice(),
-            WithdrawalBuyerUnionReader::Byte32(item) => item.as_slice(),
-        }
-    }
-    pub fn item_id(&self) -> molecule::Number {
-        match self {
-            WithdrawalBuyerUnionReader::WithdrawalSporeInfo(_) => 0,
-            WithdrawalBuyerUnionReader::Byte32(_) => 1,
-        }
-    }
-    pub fn item_name(&self) -> &str {
-        match self {
-            WithdrawalBuyerUnionReader::WithdrawalSporeInfo(_) => "WithdrawalSporeInfo",
-            WithdrawalBuyerUnionReader::Byte32(_) => "Byte32",
-        }
-    }
-}
-impl From<WithdrawalSporeInfo> for WithdrawalBuyer {
-    fn from(value: WithdrawalSporeInfo) -> Self {
-        Self::new_builder().set(value).build()
+        MergeSporeInfoOpt::new_unchecked(inner.into())
     }
 }
-impl From<Byte32> for WithdrawalBuyer {
-    fn from(value: Byte32) -> Self {
-        Self::new_builder().set(value).build()
+impl From<MergeSporeInfo> for MergeSporeInfoOpt {
+    fn from(value: MergeSporeInfo) -> Self {
+        Self::new_builder().set(Some(value)).build()
     }
 }
 #[derive(Clone)]
-pub struct WithdrawalIntentData(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for WithdrawalIntentData {
+pub struct SiblingProof(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for SiblingProof {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -1203,24 +5847,21 @@ impl ::core::fmt::LowerHex for WithdrawalIntentData {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for WithdrawalIntentData {
+impl ::core::fmt::Debug for SiblingProof {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for WithdrawalIntentData {
+impl ::core::fmt::Display for SiblingProof {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "xudt_script_hash", self.xudt_script_hash())?;
         write!(
             f,
-            ", {}: {}",
-            "xudt_lock_script_hash",
-            self.xudt_lock_script_hash()
+            "{}: {}",
+            "account_book_script_hash",
+            self.account_book_script_hash()
         )?;
-        write!(f, ", {}: {}", "buyer", self.buyer())?;
-        write!(f, ", {}: {}", "expire_since", self.expire_since())?;
-        write!(f, ", {}: {}", "owner_script_hash", self.owner_script_hash())?;
+        write!(f, ", {}: {}", "proof", self.proof())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -1228,24 +5869,18 @@ impl ::core::fmt::Display for WithdrawalIntentData {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for WithdrawalIntentData {
+impl ::core::default::Default for SiblingProof {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        WithdrawalIntentData::new_unchecked(v)
+        SiblingProof::new_unchecked(v)
     }
 }
-impl WithdrawalIntentData {
-    const DEFAULT_VALUE: [u8; 229] = [
-        229, 0, 0, 0, 24, 0, 0, 0, 56, 0, 0, 0, 88, 0, 0, 0, 189, 0, 0, 0, 197, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+impl SiblingProof {
+    const DEFAULT_VALUE: [u8; 48] = [
+        48, 0, 0, 0, 12, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     ];
-    pub const FIELD_COUNT: usize = 5;
+    pub const FIELD_COUNT: usize = 2;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -1262,49 +5897,31 @@ impl WithdrawalIntentData {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn xudt_script_hash(&self) -> Byte32 {
+    pub fn account_book_script_hash(&self) -> Byte32 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
         Byte32::new_unchecked(self.0.slice(start..end))
     }
-    pub fn xudt_lock_script_hash(&self) -> Byte32 {
+    pub fn proof(&self) -> Bytes {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
-        let end = molecule::unpack_number(&slice[12..]) as usize;
-        Byte32::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn buyer(&self) -> WithdrawalBuyer {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[12..]) as usize;
-        let end = molecule::unpack_number(&slice[16..]) as usize;
-        WithdrawalBuyer::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn expire_since(&self) -> Uint64 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[16..]) as usize;
-        let end = molecule::unpack_number(&slice[20..]) as usize;
-        Uint64::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn owner_script_hash(&self) -> Byte32 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[20..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[24..]) as usize;
-            Byte32::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            Bytes::new_unchecked(self.0.slice(start..end))
         } else {
-            Byte32::new_unchecked(self.0.slice(start..))
+            Bytes::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> WithdrawalIntentDataReader<'r> {
-        WithdrawalIntentDataReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> SiblingProofReader<'r> {
+        SiblingProofReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for WithdrawalIntentData {
-    type Builder = WithdrawalIntentDataBuilder;
-    const NAME: &'static str = "WithdrawalIntentData";
+impl molecule::prelude::Entity for SiblingProof {
+    type Builder = SiblingProofBuilder;
+    const NAME: &'static str = "SiblingProof";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        WithdrawalIntentData(data)
+        SiblingProof(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -1313,26 +5930,23 @@ impl molecule::prelude::Entity for WithdrawalIntentData {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        WithdrawalIntentDataReader::from_slice(slice).map(|reader| reader.to_entity())
+        SiblingProofReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        WithdrawalIntentDataReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        SiblingProofReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
-            .xudt_script_hash(self.xudt_script_hash())
-            .xudt_lock_script_hash(self.xudt_lock_script_hash())
-            .buyer(self.buyer())
-            .expire_since(self.expire_since())
-            .owner_script_hash(self.owner_script_hash())
+            .account_book_script_hash(self.account_book_script_hash())
+            .proof(self.proof())
     }
 }
 #[derive(Clone, Copy)]
-pub struct WithdrawalIntentDataReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for WithdrawalIntentDataReader<'r> {
+pub struct SiblingProofReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for SiblingProofReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -1341,24 +5955,21 @@ impl<'r> ::core::fmt::LowerHex for WithdrawalIntentDataReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for WithdrawalIntentDataReader<'r> {
+impl<'r> ::core::fmt::Debug for SiblingProofReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for WithdrawalIntentDataReader<'r> {
+impl<'r> ::core::fmt::Display for SiblingProofReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "xudt_script_hash", self.xudt_script_hash())?;
         write!(
             f,
-            ", {}: {}",
-            "xudt_lock_script_hash",
-            self.xudt_lock_script_hash()
+            "{}: {}",
+            "account_book_script_hash",
+            self.account_book_script_hash()
         )?;
-        write!(f, ", {}: {}", "buyer", self.buyer())?;
-        write!(f, ", {}: {}", "expire_since", self.expire_since())?;
-        write!(f, ", {}: {}", "owner_script_hash", self.owner_script_hash())?;
+        write!(f, ", {}: {}", "proof", self.proof())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -1366,8 +5977,8 @@ impl<'r> ::core::fmt::Display for WithdrawalIntentDataReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> WithdrawalIntentDataReader<'r> {
-    pub const FIELD_COUNT: usize = 5;
+impl<'r> SiblingProofReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -1384,49 +5995,295 @@ impl<'r> WithdrawalIntentDataReader<'r> {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn xudt_script_hash(&self) -> Byte32Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[4..]) as usize;
-        let end = molecule::unpack_number(&slice[8..]) as usize;
-        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    pub fn account_book_script_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn proof(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            BytesReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            BytesReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for SiblingProofReader<'r> {
+    type Entity = SiblingProof;
+    const NAME: &'static str = "SiblingProofReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        SiblingProofReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        Byte32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        BytesReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Clone, Debug, Default)]
+pub struct SiblingProofBuilder {
+    pub(crate) account_book_script_hash: Byte32,
+    pub(crate) proof: Bytes,
+}
+impl SiblingProofBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn account_book_script_hash(mut self, v: Byte32) -> Self {
+        self.account_book_script_hash = v;
+        self
+    }
+    pub fn proof(mut self, v: Bytes) -> Self {
+        self.proof = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for SiblingProofBuilder {
+    type Entity = SiblingProof;
+    const NAME: &'static str = "SiblingProofBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.account_book_script_hash.as_slice().len()
+            + self.proof.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.account_book_script_hash.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.proof.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.account_book_script_hash.as_slice())?;
+        writer.write_all(self.proof.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        SiblingProof::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct SiblingProofVec(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for SiblingProofVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for SiblingProofVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for SiblingProofVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl ::core::default::Default for SiblingProofVec {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        SiblingProofVec::new_unchecked(v)
+    }
+}
+impl SiblingProofVec {
+    const DEFAULT_VALUE: [u8; 4] = [4, 0, 0, 0];
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<SiblingProof> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> SiblingProof {
+        let slice = self.as_slice();
+        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
+        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
+        if idx == self.len() - 1 {
+            SiblingProof::new_unchecked(self.0.slice(start..))
+        } else {
+            let end_idx = start_idx + molecule::NUMBER_SIZE;
+            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
+            SiblingProof::new_unchecked(self.0.slice(start..end))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> SiblingProofVecReader<'r> {
+        SiblingProofVecReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for SiblingProofVec {
+    type Builder = SiblingProofVecBuilder;
+    const NAME: &'static str = "SiblingProofVec";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        SiblingProofVec(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SiblingProofVecReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SiblingProofVecReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().extend(self.into_iter())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct SiblingProofVecReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for SiblingProofVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for SiblingProofVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for SiblingProofVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl<'r> SiblingProofVecReader<'r> {
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
     }
-    pub fn xudt_lock_script_hash(&self) -> Byte32Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
-        let end = molecule::unpack_number(&slice[12..]) as usize;
-        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    pub fn len(&self) -> usize {
+        self.item_count()
     }
-    pub fn buyer(&self) -> WithdrawalBuyerReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[12..]) as usize;
-        let end = molecule::unpack_number(&slice[16..]) as usize;
-        WithdrawalBuyerReader::new_unchecked(&self.as_slice()[start..end])
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
-    pub fn expire_since(&self) -> Uint64Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[16..]) as usize;
-        let end = molecule::unpack_number(&slice[20..]) as usize;
-        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    pub fn get(&self, idx: usize) -> Option<SiblingProofReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
     }
-    pub fn owner_script_hash(&self) -> Byte32Reader<'r> {
+    pub fn get_unchecked(&self, idx: usize) -> SiblingProofReader<'r> {
         let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[20..]) as usize;
-        if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[24..]) as usize;
-            Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
+        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
+        if idx == self.len() - 1 {
+            SiblingProofReader::new_unchecked(&self.as_slice()[start..])
         } else {
-            Byte32Reader::new_unchecked(&self.as_slice()[start..])
+            let end_idx = start_idx + molecule::NUMBER_SIZE;
+            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
+            SiblingProofReader::new_unchecked(&self.as_slice()[start..end])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for WithdrawalIntentDataReader<'r> {
-    type Entity = WithdrawalIntentData;
-    const NAME: &'static str = "WithdrawalIntentDataReader";
+impl<'r> molecule::prelude::Reader<'r> for SiblingProofVecReader<'r> {
+    type Entity = SiblingProofVec;
+    const NAME: &'static str = "SiblingProofVecReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        WithdrawalIntentDataReader(slice)
+        SiblingProofVecReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -1441,8 +6298,16 @@ impl<'r> molecule::prelude::Reader<'r> for WithdrawalIntentDataReader<'r> {
         if slice_len != total_size {
             return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
         }
+        if slice_len == molecule::NUMBER_SIZE {
+            return Ok(());
+        }
         if slice_len < molecule::NUMBER_SIZE * 2 {
-            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+            return ve!(
+                Self,
+                TotalSizeNotMatch,
+                molecule::NUMBER_SIZE * 2,
+                slice_len
+            );
         }
         let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
         if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
@@ -1451,12 +6316,6 @@ impl<'r> molecule::prelude::Reader<'r> for WithdrawalIntentDataReader<'r> {
         if slice_len < offset_first {
             return ve!(Self, HeaderIsBroken, offset_first, slice_len);
         }
-        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
-        if field_count < Self::FIELD_COUNT {
-            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
-        } else if !compatible && field_count > Self::FIELD_COUNT {
-            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
-        };
         let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
             .chunks_exact(molecule::NUMBER_SIZE)
             .map(|x| molecule::unpack_number(x) as usize)
@@ -1465,85 +6324,134 @@ impl<'r> molecule::prelude::Reader<'r> for WithdrawalIntentDataReader<'r> {
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        Byte32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        Byte32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
-        WithdrawalBuyerReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
-        Uint64Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
-        Byte32Reader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        for pair in offsets.windows(2) {
+            let start = pair[0];
+            let end = pair[1];
+            SiblingProofReader::verify(&slice[start..end], compatible)?;
+        }
         Ok(())
     }
 }
 #[derive(Clone, Debug, Default)]
-pub struct WithdrawalIntentDataBuilder {
-    pub(crate) xudt_script_hash: Byte32,
-    pub(crate) xudt_lock_script_hash: Byte32,
-    pub(crate) buyer: WithdrawalBuyer,
-    pub(crate) expire_since: Uint64,
-    pub(crate) owner_script_hash: Byte32,
-}
-impl WithdrawalIntentDataBuilder {
-    pub const FIELD_COUNT: usize = 5;
-    pub fn xudt_script_hash(mut self, v: Byte32) -> Self {
-        self.xudt_script_hash = v;
-        self
-    }
-    pub fn xudt_lock_script_hash(mut self, v: Byte32) -> Self {
-        self.xudt_lock_script_hash = v;
+pub struct SiblingProofVecBuilder(pub(crate) Vec<SiblingProof>);
+impl SiblingProofVecBuilder {
+    pub fn set(mut self, v: Vec<SiblingProof>) -> Self {
+        self.0 = v;
         self
     }
-    pub fn buyer(mut self, v: WithdrawalBuyer) -> Self {
-        self.buyer = v;
+    pub fn push(mut self, v: SiblingProof) -> Self {
+        self.0.push(v);
         self
     }
-    pub fn expire_since(mut self, v: Uint64) -> Self {
-        self.expire_since = v;
+    pub fn extend<T: ::core::iter::IntoIterator<Item = SiblingProof>>(mut self, iter: T) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
         self
     }
-    pub fn owner_script_hash(mut self, v: Byte32) -> Self {
-        self.owner_script_hash = v;
-        self
+    pub fn replace(&mut self, index: usize, v: SiblingProof) -> Option<SiblingProof> {
+        self.0
+            .get_mut(index)
+            .map(|item| ::core::mem::replace(item, v))
     }
 }
-impl molecule::prelude::Builder for WithdrawalIntentDataBuilder {
-    type Entity = WithdrawalIntentData;
-    const NAME: &'static str = "WithdrawalIntentDataBuilder";
+impl molecule::prelude::Builder for SiblingProofVecBuilder {
+    type Entity = SiblingProofVec;
+    const NAME: &'static str = "SiblingProofVecBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.xudt_script_hash.as_slice().len()
-            + self.xudt_lock_script_hash.as_slice().len()
-            + self.buyer.as_slice().len()
-            + self.expire_since.as_slice().len()
-            + self.owner_script_hash.as_slice().len()
+        molecule::NUMBER_SIZE * (self.0.len() + 1)
+            + self
+                .0
+                .iter()
+                .map(|inner| inner.as_slice().len())
+                .sum::<usize>()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
-        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
-        offsets.push(total_size);
-        total_size += self.xudt_script_hash.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.xudt_lock_script_hash.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.buyer.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.expire_since.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.owner_script_hash.as_slice().len();
-        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
-        for offset in offsets.into_iter() {
-            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        let item_count = self.0.len();
+        if item_count == 0 {
+            writer.write_all(&molecule::pack_number(
+                molecule::NUMBER_SIZE as molecule::Number,
+            ))?;
+        } else {
+            let (total_size, offsets) = self.0.iter().fold(
+                (
+                    molecule::NUMBER_SIZE * (item_count + 1),
+                    Vec::with_capacity(item_count),
+                ),
+                |(start, mut offsets), inner| {
+                    offsets.push(start);
+                    (start + inner.as_slice().len(), offsets)
+                },
+            );
+            writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+            for offset in offsets.into_iter() {
+                writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+            }
+            for inner in self.0.iter() {
+                writer.write_all(inner.as_slice())?;
+            }
         }
-        writer.write_all(self.xudt_script_hash.as_slice())?;
-        writer.write_all(self.xudt_lock_script_hash.as_slice())?;
-        writer.write_all(self.buyer.as_slice())?;
-        writer.write_all(self.expire_since.as_slice())?;
-        writer.write_all(self.owner_script_hash.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        WithdrawalIntentData::new_unchecked(inner.into())
+        SiblingProofVec::new_unchecked(inner.into())
+    }
+}
+pub struct SiblingProofVecIterator(SiblingProofVec, usize, usize);
+impl ::core::iter::Iterator for SiblingProofVecIterator {
+    type Item = SiblingProof;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::core::iter::ExactSizeIterator for SiblingProofVecIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for SiblingProofVec {
+    type Item = SiblingProof;
+    type IntoIter = SiblingProofVecIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        SiblingProofVecIterator(self, 0, len)
+    }
+}
+impl<'r> SiblingProofVecReader<'r> {
+    pub fn iter<'t>(&'t self) -> SiblingProofVecReaderIterator<'t, 'r> {
+        SiblingProofVecReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct SiblingProofVecReaderIterator<'t, 'r>(&'t SiblingProofVecReader<'r>, usize, usize);
+impl<'t: 'r, 'r> ::core::iter::Iterator for SiblingProofVecReaderIterator<'t, 'r> {
+    type Item = SiblingProofReader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for SiblingProofVecReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::FromIterator<SiblingProof> for SiblingProofVec {
+    fn from_iter<T: IntoIterator<Item = SiblingProof>>(iter: T) -> Self {
+        Self::new_builder().extend(iter).build()
     }
 }
 #[derive(Clone)]
@@ -1567,7 +6475,29 @@ impl ::core::fmt::Display for AccountBookData {
         write!(f, "{} {{ ", Self::NAME)?;
         write!(f, "{}: {}", "proof", self.proof())?;
         write!(f, ", {}: {}", "total_income_udt", self.total_income_udt())?;
+        write!(
+            f,
+            ", {}: {}",
+            "total_withdrawn_udt",
+            self.total_withdrawn_udt()
+        )?;
         write!(f, ", {}: {}", "withdrawn_udt", self.withdrawn_udt())?;
+        write!(f, ", {}: {}", "swept_spore", self.swept_spore())?;
+        write!(f, ", {}: {}", "retired_spore", self.retired_spore())?;
+        write!(f, ", {}: {}", "merged_spores", self.merged_spores())?;
+        write!(f, ", {}: {}", "upgraded_spore", self.upgraded_spore())?;
+        write!(f, ", {}: {}", "deposited_udt", self.deposited_udt())?;
+        write!(f, ", {}: {}", "platform_fee_udt", self.platform_fee_udt())?;
+        write!(f, ", {}: {}", "buyer_lock_bought", self.buyer_lock_bought())?;
+        write!(f, ", {}: {}", "reserved_spore", self.reserved_spore())?;
+        write!(
+            f,
+            ", {}: {}",
+            "approved_dna_proof",
+            self.approved_dna_proof()
+        )?;
+        write!(f, ", {}: {}", "registered_spore", self.registered_spore())?;
+        write!(f, ", {}: {}", "sibling_proofs", self.sibling_proofs())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -1582,11 +6512,13 @@ impl ::core::default::Default for AccountBookData {
     }
 }
 impl AccountBookData {
-    const DEFAULT_VALUE: [u8; 36] = [
-        36, 0, 0, 0, 16, 0, 0, 0, 20, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0,
+    const DEFAULT_VALUE: [u8; 108] = [
+        108, 0, 0, 0, 64, 0, 0, 0, 68, 0, 0, 0, 84, 0, 0, 0, 100, 0, 0, 0, 100, 0, 0, 0, 100, 0, 0,
+        0, 100, 0, 0, 0, 100, 0, 0, 0, 100, 0, 0, 0, 100, 0, 0, 0, 100, 0, 0, 0, 100, 0, 0, 0, 100,
+        0, 0, 0, 104, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0,
     ];
-    pub const FIELD_COUNT: usize = 3;
+    pub const FIELD_COUNT: usize = 15;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -1615,14 +6547,86 @@ impl AccountBookData {
         let end = molecule::unpack_number(&slice[12..]) as usize;
         Uint128::new_unchecked(self.0.slice(start..end))
     }
-    pub fn withdrawn_udt(&self) -> Uint128Opt {
+    pub fn total_withdrawn_udt(&self) -> Uint128 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Uint128::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn withdrawn_udt(&self) -> Uint128Opt {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Uint128Opt::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn swept_spore(&self) -> WithdrawalSporeInfoOpt {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        WithdrawalSporeInfoOpt::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn retired_spore(&self) -> WithdrawalSporeInfoOpt {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        WithdrawalSporeInfoOpt::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn merged_spores(&self) -> MergeSporeInfoOpt {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let end = molecule::unpack_number(&slice[32..]) as usize;
+        MergeSporeInfoOpt::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn upgraded_spore(&self) -> MergeSporeInfoOpt {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[32..]) as usize;
+        let end = molecule::unpack_number(&slice[36..]) as usize;
+        MergeSporeInfoOpt::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn deposited_udt(&self) -> Uint128Opt {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[36..]) as usize;
+        let end = molecule::unpack_number(&slice[40..]) as usize;
+        Uint128Opt::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn platform_fee_udt(&self) -> Uint128Opt {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[40..]) as usize;
+        let end = molecule::unpack_number(&slice[44..]) as usize;
+        Uint128Opt::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn buyer_lock_bought(&self) -> Uint128Opt {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[44..]) as usize;
+        let end = molecule::unpack_number(&slice[48..]) as usize;
+        Uint128Opt::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn reserved_spore(&self) -> WithdrawalSporeInfoOpt {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[48..]) as usize;
+        let end = molecule::unpack_number(&slice[52..]) as usize;
+        WithdrawalSporeInfoOpt::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn approved_dna_proof(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[52..]) as usize;
+        let end = molecule::unpack_number(&slice[56..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn registered_spore(&self) -> WithdrawalSporeInfoOpt {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[56..]) as usize;
+        let end = molecule::unpack_number(&slice[60..]) as usize;
+        WithdrawalSporeInfoOpt::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn sibling_proofs(&self) -> SiblingProofVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[60..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[16..]) as usize;
-            Uint128Opt::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[64..]) as usize;
+            SiblingProofVec::new_unchecked(self.0.slice(start..end))
         } else {
-            Uint128Opt::new_unchecked(self.0.slice(start..))
+            SiblingProofVec::new_unchecked(self.0.slice(start..))
         }
     }
     pub fn as_reader<'r>(&'r self) -> AccountBookDataReader<'r> {
@@ -1654,7 +6658,19 @@ impl molecule::prelude::Entity for AccountBookData {
         Self::new_builder()
             .proof(self.proof())
             .total_income_udt(self.total_income_udt())
+            .total_withdrawn_udt(self.total_withdrawn_udt())
             .withdrawn_udt(self.withdrawn_udt())
+            .swept_spore(self.swept_spore())
+            .retired_spore(self.retired_spore())
+            .merged_spores(self.merged_spores())
+            .upgraded_spore(self.upgraded_spore())
+            .deposited_udt(self.deposited_udt())
+            .platform_fee_udt(self.platform_fee_udt())
+            .buyer_lock_bought(self.buyer_lock_bought())
+            .reserved_spore(self.reserved_spore())
+            .approved_dna_proof(self.approved_dna_proof())
+            .registered_spore(self.registered_spore())
+            .sibling_proofs(self.sibling_proofs())
     }
 }
 #[derive(Clone, Copy)]
@@ -1678,7 +6694,29 @@ impl<'r> ::core::fmt::Display for AccountBookDataReader<'r> {
         write!(f, "{} {{ ", Self::NAME)?;
         write!(f, "{}: {}", "proof", self.proof())?;
         write!(f, ", {}: {}", "total_income_udt", self.total_income_udt())?;
+        write!(
+            f,
+            ", {}: {}",
+            "total_withdrawn_udt",
+            self.total_withdrawn_udt()
+        )?;
         write!(f, ", {}: {}", "withdrawn_udt", self.withdrawn_udt())?;
+        write!(f, ", {}: {}", "swept_spore", self.swept_spore())?;
+        write!(f, ", {}: {}", "retired_spore", self.retired_spore())?;
+        write!(f, ", {}: {}", "merged_spores", self.merged_spores())?;
+        write!(f, ", {}: {}", "upgraded_spore", self.upgraded_spore())?;
+        write!(f, ", {}: {}", "deposited_udt", self.deposited_udt())?;
+        write!(f, ", {}: {}", "platform_fee_udt", self.platform_fee_udt())?;
+        write!(f, ", {}: {}", "buyer_lock_bought", self.buyer_lock_bought())?;
+        write!(f, ", {}: {}", "reserved_spore", self.reserved_spore())?;
+        write!(
+            f,
+            ", {}: {}",
+            "approved_dna_proof",
+            self.approved_dna_proof()
+        )?;
+        write!(f, ", {}: {}", "registered_spore", self.registered_spore())?;
+        write!(f, ", {}: {}", "sibling_proofs", self.sibling_proofs())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -1687,7 +6725,7 @@ impl<'r> ::core::fmt::Display for AccountBookDataReader<'r> {
     }
 }
 impl<'r> AccountBookDataReader<'r> {
-    pub const FIELD_COUNT: usize = 3;
+    pub const FIELD_COUNT: usize = 15;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -1704,26 +6742,98 @@ impl<'r> AccountBookDataReader<'r> {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn proof(&self) -> BytesReader<'r> {
+    pub fn proof(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn total_income_udt(&self) -> Uint128Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Uint128Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn total_withdrawn_udt(&self) -> Uint128Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Uint128Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn withdrawn_udt(&self) -> Uint128OptReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Uint128OptReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn swept_spore(&self) -> WithdrawalSporeInfoOptReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        WithdrawalSporeInfoOptReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn retired_spore(&self) -> WithdrawalSporeInfoOptReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        WithdrawalSporeInfoOptReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn merged_spores(&self) -> MergeSporeInfoOptReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let end = molecule::unpack_number(&slice[32..]) as usize;
+        MergeSporeInfoOptReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn upgraded_spore(&self) -> MergeSporeInfoOptReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[32..]) as usize;
+        let end = molecule::unpack_number(&slice[36..]) as usize;
+        MergeSporeInfoOptReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn deposited_udt(&self) -> Uint128OptReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[36..]) as usize;
+        let end = molecule::unpack_number(&slice[40..]) as usize;
+        Uint128OptReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn platform_fee_udt(&self) -> Uint128OptReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[40..]) as usize;
+        let end = molecule::unpack_number(&slice[44..]) as usize;
+        Uint128OptReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn buyer_lock_bought(&self) -> Uint128OptReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[44..]) as usize;
+        let end = molecule::unpack_number(&slice[48..]) as usize;
+        Uint128OptReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn reserved_spore(&self) -> WithdrawalSporeInfoOptReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[48..]) as usize;
+        let end = molecule::unpack_number(&slice[52..]) as usize;
+        WithdrawalSporeInfoOptReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn approved_dna_proof(&self) -> BytesReader<'r> {
         let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[4..]) as usize;
-        let end = molecule::unpack_number(&slice[8..]) as usize;
+        let start = molecule::unpack_number(&slice[52..]) as usize;
+        let end = molecule::unpack_number(&slice[56..]) as usize;
         BytesReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn total_income_udt(&self) -> Uint128Reader<'r> {
+    pub fn registered_spore(&self) -> WithdrawalSporeInfoOptReader<'r> {
         let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
-        let end = molecule::unpack_number(&slice[12..]) as usize;
-        Uint128Reader::new_unchecked(&self.as_slice()[start..end])
+        let start = molecule::unpack_number(&slice[56..]) as usize;
+        let end = molecule::unpack_number(&slice[60..]) as usize;
+        WithdrawalSporeInfoOptReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn withdrawn_udt(&self) -> Uint128OptReader<'r> {
+    pub fn sibling_proofs(&self) -> SiblingProofVecReader<'r> {
         let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let start = molecule::unpack_number(&slice[60..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[16..]) as usize;
-            Uint128OptReader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[64..]) as usize;
+            SiblingProofVecReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            Uint128OptReader::new_unchecked(&self.as_slice()[start..])
+            SiblingProofVecReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
@@ -1775,7 +6885,19 @@ impl<'r> molecule::prelude::Reader<'r> for AccountBookDataReader<'r> {
         }
         BytesReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
         Uint128Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
-        Uint128OptReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Uint128Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Uint128OptReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        WithdrawalSporeInfoOptReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        WithdrawalSporeInfoOptReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
+        MergeSporeInfoOptReader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
+        MergeSporeInfoOptReader::verify(&slice[offsets[7]..offsets[8]], compatible)?;
+        Uint128OptReader::verify(&slice[offsets[8]..offsets[9]], compatible)?;
+        Uint128OptReader::verify(&slice[offsets[9]..offsets[10]], compatible)?;
+        Uint128OptReader::verify(&slice[offsets[10]..offsets[11]], compatible)?;
+        WithdrawalSporeInfoOptReader::verify(&slice[offsets[11]..offsets[12]], compatible)?;
+        BytesReader::verify(&slice[offsets[12]..offsets[13]], compatible)?;
+        WithdrawalSporeInfoOptReader::verify(&slice[offsets[13]..offsets[14]], compatible)?;
+        SiblingProofVecReader::verify(&slice[offsets[14]..offsets[15]], compatible)?;
         Ok(())
     }
 }
@@ -1783,10 +6905,22 @@ impl<'r> molecule::prelude::Reader<'r> for AccountBookDataReader<'r> {
 pub struct AccountBookDataBuilder {
     pub(crate) proof: Bytes,
     pub(crate) total_income_udt: Uint128,
+    pub(crate) total_withdrawn_udt: Uint128,
     pub(crate) withdrawn_udt: Uint128Opt,
+    pub(crate) swept_spore: WithdrawalSporeInfoOpt,
+    pub(crate) retired_spore: WithdrawalSporeInfoOpt,
+    pub(crate) merged_spores: MergeSporeInfoOpt,
+    pub(crate) upgraded_spore: MergeSporeInfoOpt,
+    pub(crate) deposited_udt: Uint128Opt,
+    pub(crate) platform_fee_udt: Uint128Opt,
+    pub(crate) buyer_lock_bought: Uint128Opt,
+    pub(crate) reserved_spore: WithdrawalSporeInfoOpt,
+    pub(crate) approved_dna_proof: Bytes,
+    pub(crate) registered_spore: WithdrawalSporeInfoOpt,
+    pub(crate) sibling_proofs: SiblingProofVec,
 }
 impl AccountBookDataBuilder {
-    pub const FIELD_COUNT: usize = 3;
+    pub const FIELD_COUNT: usize = 15;
     pub fn proof(mut self, v: Bytes) -> Self {
         self.proof = v;
         self
@@ -1795,10 +6929,58 @@ impl AccountBookDataBuilder {
         self.total_income_udt = v;
         self
     }
+    pub fn total_withdrawn_udt(mut self, v: Uint128) -> Self {
+        self.total_withdrawn_udt = v;
+        self
+    }
     pub fn withdrawn_udt(mut self, v: Uint128Opt) -> Self {
         self.withdrawn_udt = v;
         self
     }
+    pub fn swept_spore(mut self, v: WithdrawalSporeInfoOpt) -> Self {
+        self.swept_spore = v;
+        self
+    }
+    pub fn retired_spore(mut self, v: WithdrawalSporeInfoOpt) -> Self {
+        self.retired_spore = v;
+        self
+    }
+    pub fn merged_spores(mut self, v: MergeSporeInfoOpt) -> Self {
+        self.merged_spores = v;
+        self
+    }
+    pub fn upgraded_spore(mut self, v: MergeSporeInfoOpt) -> Self {
+        self.upgraded_spore = v;
+        self
+    }
+    pub fn deposited_udt(mut self, v: Uint128Opt) -> Self {
+        self.deposited_udt = v;
+        self
+    }
+    pub fn platform_fee_udt(mut self, v: Uint128Opt) -> Self {
+        self.platform_fee_udt = v;
+        self
+    }
+    pub fn buyer_lock_bought(mut self, v: Uint128Opt) -> Self {
+        self.buyer_lock_bought = v;
+        self
+    }
+    pub fn reserved_spore(mut self, v: WithdrawalSporeInfoOpt) -> Self {
+        self.reserved_spore = v;
+        self
+    }
+    pub fn approved_dna_proof(mut self, v: Bytes) -> Self {
+        self.approved_dna_proof = v;
+        self
+    }
+    pub fn registered_spore(mut self, v: WithdrawalSporeInfoOpt) -> Self {
+        self.registered_spore = v;
+        self
+    }
+    pub fn sibling_proofs(mut self, v: SiblingProofVec) -> Self {
+        self.sibling_proofs = v;
+        self
+    }
 }
 impl molecule::prelude::Builder for AccountBookDataBuilder {
     type Entity = AccountBookData;
@@ -1807,7 +6989,19 @@ impl molecule::prelude::Builder for AccountBookDataBuilder {
         molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
             + self.proof.as_slice().len()
             + self.total_income_udt.as_slice().len()
+            + self.total_withdrawn_udt.as_slice().len()
             + self.withdrawn_udt.as_slice().len()
+            + self.swept_spore.as_slice().len()
+            + self.retired_spore.as_slice().len()
+            + self.merged_spores.as_slice().len()
+            + self.upgraded_spore.as_slice().len()
+            + self.deposited_udt.as_slice().len()
+            + self.platform_fee_udt.as_slice().len()
+            + self.buyer_lock_bought.as_slice().len()
+            + self.reserved_spore.as_slice().len()
+            + self.approved_dna_proof.as_slice().len()
+            + self.registered_spore.as_slice().len()
+            + self.sibling_proofs.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
@@ -1817,14 +7011,50 @@ impl molecule::prelude::Builder for AccountBookDataBuilder {
         offsets.push(total_size);
         total_size += self.total_income_udt.as_slice().len();
         offsets.push(total_size);
+        total_size += self.total_withdrawn_udt.as_slice().len();
+        offsets.push(total_size);
         total_size += self.withdrawn_udt.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.swept_spore.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.retired_spore.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.merged_spores.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.upgraded_spore.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.deposited_udt.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.platform_fee_udt.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.buyer_lock_bought.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.reserved_spore.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.approved_dna_proof.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.registered_spore.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.sibling_proofs.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
         writer.write_all(self.proof.as_slice())?;
         writer.write_all(self.total_income_udt.as_slice())?;
+        writer.write_all(self.total_withdrawn_udt.as_slice())?;
         writer.write_all(self.withdrawn_udt.as_slice())?;
+        writer.write_all(self.swept_spore.as_slice())?;
+        writer.write_all(self.retired_spore.as_slice())?;
+        writer.write_all(self.merged_spores.as_slice())?;
+        writer.write_all(self.upgraded_spore.as_slice())?;
+        writer.write_all(self.deposited_udt.as_slice())?;
+        writer.write_all(self.platform_fee_udt.as_slice())?;
+        writer.write_all(self.buyer_lock_bought.as_slice())?;
+        writer.write_all(self.reserved_spore.as_slice())?;
+        writer.write_all(self.approved_dna_proof.as_slice())?;
+        writer.write_all(self.registered_spore.as_slice())?;
+        writer.write_all(self.sibling_proofs.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
@@ -1897,6 +7127,61 @@ impl ::core::fmt::Display for AccountBookCellData {
             "profit_distribution_number",
             self.profit_distribution_number()
         )?;
+        write!(
+            f,
+            ", {}: {}",
+            "auther_vesting_start",
+            self.auther_vesting_start()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "auther_vesting_duration",
+            self.auther_vesting_duration()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "claim_deadline_since",
+            self.claim_deadline_since()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "retired_withdrawn_udt",
+            self.retired_withdrawn_udt()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "minted_withdrawn_udt",
+            self.minted_withdrawn_udt()
+        )?;
+        write!(f, ", {}: {}", "merge_spore_count", self.merge_spore_count())?;
+        write!(f, ", {}: {}", "level_prices", self.level_prices())?;
+        write!(f, ", {}: {}", "co_authors", self.co_authors())?;
+        write!(
+            f,
+            ", {}: {}",
+            "withdrawal_fee_udt",
+            self.withdrawal_fee_udt()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "withdrawal_fee_ratio",
+            self.withdrawal_fee_ratio()
+        )?;
+        write!(f, ", {}: {}", "level_rule", self.level_rule())?;
+        write!(f, ", {}: {}", "max_per_buyer", self.max_per_buyer())?;
+        write!(f, ", {}: {}", "reserved_count", self.reserved_count())?;
+        write!(f, ", {}: {}", "approved_dna_root", self.approved_dna_root())?;
+        write!(
+            f,
+            ", {}: {}",
+            "coupon_pubkey_hash",
+            self.coupon_pubkey_hash()
+        )?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -1911,10 +7196,12 @@ impl ::core::default::Default for AccountBookCellData {
     }
 }
 impl AccountBookCellData {
-    const DEFAULT_VALUE: [u8; 377] = [
-        121, 1, 0, 0, 60, 0, 0, 0, 92, 0, 0, 0, 96, 0, 0, 0, 128, 0, 0, 0, 160, 0, 0, 0, 192, 0, 0,
-        0, 224, 0, 0, 0, 0, 1, 0, 0, 32, 1, 0, 0, 33, 1, 0, 0, 65, 1, 0, 0, 97, 1, 0, 0, 113, 1, 0,
-        0, 117, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    const DEFAULT_VALUE: [u8; 567] = [
+        55, 2, 0, 0, 120, 0, 0, 0, 152, 0, 0, 0, 156, 0, 0, 0, 188, 0, 0, 0, 220, 0, 0, 0, 252, 0,
+        0, 0, 28, 1, 0, 0, 60, 1, 0, 0, 92, 1, 0, 0, 93, 1, 0, 0, 125, 1, 0, 0, 157, 1, 0, 0, 173,
+        1, 0, 0, 177, 1, 0, 0, 181, 1, 0, 0, 189, 1, 0, 0, 197, 1, 0, 0, 205, 1, 0, 0, 209, 1, 0,
+        0, 213, 1, 0, 0, 214, 1, 0, 0, 218, 1, 0, 0, 222, 1, 0, 0, 238, 1, 0, 0, 239, 1, 0, 0, 239,
+        1, 0, 0, 243, 1, 0, 0, 247, 1, 0, 0, 23, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
@@ -1924,9 +7211,14 @@ impl AccountBookCellData {
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     ];
-    pub const FIELD_COUNT: usize = 14;
+    pub const FIELD_COUNT: usize = 29;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -2024,11 +7316,101 @@ impl AccountBookCellData {
     pub fn profit_distribution_number(&self) -> Bytes {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[56..]) as usize;
+        let end = molecule::unpack_number(&slice[60..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn auther_vesting_start(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[60..]) as usize;
+        let end = molecule::unpack_number(&slice[64..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn auther_vesting_duration(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[64..]) as usize;
+        let end = molecule::unpack_number(&slice[68..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn claim_deadline_since(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[68..]) as usize;
+        let end = molecule::unpack_number(&slice[72..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn retired_withdrawn_udt(&self) -> Uint128Vec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[72..]) as usize;
+        let end = molecule::unpack_number(&slice[76..]) as usize;
+        Uint128Vec::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn minted_withdrawn_udt(&self) -> Uint128Vec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[76..]) as usize;
+        let end = molecule::unpack_number(&slice[80..]) as usize;
+        Uint128Vec::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn merge_spore_count(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[80..]) as usize;
+        let end = molecule::unpack_number(&slice[84..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn level_prices(&self) -> Uint128Vec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[84..]) as usize;
+        let end = molecule::unpack_number(&slice[88..]) as usize;
+        Uint128Vec::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn co_authors(&self) -> CoAuthorVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[88..]) as usize;
+        let end = molecule::unpack_number(&slice[92..]) as usize;
+        CoAuthorVec::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn withdrawal_fee_udt(&self) -> Uint128 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[92..]) as usize;
+        let end = molecule::unpack_number(&slice[96..]) as usize;
+        Uint128::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn withdrawal_fee_ratio(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[96..]) as usize;
+        let end = molecule::unpack_number(&slice[100..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn level_rule(&self) -> LevelRuleOpt {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[100..]) as usize;
+        let end = molecule::unpack_number(&slice[104..]) as usize;
+        LevelRuleOpt::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn max_per_buyer(&self) -> Uint32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[104..]) as usize;
+        let end = molecule::unpack_number(&slice[108..]) as usize;
+        Uint32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn reserved_count(&self) -> Uint32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[108..]) as usize;
+        let end = molecule::unpack_number(&slice[112..]) as usize;
+        Uint32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn approved_dna_root(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[112..]) as usize;
+        let end = molecule::unpack_number(&slice[116..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn coupon_pubkey_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[116..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[60..]) as usize;
-            Bytes::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[120..]) as usize;
+            Byte32::new_unchecked(self.0.slice(start..end))
         } else {
-            Bytes::new_unchecked(self.0.slice(start..))
+            Byte32::new_unchecked(self.0.slice(start..))
         }
     }
     pub fn as_reader<'r>(&'r self) -> AccountBookCellDataReader<'r> {
@@ -2072,6 +7454,21 @@ impl molecule::prelude::Entity for AccountBookCellData {
             .price(self.price())
             .profit_distribution_ratio(self.profit_distribution_ratio())
             .profit_distribution_number(self.profit_distribution_number())
+            .auther_vesting_start(self.auther_vesting_start())
+            .auther_vesting_duration(self.auther_vesting_duration())
+            .claim_deadline_since(self.claim_deadline_since())
+            .retired_withdrawn_udt(self.retired_withdrawn_udt())
+            .minted_withdrawn_udt(self.minted_withdrawn_udt())
+            .merge_spore_count(self.merge_spore_count())
+            .level_prices(self.level_prices())
+            .co_authors(self.co_authors())
+            .withdrawal_fee_udt(self.withdrawal_fee_udt())
+            .withdrawal_fee_ratio(self.withdrawal_fee_ratio())
+            .level_rule(self.level_rule())
+            .max_per_buyer(self.max_per_buyer())
+            .reserved_count(self.reserved_count())
+            .approved_dna_root(self.approved_dna_root())
+            .coupon_pubkey_hash(self.coupon_pubkey_hash())
     }
 }
 #[derive(Clone, Copy)]
@@ -2137,6 +7534,61 @@ impl<'r> ::core::fmt::Display for AccountBookCellDataReader<'r> {
             "profit_distribution_number",
             self.profit_distribution_number()
         )?;
+        write!(
+            f,
+            ", {}: {}",
+            "auther_vesting_start",
+            self.auther_vesting_start()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "auther_vesting_duration",
+            self.auther_vesting_duration()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "claim_deadline_since",
+            self.claim_deadline_since()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "retired_withdrawn_udt",
+            self.retired_withdrawn_udt()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "minted_withdrawn_udt",
+            self.minted_withdrawn_udt()
+        )?;
+        write!(f, ", {}: {}", "merge_spore_count", self.merge_spore_count())?;
+        write!(f, ", {}: {}", "level_prices", self.level_prices())?;
+        write!(f, ", {}: {}", "co_authors", self.co_authors())?;
+        write!(
+            f,
+            ", {}: {}",
+            "withdrawal_fee_udt",
+            self.withdrawal_fee_udt()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "withdrawal_fee_ratio",
+            self.withdrawal_fee_ratio()
+        )?;
+        write!(f, ", {}: {}", "level_rule", self.level_rule())?;
+        write!(f, ", {}: {}", "max_per_buyer", self.max_per_buyer())?;
+        write!(f, ", {}: {}", "reserved_count", self.reserved_count())?;
+        write!(f, ", {}: {}", "approved_dna_root", self.approved_dna_root())?;
+        write!(
+            f,
+            ", {}: {}",
+            "coupon_pubkey_hash",
+            self.coupon_pubkey_hash()
+        )?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -2145,7 +7597,7 @@ impl<'r> ::core::fmt::Display for AccountBookCellDataReader<'r> {
     }
 }
 impl<'r> AccountBookCellDataReader<'r> {
-    pub const FIELD_COUNT: usize = 14;
+    pub const FIELD_COUNT: usize = 29;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -2243,11 +7695,101 @@ impl<'r> AccountBookCellDataReader<'r> {
     pub fn profit_distribution_number(&self) -> BytesReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[56..]) as usize;
+        let end = molecule::unpack_number(&slice[60..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn auther_vesting_start(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[60..]) as usize;
+        let end = molecule::unpack_number(&slice[64..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn auther_vesting_duration(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[64..]) as usize;
+        let end = molecule::unpack_number(&slice[68..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn claim_deadline_since(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[68..]) as usize;
+        let end = molecule::unpack_number(&slice[72..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn retired_withdrawn_udt(&self) -> Uint128VecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[72..]) as usize;
+        let end = molecule::unpack_number(&slice[76..]) as usize;
+        Uint128VecReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn minted_withdrawn_udt(&self) -> Uint128VecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[76..]) as usize;
+        let end = molecule::unpack_number(&slice[80..]) as usize;
+        Uint128VecReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn merge_spore_count(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[80..]) as usize;
+        let end = molecule::unpack_number(&slice[84..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn level_prices(&self) -> Uint128VecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[84..]) as usize;
+        let end = molecule::unpack_number(&slice[88..]) as usize;
+        Uint128VecReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn co_authors(&self) -> CoAuthorVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[88..]) as usize;
+        let end = molecule::unpack_number(&slice[92..]) as usize;
+        CoAuthorVecReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn withdrawal_fee_udt(&self) -> Uint128Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[92..]) as usize;
+        let end = molecule::unpack_number(&slice[96..]) as usize;
+        Uint128Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn withdrawal_fee_ratio(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[96..]) as usize;
+        let end = molecule::unpack_number(&slice[100..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn level_rule(&self) -> LevelRuleOptReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[100..]) as usize;
+        let end = molecule::unpack_number(&slice[104..]) as usize;
+        LevelRuleOptReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn max_per_buyer(&self) -> Uint32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[104..]) as usize;
+        let end = molecule::unpack_number(&slice[108..]) as usize;
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn reserved_count(&self) -> Uint32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[108..]) as usize;
+        let end = molecule::unpack_number(&slice[112..]) as usize;
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn approved_dna_root(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[112..]) as usize;
+        let end = molecule::unpack_number(&slice[116..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn coupon_pubkey_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[116..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[60..]) as usize;
-            BytesReader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[120..]) as usize;
+            Byte32Reader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            BytesReader::new_unchecked(&self.as_slice()[start..])
+            Byte32Reader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
@@ -2311,6 +7853,21 @@ impl<'r> molecule::prelude::Reader<'r> for AccountBookCellDataReader<'r> {
         Uint128Reader::verify(&slice[offsets[11]..offsets[12]], compatible)?;
         BytesReader::verify(&slice[offsets[12]..offsets[13]], compatible)?;
         BytesReader::verify(&slice[offsets[13]..offsets[14]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[14]..offsets[15]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[15]..offsets[16]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[16]..offsets[17]], compatible)?;
+        Uint128VecReader::verify(&slice[offsets[17]..offsets[18]], compatible)?;
+        Uint128VecReader::verify(&slice[offsets[18]..offsets[19]], compatible)?;
+        ByteReader::verify(&slice[offsets[19]..offsets[20]], compatible)?;
+        Uint128VecReader::verify(&slice[offsets[20]..offsets[21]], compatible)?;
+        CoAuthorVecReader::verify(&slice[offsets[21]..offsets[22]], compatible)?;
+        Uint128Reader::verify(&slice[offsets[22]..offsets[23]], compatible)?;
+        ByteReader::verify(&slice[offsets[23]..offsets[24]], compatible)?;
+        LevelRuleOptReader::verify(&slice[offsets[24]..offsets[25]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[25]..offsets[26]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[26]..offsets[27]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[27]..offsets[28]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[28]..offsets[29]], compatible)?;
         Ok(())
     }
 }
@@ -2330,9 +7887,24 @@ pub struct AccountBookCellDataBuilder {
     pub(crate) price: Uint128,
     pub(crate) profit_distribution_ratio: Bytes,
     pub(crate) profit_distribution_number: Bytes,
+    pub(crate) auther_vesting_start: Uint64,
+    pub(crate) auther_vesting_duration: Uint64,
+    pub(crate) claim_deadline_since: Uint64,
+    pub(crate) retired_withdrawn_udt: Uint128Vec,
+    pub(crate) minted_withdrawn_udt: Uint128Vec,
+    pub(crate) merge_spore_count: Byte,
+    pub(crate) level_prices: Uint128Vec,
+    pub(crate) co_authors: CoAuthorVec,
+    pub(crate) withdrawal_fee_udt: Uint128,
+    pub(crate) withdrawal_fee_ratio: Byte,
+    pub(crate) level_rule: LevelRuleOpt,
+    pub(crate) max_per_buyer: Uint32,
+    pub(crate) reserved_count: Uint32,
+    pub(crate) approved_dna_root: Byte32,
+    pub(crate) coupon_pubkey_hash: Byte32,
 }
 impl AccountBookCellDataBuilder {
-    pub const FIELD_COUNT: usize = 14;
+    pub const FIELD_COUNT: usize = 29;
     pub fn smt_root_hash(mut self, v: Byte32) -> Self {
         self.smt_root_hash = v;
         self
@@ -2389,6 +7961,66 @@ impl AccountBookCellDataBuilder {
         self.profit_distribution_number = v;
         self
     }
+    pub fn auther_vesting_start(mut self, v: Uint64) -> Self {
+        self.auther_vesting_start = v;
+        self
+    }
+    pub fn auther_vesting_duration(mut self, v: Uint64) -> Self {
+        self.auther_vesting_duration = v;
+        self
+    }
+    pub fn claim_deadline_since(mut self, v: Uint64) -> Self {
+        self.claim_deadline_since = v;
+        self
+    }
+    pub fn retired_withdrawn_udt(mut self, v: Uint128Vec) -> Self {
+        self.retired_withdrawn_udt = v;
+        self
+    }
+    pub fn minted_withdrawn_udt(mut self, v: Uint128Vec) -> Self {
+        self.minted_withdrawn_udt = v;
+        self
+    }
+    pub fn merge_spore_count(mut self, v: Byte) -> Self {
+        self.merge_spore_count = v;
+        self
+    }
+    pub fn level_prices(mut self, v: Uint128Vec) -> Self {
+        self.level_prices = v;
+        self
+    }
+    pub fn co_authors(mut self, v: CoAuthorVec) -> Self {
+        self.co_authors = v;
+        self
+    }
+    pub fn withdrawal_fee_udt(mut self, v: Uint128) -> Self {
+        self.withdrawal_fee_udt = v;
+        self
+    }
+    pub fn withdrawal_fee_ratio(mut self, v: Byte) -> Self {
+        self.withdrawal_fee_ratio = v;
+        self
+    }
+    pub fn level_rule(mut self, v: LevelRuleOpt) -> Self {
+        self.level_rule = v;
+        self
+    }
+    pub fn max_per_buyer(mut self, v: Uint32) -> Self {
+        self.max_per_buyer = v;
+        self
+    }
+    pub fn reserved_count(mut self, v: Uint32) -> Self {
+        self.reserved_count = v;
+        self
+    }
+    pub fn approved_dna_root(mut self, v: Byte32) -> Self {
+        self.approved_dna_root = v;
+        self
+    }
+    pub fn coupon_pubkey_hash(mut self, v: Byte32) -> Self {
+        self.coupon_pubkey_hash = v;
+        self
+    }
 }
 impl molecule::prelude::Builder for AccountBookCellDataBuilder {
     type Entity = AccountBookCellData;
@@ -2409,6 +8041,21 @@ impl molecule::prelude::Builder for AccountBookCellDataBuilder {
             + self.price.as_slice().len()
             + self.profit_distribution_ratio.as_slice().len()
             + self.profit_distribution_number.as_slice().len()
+            + self.auther_vesting_start.as_slice().len()
+            + self.auther_vesting_duration.as_slice().len()
+            + self.claim_deadline_since.as_slice().len()
+            + self.retired_withdrawn_udt.as_slice().len()
+            + self.minted_withdrawn_udt.as_slice().len()
+            + self.merge_spore_count.as_slice().len()
+            + self.level_prices.as_slice().len()
+            + self.co_authors.as_slice().len()
+            + self.withdrawal_fee_udt.as_slice().len()
+            + self.withdrawal_fee_ratio.as_slice().len()
+            + self.level_rule.as_slice().len()
+            + self.max_per_buyer.as_slice().len()
+            + self.reserved_count.as_slice().len()
+            + self.approved_dna_root.as_slice().len()
+            + self.coupon_pubkey_hash.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
@@ -2441,6 +8088,36 @@ impl molecule::prelude::Builder for AccountBookCellDataBuilder {
         total_size += self.profit_distribution_ratio.as_slice().len();
         offsets.push(total_size);
         total_size += self.profit_distribution_number.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.auther_vesting_start.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.auther_vesting_duration.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.claim_deadline_since.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.retired_withdrawn_udt.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.minted_withdrawn_udt.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.merge_spore_count.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.level_prices.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.co_authors.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.withdrawal_fee_udt.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.withdrawal_fee_ratio.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.level_rule.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.max_per_buyer.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.reserved_count.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.approved_dna_root.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.coupon_pubkey_hash.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
@@ -2459,6 +8136,21 @@ impl molecule::prelude::Builder for AccountBookCellDataBuilder {
         writer.write_all(self.price.as_slice())?;
         writer.write_all(self.profit_distribution_ratio.as_slice())?;
         writer.write_all(self.profit_distribution_number.as_slice())?;
+        writer.write_all(self.auther_vesting_start.as_slice())?;
+        writer.write_all(self.auther_vesting_duration.as_slice())?;
+        writer.write_all(self.claim_deadline_since.as_slice())?;
+        writer.write_all(self.retired_withdrawn_udt.as_slice())?;
+        writer.write_all(self.minted_withdrawn_udt.as_slice())?;
+        writer.write_all(self.merge_spore_count.as_slice())?;
+        writer.write_all(self.level_prices.as_slice())?;
+        writer.write_all(self.co_authors.as_slice())?;
+        writer.write_all(self.withdrawal_fee_udt.as_slice())?;
+        writer.write_all(self.withdrawal_fee_ratio.as_slice())?;
+        writer.write_all(self.level_rule.as_slice())?;
+        writer.write_all(self.max_per_buyer.as_slice())?;
+        writer.write_all(self.reserved_count.as_slice())?;
+        writer.write_all(self.approved_dna_root.as_slice())?;
+        writer.write_all(self.coupon_pubkey_hash.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
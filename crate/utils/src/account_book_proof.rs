@@ -16,19 +16,45 @@ pub type SMTTree = SparseMerkleTree<Blake2bHasher, SmtValue, DefaultStore<SmtVal
 pub enum SmtKey {
     AccountBalance,
     TotalIncome,
+    TotalWithdrawn,
     Platform,
+    PlatformFee,
     Auther,
     Buyer(crate::Hash),
+    CoAuthor(crate::Hash),
+    Dna(crate::Hash),
+    BuyerLock(crate::Hash),
+    Coupon(crate::Hash),
+    Minted(crate::Hash), // Spores minted by merging or upgrading, valued by their level
+    Burned(crate::Hash), // Spores burned by retiring, merging or upgrading, valued by their level
 }
 impl SmtKey {
     pub fn get_key(&self) -> H256 {
-        crate::Hash::ckb_hash(match self {
-            Self::AccountBalance => "AccountBalance".as_bytes(),
-            Self::TotalIncome => "TotalIncome".as_bytes(),
-            Self::Platform => "Platform".as_bytes(),
-            Self::Auther => "Auther".as_bytes(),
-            Self::Buyer(hash) => hash.as_slice(),
-        })
+        match self {
+            Self::AccountBalance => crate::Hash::ckb_hash("AccountBalance".as_bytes()),
+            Self::TotalIncome => crate::Hash::ckb_hash("TotalIncome".as_bytes()),
+            Self::TotalWithdrawn => crate::Hash::ckb_hash("TotalWithdrawn".as_bytes()),
+            Self::Platform => crate::Hash::ckb_hash("Platform".as_bytes()),
+            Self::PlatformFee => crate::Hash::ckb_hash("PlatformFee".as_bytes()),
+            Self::Auther => crate::Hash::ckb_hash("Auther".as_bytes()),
+            Self::Buyer(hash) => crate::Hash::ckb_hash(hash.as_slice()),
+            Self::CoAuthor(hash) => {
+                crate::Hash::ckb_hash(&["CoAuthor".as_bytes(), hash.as_slice()].concat())
+            }
+            Self::Dna(hash) => crate::Hash::ckb_hash(&["Dna".as_bytes(), hash.as_slice()].concat()),
+            Self::BuyerLock(hash) => {
+                crate::Hash::ckb_hash(&["BuyerLock".as_bytes(), hash.as_slice()].concat())
+            }
+            Self::Coupon(nonce) => {
+                crate::Hash::ckb_hash(&["Coupon".as_bytes(), nonce.as_slice()].concat())
+            }
+            Self::Minted(hash) => {
+                crate::Hash::ckb_hash(&["Minted".as_bytes(), hash.as_slice()].concat())
+            }
+            Self::Burned(hash) => {
+                crate::Hash::ckb_hash(&["Burned".as_bytes(), hash.as_slice()].concat())
+            }
+        }
         .into()
     }
 }
@@ -74,40 +100,186 @@ impl AccountBookProof {
         root: Hash,
         total_income: u128,
         account_balance: u128,
+        total_withdrawn: u128,
         buyer: (SmtKey, Option<u128>),
     ) -> Result<bool, Error> {
+        self.verify_leaves(
+            root,
+            total_income,
+            account_balance,
+            total_withdrawn,
+            &[buyer],
+        )
+    }
+
+    pub fn verify_leaves(
+        &self,
+        root: Hash,
+        total_income: u128,
+        account_balance: u128,
+        total_withdrawn: u128,
+        buyers: &[(SmtKey, Option<u128>)],
+    ) -> Result<bool, Error> {
+        self.verify_totals(
+            root,
+            total_income,
+            account_balance,
+            (total_withdrawn, false),
+            buyers,
+        )
+    }
+
+    // AccountBooks created before the TotalWithdrawn leaf have none, whatever they paid out.
+    // They are still accepted in Inputs, the Outputs always hold the leaf.
+    pub fn verify_old(
+        &self,
+        root: Hash,
+        total_income: u128,
+        account_balance: u128,
+        total_withdrawn: u128,
+        buyer: (SmtKey, Option<u128>),
+    ) -> Result<bool, Error> {
+        self.verify_old_leaves(
+            root,
+            total_income,
+            account_balance,
+            total_withdrawn,
+            &[buyer],
+        )
+    }
+
+    pub fn verify_old_leaves(
+        &self,
+        root: Hash,
+        total_income: u128,
+        account_balance: u128,
+        total_withdrawn: u128,
+        buyers: &[(SmtKey, Option<u128>)],
+    ) -> Result<bool, Error> {
+        if self.verify_leaves(
+            root.clone(),
+            total_income,
+            account_balance,
+            total_withdrawn,
+            buyers,
+        )? {
+            return Ok(true);
+        }
+        self.verify_totals(
+            root,
+            total_income,
+            account_balance,
+            (total_withdrawn, true),
+            buyers,
+        )
+    }
+
+    fn verify_totals(
+        &self,
+        root: Hash,
+        total_income: u128,
+        account_balance: u128,
+        total_withdrawn: (u128, bool),
+        buyers: &[(SmtKey, Option<u128>)],
+    ) -> Result<bool, Error> {
+        let (total_withdrawn, without_leaf) = total_withdrawn;
+        use alloc::vec;
+        let proof = CompiledMerkleProof(self.proof.clone());
+
+        // Everything not paid out stays in the AccountBook,
+        // so no ratio can be overdrawn without breaking the balance
+        if crate::checked_add(account_balance, total_withdrawn)? != total_income {
+            ckb_std::log::error!(
+                "AccountBalance({}) + TotalWithdrawn({}) != TotalIncome({})",
+                account_balance,
+                total_withdrawn,
+                total_income
+            );
+            return Err(Error::AccountBook);
+        }
+
+        // The TotalWithdrawn leaf does not exist before the first payout,
+        // nor in AccountBooks created before it
+        let mut leaves = vec![
+            (
+                SmtKey::TotalIncome.get_key(),
+                SmtValue::new(total_income).to_h256(),
+            ),
+            (
+                SmtKey::AccountBalance.get_key(),
+                SmtValue::new(account_balance).to_h256(),
+            ),
+            (
+                SmtKey::TotalWithdrawn.get_key(),
+                if total_withdrawn == 0 || without_leaf {
+                    Default::default()
+                } else {
+                    SmtValue::new(total_withdrawn).to_h256()
+                },
+            ),
+        ];
+        leaves.extend(buyers.iter().map(to_leaf));
+
+        proof
+            .verify::<Blake2bHasher>(&root.into(), leaves)
+            .map_err(|e| {
+                ckb_std::log::error!("Verify Inputs Smt Error: {:?}", e);
+                Error::Smt
+            })
+    }
+
+    // Leaves of another AccountBook, its totals are not involved
+    pub fn verify_sibling(
+        &self,
+        root: Hash,
+        leaves: &[(SmtKey, Option<u128>)],
+    ) -> Result<bool, Error> {
+        let proof = CompiledMerkleProof(self.proof.clone());
+
+        proof
+            .verify::<Blake2bHasher>(&root.into(), leaves.iter().map(to_leaf).collect())
+            .map_err(|e| {
+                ckb_std::log::error!("Verify sibling Smt Error: {:?}", e);
+                Error::Smt
+            })
+    }
+
+    // The approved DNA set is an SMT of SmtKey::Dna(hash) -> SMT_VALUE_APPROVED
+    pub fn verify_approved_dna(&self, root: Hash, dna_hash: Hash) -> Result<bool, Error> {
         use alloc::vec;
         let proof = CompiledMerkleProof(self.proof.clone());
 
         proof
             .verify::<Blake2bHasher>(
                 &root.into(),
-                vec![
-                    (
-                        SmtKey::TotalIncome.get_key(),
-                        SmtValue::new(total_income).to_h256(),
-                    ),
-                    (
-                        SmtKey::AccountBalance.get_key(),
-                        SmtValue::new(account_balance).to_h256(),
-                    ),
-                    (
-                        buyer.0.get_key(),
-                        if let Some(a) = buyer.1 {
-                            SmtValue::new(a).to_h256()
-                        } else {
-                            Default::default()
-                        },
-                    ),
-                ],
+                vec![(
+                    SmtKey::Dna(dna_hash).get_key(),
+                    SmtValue::new(SMT_VALUE_APPROVED).to_h256(),
+                )],
             )
             .map_err(|e| {
-                ckb_std::log::error!("Verify Inputs Smt Error: {:?}", e);
+                ckb_std::log::error!("Verify approved DNA Smt Error: {:?}", e);
                 Error::Smt
             })
     }
 }
 
+fn to_leaf((key, value): &(SmtKey, Option<u128>)) -> (H256, H256) {
+    (
+        key.get_key(),
+        if let Some(a) = value {
+            SmtValue::new(*a).to_h256()
+        } else {
+            Default::default()
+        },
+    )
+}
+
+pub const SMT_VALUE_APPROVED: u128 = 1;
+
+// SMT leaves cannot be deleted, retired Buyers are marked with this value
+pub const SMT_VALUE_RETIRED: u128 = u128::MAX;
+
 pub const SMT_ROOT_HASH_INITIAL: [u8; 32] = [
     0x0b, 0x4c, 0x8b, 0xd4, 0xf8, 0x27, 0xd2, 0xd9, 0xf0, 0x4e, 0xb9, 0x26, 0xe2, 0x89, 0xdb, 0x7a,
     0x62, 0xb7, 0x86, 0x40, 0x38, 0x99, 0x94, 0xde, 0xd5, 0x82, 0xd7, 0x5f, 0xa6, 0x33, 0xd6, 0xb0,
@@ -0,0 +1,129 @@
+use crate::Hash;
+use ckb_std::{
+    ckb_constants::Source,
+    ckb_types::prelude::{Entity, Unpack},
+    high_level::load_header,
+    log,
+    since::{EpochNumberWithFraction, LockValue, Since},
+};
+use core::cmp::Ordering;
+use secp256k1::{
+    ecdsa::{RecoverableSignature, RecoveryId},
+    Message, Secp256k1,
+};
+use types::{error::SilentBerryError as Error, BuyIntentData, Byte65, Coupon};
+
+// ckb_hash of the Coupon without the signature
+pub fn get_coupon_message(coupon: &Coupon) -> Hash {
+    Hash::ckb_hash(&coupon.as_slice()[..Coupon::TOTAL_SIZE - Byte65::TOTAL_SIZE])
+}
+
+// Returns the discount of the coupon in the BuyIntent, 0 without a coupon
+pub fn verify_coupon(
+    buy_intent_data: &BuyIntentData,
+    account_book_hash: &Hash,
+    pubkey_hash: &Hash,
+) -> Result<u128, Error> {
+    let coupon = buy_intent_data.coupon();
+    let discount: u128 = coupon.discount().unpack();
+    if discount == 0 {
+        return Ok(0);
+    }
+
+    if *pubkey_hash == [0u8; 32] {
+        log::error!("Coupons are not accepted by the AccountBook");
+        return Err(Error::Coupon);
+    }
+    if *account_book_hash != coupon.account_book_script_hash() {
+        log::error!("The coupon is not for this AccountBook");
+        return Err(Error::Coupon);
+    }
+
+    let intent_expire = Since::new(buy_intent_data.expire_since().unpack());
+    let coupon_expire = Since::new(coupon.expire_since().unpack());
+    if !matches!(
+        intent_expire.partial_cmp(&coupon_expire),
+        Some(Ordering::Less | Ordering::Equal)
+    ) {
+        log::error!(
+            "The BuyIntent expires after the coupon, {:?}, {:?}",
+            intent_expire,
+            coupon_expire
+        );
+        return Err(Error::Coupon);
+    }
+
+    let signature = coupon.signature().raw_data();
+    let recovery_id = RecoveryId::from_i32(signature[64] as i32).map_err(|e| {
+        log::error!("Parse coupon recovery id failed: {:?}", e);
+        Error::Coupon
+    })?;
+    let signature =
+        RecoverableSignature::from_compact(&signature[..64], recovery_id).map_err(|e| {
+            log::error!("Parse coupon signature failed: {:?}", e);
+            Error::Coupon
+        })?;
+    let message = Message::from_digest(get_coupon_message(&coupon).into());
+    let pubkey = Secp256k1::verification_only()
+        .recover_ecdsa(&message, &signature)
+        .map_err(|e| {
+            log::error!("Recover coupon pubkey failed: {:?}", e);
+            Error::Coupon
+        })?;
+    if Hash::ckb_hash(&pubkey.serialize()) != *pubkey_hash {
+        log::error!("The coupon is not signed by the platform");
+        return Err(Error::Coupon);
+    }
+
+    Ok(discount)
+}
+
+// A transaction cannot prove it comes before a point in time, so the coupon is honoured
+// for an intent placed before it expires: the header of the block that created
+// the BuyIntent (in HeaderDeps) is earlier than the expiry of the coupon
+pub fn check_coupon_expiry(
+    buy_intent_data: &BuyIntentData,
+    index: usize,
+    source: Source,
+) -> Result<(), Error> {
+    let coupon = buy_intent_data.coupon();
+    let discount: u128 = coupon.discount().unpack();
+    if discount == 0 {
+        return Ok(());
+    }
+
+    let coupon_expire = Since::new(coupon.expire_since().unpack());
+    let header = load_header(index, source)
+        .map_err(|e| {
+            log::error!("The header of the BuyIntent is not in HeaderDeps: {:?}", e);
+            Error::Coupon
+        })?
+        .raw();
+
+    let number: u64 = header.number().unpack();
+    let epoch: u64 = header.epoch().unpack();
+    let timestamp: u64 = header.timestamp().unpack();
+    let created = match coupon_expire.extract_lock_value() {
+        Some(LockValue::BlockNumber(_)) => Since::from_block_number(number, true),
+        Some(LockValue::EpochNumberWithFraction(_)) => Some(Since::from_epoch(
+            EpochNumberWithFraction::from_full_value(epoch),
+            true,
+        )),
+        // The since of a timestamp is in seconds, the header in milliseconds
+        Some(LockValue::Timestamp(_)) => Since::from_timestamp(timestamp / 1000, true),
+        None => None,
+    };
+    if !matches!(
+        created.and_then(|created| created.partial_cmp(&coupon_expire)),
+        Some(Ordering::Less)
+    ) {
+        log::error!(
+            "The BuyIntent is placed after the coupon expires, {:?}, {:?}",
+            created,
+            coupon_expire
+        );
+        return Err(Error::Coupon);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+// Just enough JSON to read a string field of the top level object in the Spore content
+
+fn skip_whitespace(content: &[u8], pos: usize) -> usize {
+    pos + content[pos.min(content.len())..]
+        .iter()
+        .take_while(|c| c.is_ascii_whitespace())
+        .count()
+}
+
+// Returns the raw bytes between the quotes and the position after the closing quote
+fn parse_string(content: &[u8], pos: usize) -> Option<(&[u8], usize)> {
+    if content.get(pos) != Some(&b'"') {
+        return None;
+    }
+    let mut i = pos + 1;
+    while let Some(c) = content.get(i) {
+        match c {
+            b'\\' => i += 2,
+            b'"' => return Some((&content[pos + 1..i], i + 1)),
+            _ => i += 1,
+        }
+    }
+    None
+}
+
+// Returns the position after a value that is not looked into
+fn skip_value(content: &[u8], pos: usize) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut i = pos;
+    loop {
+        match content.get(i)? {
+            b'"' => {
+                i = parse_string(content, i)?.1;
+                if depth == 0 {
+                    return Some(i);
+                }
+                continue;
+            }
+            b'{' | b'[' => depth += 1,
+            b'}' | b']' if depth == 0 => return Some(i),
+            b'}' | b']' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i + 1);
+                }
+            }
+            b',' if depth == 0 => return Some(i),
+            _ => {}
+        }
+        i += 1;
+    }
+}
+
+// The string value of `key` in the top level object,
+// None if the content is not an object, or the key is missing, repeated or not a plain string
+pub fn get_string_field<'a>(content: &'a [u8], key: &[u8]) -> Option<&'a [u8]> {
+    let mut pos = skip_whitespace(content, 0);
+    if content.get(pos) != Some(&b'{') {
+        return None;
+    }
+    pos = skip_whitespace(content, pos + 1);
+    if content.get(pos) == Some(&b'}') {
+        return None;
+    }
+
+    let mut found = None;
+    loop {
+        let (field, next) = parse_string(content, pos)?;
+        pos = skip_whitespace(content, next);
+        if content.get(pos) != Some(&b':') {
+            return None;
+        }
+        pos = skip_whitespace(content, pos + 1);
+
+        if field == key {
+            let (value, next) = parse_string(content, pos)?;
+            if found.is_some() || value.contains(&b'\\') {
+                return None;
+            }
+            found = Some(value);
+            pos = next;
+        } else {
+            pos = skip_value(content, pos)?;
+        }
+
+        pos = skip_whitespace(content, pos);
+        match content.get(pos)? {
+            b',' => pos = skip_whitespace(content, pos + 1),
+            b'}' => return found,
+            _ => return None,
+        }
+    }
+}
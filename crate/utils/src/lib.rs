@@ -6,20 +6,32 @@ extern crate alloc;
 mod account_book_proof;
 
 #[cfg(feature = "smt")]
-pub use account_book_proof::{AccountBookProof, SmtKey, H256, SMT_ROOT_HASH_INITIAL};
+pub use account_book_proof::{
+    AccountBookProof, SmtKey, H256, SMT_ROOT_HASH_INITIAL, SMT_VALUE_APPROVED, SMT_VALUE_RETIRED,
+};
 #[cfg(all(feature = "smt", feature = "std"))]
 pub use account_book_proof::{SMTTree, SmtValue};
 
+#[cfg(feature = "coupon")]
+mod coupon;
+#[cfg(feature = "coupon")]
+pub use coupon::{check_coupon_expiry, get_coupon_message, verify_coupon};
+
 mod hash;
 pub use hash::{Hash, HASH_SIZE};
 
+mod json;
+
+mod math;
+pub use math::{checked_add, checked_mul, checked_mul_div, checked_sub};
+
 mod udt_info;
 pub use udt_info::UDTInfo;
 
 use alloc::vec::Vec;
 use ckb_std::{
     ckb_constants::Source,
-    ckb_types::prelude::{Entity, Reader},
+    ckb_types::prelude::{Entity, Reader, Unpack},
     error::SysError,
     high_level::{load_cell_data, load_cell_lock, load_cell_type, load_witness_args, QueryIter},
     log,
@@ -122,6 +134,66 @@ pub fn get_spore_level(spore_data: &spore_types::spore::SporeData) -> Result<u8,
     })? as u8)
 }
 
+// Level of the Spore derived by the rule of the AccountBook,
+// without a rule, the last two hex characters of the content are used
+pub fn get_spore_level_by_rule(
+    spore_data: &spore_types::spore::SporeData,
+    rule: &types::LevelRuleOpt,
+) -> Result<u8, Error> {
+    let rule = match rule.to_opt() {
+        Some(rule) => rule,
+        None => return get_spore_level(spore_data),
+    };
+
+    let dna = get_spore_dna(spore_data)?;
+    let index: u8 = rule.dna_byte_index().into();
+    let pos = index as usize * 2;
+    let byte = dna
+        .get(pos..pos + 2)
+        .and_then(|h| core::str::from_utf8(h).ok())
+        .and_then(|h| u8::from_str_radix(h, 16).ok())
+        .ok_or_else(|| {
+            log::error!("DNA byte {} not found in DNA: {:02x?}", index, dna);
+            Error::Spore
+        })?;
+    let value = byte & u8::from(rule.dna_bit_mask());
+
+    for range in rule.ranges().into_iter() {
+        let min: u8 = range.min().into();
+        let max: u8 = range.max().into();
+        if min <= value && value <= max {
+            return Ok(range.level().into());
+        }
+    }
+
+    log::error!("DNA value {} is not in any level range", value);
+    Err(Error::Spore)
+}
+
+// The `dna` field in the Spore content (lowercase), e.g. {"dna":"4000000000002"}
+fn get_spore_dna(spore_data: &spore_types::spore::SporeData) -> Result<Vec<u8>, Error> {
+    let content = spore_data.content().raw_data();
+    let dna_not_found = || {
+        log::error!("DNA not found in Spore Content: {:02x?}", content.as_ref());
+        Error::Spore
+    };
+
+    let dna: Vec<u8> = json::get_string_field(&content, b"dna")
+        .ok_or_else(dna_not_found)?
+        .iter()
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+    if dna.is_empty() {
+        return Err(dna_not_found());
+    }
+
+    Ok(dna)
+}
+
+pub fn get_spore_dna_hash(spore_data: &spore_types::spore::SporeData) -> Result<Hash, Error> {
+    Ok(Hash::ckb_hash(&get_spore_dna(spore_data)?))
+}
+
 pub fn load_buy_intent_data(index: usize, source: Source) -> Result<BuyIntentData, Error> {
     let witness = load_witness_args(index, source)?;
 
@@ -143,6 +215,47 @@ pub fn load_buy_intent_data(index: usize, source: Source) -> Result<BuyIntentDat
     Ok(BuyIntentData::new_unchecked(witness))
 }
 
+// 0 is treated as 1
+pub fn get_quantity(buy_intent_data: &BuyIntentData) -> u32 {
+    let quantity: u32 = buy_intent_data.quantity().unpack();
+    quantity.max(1)
+}
+
+// xUDT paid per Spore, less the discount of the coupon
+pub fn get_unit_payment(buy_intent_data: &BuyIntentData) -> u128 {
+    let max_price: u128 = buy_intent_data.max_price().unpack();
+    let payment = if max_price == 0 {
+        buy_intent_data.price().unpack()
+    } else {
+        max_price
+    };
+    let discount: u128 = buy_intent_data.coupon().discount().unpack();
+    payment.saturating_sub(discount)
+}
+
+// xUDT to be escrowed in the DobSelling before the BuyIntent can be filled
+pub fn get_full_payment(buy_intent_data: &BuyIntentData) -> Result<u128, Error> {
+    checked_mul(
+        get_unit_payment(buy_intent_data),
+        get_quantity(buy_intent_data) as u128,
+    )
+}
+
+// xUDT of the DobSelling that goes back to the owner on revocation,
+// an incomplete installment only gets `refund_ratio` percent of it
+pub fn get_revocation_refund(
+    buy_intent_data: &BuyIntentData,
+    escrowed: u128,
+) -> Result<u128, Error> {
+    let deposit: u128 = buy_intent_data.deposit().unpack();
+    if deposit == 0 || escrowed >= get_full_payment(buy_intent_data)? {
+        return Ok(escrowed);
+    }
+
+    let refund_ratio: u8 = buy_intent_data.refund_ratio().into();
+    checked_mul_div(escrowed, refund_ratio.min(100) as u128, 100)
+}
+
 pub fn load_account_book_data(index: usize, source: Source) -> Result<AccountBookData, Error> {
     let witness = load_witness_args(index, source)?;
     let witness = witness
@@ -0,0 +1,39 @@
+use ckb_std::log;
+use types::error::SilentBerryError as Error;
+
+pub fn checked_add(a: u128, b: u128) -> Result<u128, Error> {
+    a.checked_add(b).ok_or_else(|| {
+        log::error!("Overflow: {} + {}", a, b);
+        Error::AccountBookOverflow
+    })
+}
+
+pub fn checked_sub(a: u128, b: u128) -> Result<u128, Error> {
+    a.checked_sub(b).ok_or_else(|| {
+        log::error!("Overflow: {} - {}", a, b);
+        Error::AccountBookOverflow
+    })
+}
+
+pub fn checked_mul(a: u128, b: u128) -> Result<u128, Error> {
+    a.checked_mul(b).ok_or_else(|| {
+        log::error!("Overflow: {} * {}", a, b);
+        Error::AccountBookOverflow
+    })
+}
+
+// a * b / c, rounded down.
+// Split a by c first so that the result does not overflow as long as it fits in u128
+pub fn checked_mul_div(a: u128, b: u128, c: u128) -> Result<u128, Error> {
+    let overflow = || {
+        log::error!("Overflow: {} * {} / {}", a, b, c);
+        Error::AccountBookOverflow
+    };
+    if c == 0 {
+        return Err(overflow());
+    }
+
+    let high = (a / c).checked_mul(b).ok_or_else(overflow)?;
+    let low = (a % c).checked_mul(b).ok_or_else(overflow)? / c;
+    high.checked_add(low).ok_or_else(overflow)
+}
@@ -3,7 +3,7 @@ use alloc::vec::Vec;
 use ckb_std::{
     ckb_constants::Source,
     error::SysError,
-    high_level::{load_cell_data, load_cell_type_hash},
+    high_level::{load_cell_data, load_cell_lock_hash, load_cell_type_hash},
     log,
 };
 use types::error::SilentBerryError;
@@ -68,30 +68,50 @@ impl UDTInfo {
     }
 
     // Check if the UDT in Inputs and Outputs is the same
-    fn check_udt(&self) -> Result<(), SilentBerryError> {
-        let mut input_udt = 0u128;
-        for (udt, _index) in &self.inputs {
-            input_udt += *udt;
-        }
-
-        let mut output_udt = 0u128;
-        for (udt, _index) in &self.inputs {
-            output_udt += udt;
-        }
+    pub fn check_udt(&self) -> Result<(), SilentBerryError> {
+        let input_udt = Self::sum(&self.inputs)?;
+        let output_udt = Self::sum(&self.outputs)?;
 
         if input_udt != output_udt {
-            log::error!("Inputs and Outputs UDT is not equal");
+            log::error!(
+                "Inputs and Outputs UDT is not equal: {}, {}",
+                input_udt,
+                output_udt
+            );
             return Err(SilentBerryError::CheckXUDT);
         }
 
         Ok(())
     }
 
-    pub fn total(&self) -> u128 {
-        let mut total = 0;
-        for (udt, _) in &self.inputs {
-            total += udt;
+    fn sum(udts: &[(u128, usize)]) -> Result<u128, SilentBerryError> {
+        let mut total = 0u128;
+        for (udt, _index) in udts {
+            total = crate::checked_add(total, *udt)?;
+        }
+        Ok(total)
+    }
+
+    pub fn input(&self, index: usize) -> Option<u128> {
+        self.inputs
+            .iter()
+            .find_map(|(udt, i)| if *i == index { Some(*udt) } else { None })
+    }
+
+    pub fn output(&self, index: usize) -> Option<u128> {
+        self.outputs
+            .iter()
+            .find_map(|(udt, i)| if *i == index { Some(*udt) } else { None })
+    }
+
+    // Total xUDT of the Outputs locked by the lock script
+    pub fn outputs_by_lock(&self, lock_script_hash: &Hash) -> Result<u128, SilentBerryError> {
+        let mut total = 0u128;
+        for (udt, index) in &self.outputs {
+            if *lock_script_hash == load_cell_lock_hash(*index, Source::Output)? {
+                total = crate::checked_add(total, *udt)?;
+            }
         }
-        total
+        Ok(total)
     }
 }
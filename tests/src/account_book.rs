@@ -21,10 +21,29 @@ impl AccountBook {
         self.tree.root().as_slice().try_into().unwrap()
     }
     pub fn proof(&self, k: SmtKey) -> Vec<u8> {
-        let ks: Vec<H256> = [SmtKey::TotalIncome, SmtKey::AccountBalance, k]
-            .iter()
-            .map(|k| k.get_key())
-            .collect();
+        self.proof_keys(&[k])
+    }
+    pub fn proof_keys(&self, k: &[SmtKey]) -> Vec<u8> {
+        let ks: Vec<H256> = [
+            SmtKey::TotalIncome,
+            SmtKey::AccountBalance,
+            SmtKey::TotalWithdrawn,
+        ]
+        .iter()
+        .chain(k)
+        .map(|k| k.get_key())
+        .collect();
+
+        self.tree
+            .merkle_proof(ks.clone())
+            .unwrap()
+            .compile(ks)
+            .unwrap()
+            .0
+    }
+    // Proof of another AccountBook, without the totals
+    pub fn sibling_proof(&self, k: &[SmtKey]) -> Vec<u8> {
+        let ks: Vec<H256> = k.iter().map(|k| k.get_key()).collect();
 
         self.tree
             .merkle_proof(ks.clone())
@@ -49,6 +68,7 @@ impl AccountBook {
 
         smt.update(SmtKey::TotalIncome, 30000);
         smt.update(SmtKey::AccountBalance, 10000);
+        smt.update(SmtKey::TotalWithdrawn, 20000);
 
         let mut c: u8 = 0;
         fn new_hash(count: &mut u8) -> Hash {
@@ -84,6 +104,34 @@ impl AccountBook {
     pub fn get_total_income(&self) -> u128 {
         self.get_item(SmtKey::TotalIncome)
     }
+
+    pub fn get_total_withdrawn(&self) -> u128 {
+        let k: Hash = SmtKey::TotalWithdrawn.get_key().into();
+        let k: [u8; 32] = k.into();
+        self.bk_items.get(&k).map_or(0, |v| v.price)
+    }
+}
+
+// A leaf written under one key must leave the other one unset in the SMT
+#[cfg(test)]
+fn check_leaf_apart(written: SmtKey, other: SmtKey) {
+    let mut smt = AccountBook::new_empty();
+    smt.update(written.clone(), 7);
+    let root_hash = smt.root_hash();
+    let keys = [written, other];
+    let cproof = utils::AccountBookProof::new(smt.sibling_proof(&keys));
+    assert!(cproof
+        .verify_sibling(
+            root_hash.clone(),
+            &[(keys[0].clone(), Some(7)), (keys[1].clone(), None)],
+        )
+        .unwrap());
+    assert!(!cproof
+        .verify_sibling(
+            root_hash,
+            &[(keys[0].clone(), Some(7)), (keys[1].clone(), Some(7))],
+        )
+        .unwrap());
 }
 
 #[test]
@@ -99,6 +147,7 @@ fn test_smt() {
     let total_income = 400000;
 
     smt.update(SmtKey::AccountBalance, 80000);
+    smt.update(SmtKey::TotalWithdrawn, 320000);
     smt.update(SmtKey::Auther, 2001);
     smt.update(SmtKey::Platform, 0);
     smt.update(SmtKey::Buyer(new_hash(&mut c)), 123);
@@ -119,6 +168,7 @@ fn test_smt() {
     let root_hash_2 = smt.root_hash();
 
     smt.update(SmtKey::AccountBalance, 79800);
+    smt.update(SmtKey::TotalWithdrawn, 320200);
     let root_hash_3 = smt.root_hash();
     let total_3 = smt.get_account_balance();
 
@@ -129,14 +179,27 @@ fn test_smt() {
             root_hash_1,
             total_income,
             total_1.clone(),
+            320000,
             (k.clone(), None)
         )
         .unwrap());
     assert!(cproof
-        .verify(root_hash_2, total_income, total_1, (k.clone(), Some(200)))
+        .verify(
+            root_hash_2,
+            total_income,
+            total_1,
+            320000,
+            (k.clone(), Some(200))
+        )
         .unwrap());
     assert!(cproof
-        .verify(root_hash_3, total_income, total_3, (k.clone(), Some(200)))
+        .verify(
+            root_hash_3,
+            total_income,
+            total_3,
+            320200,
+            (k.clone(), Some(200))
+        )
         .unwrap());
 }
 
@@ -151,24 +214,619 @@ fn test_empty_smt() {
     let cproof = utils::AccountBookProof::new(proof);
     let ret = cproof
         .clone()
-        .verify(smt_root_hash.clone(), 0, 0, (SmtKey::Auther, None))
+        .verify(smt_root_hash.clone(), 0, 0, 0, (SmtKey::Auther, None))
         .expect("Verify SMT Hash");
     assert!(ret);
     let ret = cproof
         .clone()
-        .verify(smt_root_hash.clone(), 0, 0, (SmtKey::Auther, Some(0)))
+        .verify(smt_root_hash.clone(), 0, 0, 0, (SmtKey::Auther, Some(0)))
         .expect("Verify SMT Hash");
     assert!(!ret);
     cproof
         .clone()
-        .verify(smt_root_hash.clone(), 0, 0, (SmtKey::Platform, None))
+        .verify(smt_root_hash.clone(), 0, 0, 0, (SmtKey::Platform, None))
         .unwrap_err();
 
     let proof = smt.proof(SmtKey::Platform);
     let cproof = utils::AccountBookProof::new(proof);
     let ret = cproof
         .clone()
-        .verify(smt_root_hash.clone(), 0, 0, (SmtKey::Platform, None))
+        .verify(smt_root_hash.clone(), 0, 0, 0, (SmtKey::Platform, None))
         .expect("Verify SMT Hash");
     assert!(ret);
 }
+
+#[test]
+fn test_smt_multi_leaves() {
+    let mut smt = AccountBook::new_test();
+
+    let burned = [
+        SmtKey::Buyer([0xB1; 32].into()),
+        SmtKey::Buyer([0xB2; 32].into()),
+    ];
+    let minted = SmtKey::Buyer([0xC1; 32].into());
+    smt.update(burned[0].clone(), 100);
+    smt.update(burned[1].clone(), 0);
+
+    let total_income = smt.get_total_income();
+    let account_balance = smt.get_account_balance();
+    let root_hash_1 = smt.root_hash();
+    let proof = smt.proof_keys(&[burned[0].clone(), burned[1].clone(), minted.clone()]);
+
+    smt.update(burned[0].clone(), utils::SMT_VALUE_RETIRED);
+    smt.update(burned[1].clone(), utils::SMT_VALUE_RETIRED);
+    smt.update(minted.clone(), 300);
+    smt.update(SmtKey::AccountBalance, account_balance - 200);
+    smt.update(SmtKey::TotalWithdrawn, 20200);
+    let root_hash_2 = smt.root_hash();
+
+    let cproof = utils::AccountBookProof::new(proof);
+    assert!(cproof
+        .verify_leaves(
+            root_hash_1,
+            total_income,
+            account_balance,
+            20000,
+            &[
+                (burned[0].clone(), Some(100)),
+                (burned[1].clone(), Some(0)),
+                (minted.clone(), None),
+            ],
+        )
+        .unwrap());
+    assert!(cproof
+        .verify_leaves(
+            root_hash_2.clone(),
+            total_income,
+            account_balance - 200,
+            20200,
+            &[
+                (burned[0].clone(), Some(utils::SMT_VALUE_RETIRED)),
+                (burned[1].clone(), Some(utils::SMT_VALUE_RETIRED)),
+                (minted.clone(), Some(300)),
+            ],
+        )
+        .unwrap());
+    assert!(!cproof
+        .verify_leaves(
+            root_hash_2,
+            total_income,
+            account_balance - 200,
+            20200,
+            &[
+                (burned[0].clone(), Some(utils::SMT_VALUE_RETIRED)),
+                (burned[1].clone(), Some(utils::SMT_VALUE_RETIRED)),
+                (minted, Some(301)),
+            ],
+        )
+        .unwrap());
+}
+
+#[test]
+fn test_smt_co_author_key() {
+    let lock_hash: Hash = [0x11; 32].into();
+    check_leaf_apart(
+        SmtKey::CoAuthor(lock_hash.clone()),
+        SmtKey::Buyer(lock_hash.clone()),
+    );
+    check_leaf_apart(SmtKey::CoAuthor(lock_hash.clone()), SmtKey::Auther);
+    check_leaf_apart(
+        SmtKey::CoAuthor(lock_hash),
+        SmtKey::CoAuthor([0x12; 32].into()),
+    );
+}
+
+#[test]
+fn test_checked_arithmetic() {
+    use types::error::SilentBerryError;
+    use utils::{checked_add, checked_mul_div, checked_sub};
+
+    assert_eq!(checked_add(u128::MAX - 1, 1).unwrap(), u128::MAX);
+    assert_eq!(
+        checked_add(u128::MAX, 1).unwrap_err(),
+        SilentBerryError::AccountBookOverflow
+    );
+    assert_eq!(
+        checked_sub(0, 1).unwrap_err(),
+        SilentBerryError::AccountBookOverflow
+    );
+
+    // The intermediate product overflows, but the result fits
+    assert_eq!(
+        checked_mul_div(u128::MAX, 40, 100).unwrap(),
+        u128::MAX / 5 * 2
+    );
+    assert_eq!(checked_mul_div(u128::MAX, 100, 100).unwrap(), u128::MAX);
+    assert_eq!(checked_mul_div(300000, 30, 100).unwrap(), 90000);
+    assert_eq!(checked_mul_div(299, 30, 100).unwrap(), 299 * 30 / 100);
+    assert_eq!(
+        checked_mul_div(u128::MAX, 101, 100).unwrap_err(),
+        SilentBerryError::AccountBookOverflow
+    );
+    assert_eq!(
+        checked_mul_div(1, 1, 0).unwrap_err(),
+        SilentBerryError::AccountBookOverflow
+    );
+}
+
+#[test]
+fn test_udt_info_overflow() {
+    use types::error::SilentBerryError;
+    use utils::UDTInfo;
+
+    let udt_info = UDTInfo {
+        inputs: vec![(u128::MAX - 10, 0), (10, 1)],
+        outputs: vec![(u128::MAX, 0)],
+    };
+    udt_info.check_udt().unwrap();
+
+    let udt_info = UDTInfo {
+        inputs: vec![(u128::MAX, 0), (1, 1)],
+        outputs: vec![(u128::MAX, 0), (1, 1)],
+    };
+    assert_eq!(
+        udt_info.check_udt().unwrap_err(),
+        SilentBerryError::AccountBookOverflow
+    );
+
+    // Outputs are no longer compared with Inputs themselves
+    let udt_info = UDTInfo {
+        inputs: vec![(100, 0)],
+        outputs: vec![(90, 0)],
+    };
+    assert_eq!(
+        udt_info.check_udt().unwrap_err(),
+        SilentBerryError::CheckXUDT
+    );
+}
+
+#[test]
+fn test_smt_total_withdrawn() {
+    let mut smt = AccountBook::new_test();
+    let k = SmtKey::Buyer([0xD1; 32].into());
+    let total_income = smt.get_total_income();
+    let account_balance = smt.get_account_balance();
+    let total_withdrawn = smt.get_total_withdrawn();
+    assert_eq!(account_balance + total_withdrawn, total_income);
+    smt.update(k.clone(), 100);
+    smt.update(SmtKey::AccountBalance, account_balance - 100);
+    let proof = smt.proof(k.clone());
+
+    // TotalWithdrawn is not updated with the payout
+    let root_hash_1 = smt.root_hash();
+    let cproof = utils::AccountBookProof::new(proof);
+    assert_eq!(
+        cproof
+            .verify(
+                root_hash_1.clone(),
+                total_income,
+                account_balance - 100,
+                total_withdrawn,
+                (k.clone(), Some(100))
+            )
+            .unwrap_err(),
+        types::error::SilentBerryError::AccountBook
+    );
+    assert!(!cproof
+        .verify(
+            root_hash_1,
+            total_income,
+            account_balance - 100,
+            total_withdrawn + 100,
+            (k.clone(), Some(100))
+        )
+        .unwrap());
+
+    smt.update(SmtKey::TotalWithdrawn, total_withdrawn + 100);
+    let root_hash_2 = smt.root_hash();
+    assert!(cproof
+        .verify(
+            root_hash_2.clone(),
+            total_income,
+            account_balance - 100,
+            total_withdrawn + 100,
+            (k.clone(), Some(100))
+        )
+        .unwrap());
+
+    // Paying out more than the income cannot balance
+    assert_eq!(
+        cproof
+            .verify(root_hash_2, 0, account_balance, 0, (k, Some(100)))
+            .unwrap_err(),
+        types::error::SilentBerryError::AccountBook
+    );
+}
+
+#[test]
+fn test_smt_legacy_total_withdrawn() {
+    // An AccountBook created before the TotalWithdrawn leaf, it has paid out 20000
+    let mut smt = AccountBook::default();
+    smt.update(SmtKey::TotalIncome, 30000);
+    smt.update(SmtKey::AccountBalance, 10000);
+    let k = SmtKey::Buyer([0xD2; 32].into());
+    smt.update(k.clone(), 100);
+    let root_hash_1 = smt.root_hash();
+    let cproof = utils::AccountBookProof::new(smt.proof(k.clone()));
+
+    assert!(!cproof
+        .verify(
+            root_hash_1.clone(),
+            30000,
+            10000,
+            20000,
+            (k.clone(), Some(100))
+        )
+        .unwrap());
+    assert!(cproof
+        .verify_old(
+            root_hash_1.clone(),
+            30000,
+            10000,
+            20000,
+            (k.clone(), Some(100))
+        )
+        .unwrap());
+    // The payouts are still bound by the balance
+    assert_eq!(
+        cproof
+            .verify_old(root_hash_1, 30000, 10000, 0, (k.clone(), Some(100)))
+            .unwrap_err(),
+        types::error::SilentBerryError::AccountBook
+    );
+
+    // The next transition writes the leaf, and the AccountBook is no longer legacy
+    smt.update(k.clone(), 200);
+    smt.update(SmtKey::AccountBalance, 9900);
+    smt.update(SmtKey::TotalWithdrawn, 20100);
+    let root_hash_2 = smt.root_hash();
+    assert!(cproof
+        .verify(
+            root_hash_2.clone(),
+            30000,
+            9900,
+            20100,
+            (k.clone(), Some(200))
+        )
+        .unwrap());
+    assert!(cproof
+        .verify_old(
+            root_hash_2.clone(),
+            30000,
+            9900,
+            20100,
+            (k.clone(), Some(200))
+        )
+        .unwrap());
+}
+
+#[test]
+fn test_spore_dna_hash() {
+    let spore = |content: &str| {
+        crate::spore::build_serialized_spore_data(
+            content.as_bytes().to_vec(),
+            "dob/1",
+            Some([3u8; 32].to_vec()),
+        )
+    };
+    let dna_hash = utils::get_spore_dna_hash(&spore("{\"dna\":\"4000000000002\"}")).unwrap();
+    assert!(
+        dna_hash == utils::get_spore_dna_hash(&spore("{ \"dna\" : \"4000000000002\" }")).unwrap()
+    );
+    assert!(
+        utils::get_spore_dna_hash(&spore("{\"dna\":\"4a00000000002\"}")).unwrap()
+            == utils::get_spore_dna_hash(&spore("{\"dna\":\"4A00000000002\"}")).unwrap()
+    );
+    assert!(dna_hash != utils::get_spore_dna_hash(&spore("{\"dna\":\"4000000000102\"}")).unwrap());
+    utils::get_spore_dna_hash(&spore("{\"id\":\"4000000000002\"}")).unwrap_err();
+    utils::get_spore_dna_hash(&spore("{\"dna\":\"\"}")).unwrap_err();
+
+    // Only the dna field of the top level object counts
+    let get = |content: &str| utils::get_spore_dna_hash(&spore(content));
+    assert!(
+        get(r#"{"name":"\"dna\":\"4000000000102\"","dna":"4000000000002"}"#).unwrap() == dna_hash
+    );
+    assert!(get(r#"{"desc":"dna","dna":"4000000000002"}"#).unwrap() == dna_hash);
+    assert!(
+        get(r#"{"traits":{"dna":"4000000000102"},"list":["dna",1],"dna":"4000000000002"}"#)
+            .unwrap()
+            == dna_hash
+    );
+    assert!(get(r#"{"level":2, "ok":true, "dna":"4000000000002"}"#).unwrap() == dna_hash);
+    get(r#"{"traits":{"dna":"4000000000002"}}"#).unwrap_err();
+    get(r#"{"dna":"4000000000002","dna":"4000000000102"}"#).unwrap_err();
+    get(r#"{"dna":4000000000002}"#).unwrap_err();
+    get(r#"["dna","4000000000002"]"#).unwrap_err();
+    get(r#"{"dna":"4000000000002""#).unwrap_err();
+
+    check_leaf_apart(SmtKey::Dna(dna_hash.clone()), SmtKey::Buyer(dna_hash));
+}
+
+#[test]
+fn test_spore_level_by_rule() {
+    use ckb_testtool::ckb_types::prelude::{Builder, Entity};
+    use types::{LevelRange, LevelRangeVec, LevelRule, LevelRuleOpt};
+
+    let spore = |content: &str| {
+        crate::spore::build_serialized_spore_data(
+            content.as_bytes().to_vec(),
+            "dob/1",
+            Some([3u8; 32].to_vec()),
+        )
+    };
+    let range = |min: u8, max: u8, level: u8| {
+        LevelRange::new_builder()
+            .min(min.into())
+            .max(max.into())
+            .level(level.into())
+            .build()
+    };
+    let rule = LevelRuleOpt::new_builder()
+        .set(Some(
+            LevelRule::new_builder()
+                .dna_byte_index(1.into())
+                .dna_bit_mask(0xF0.into())
+                .ranges(
+                    LevelRangeVec::new_builder()
+                        .push(range(0x00, 0x1F, 0))
+                        .push(range(0x20, 0x7F, 1))
+                        .push(range(0x80, 0xFF, 2))
+                        .build(),
+                )
+                .build(),
+        ))
+        .build();
+
+    let level = |content: &str| utils::get_spore_level_by_rule(&spore(content), &rule);
+    assert_eq!(level("{\"dna\":\"4000000000002\"}").unwrap(), 0);
+    assert_eq!(level("{\"dna\":\"402f000000002\"}").unwrap(), 1);
+    assert_eq!(level("{\"dna\":\"40F0000000000\"}").unwrap(), 2);
+    level("{\"dna\":\"40\"}").unwrap_err();
+    level("{\"dna\":\"40zz\"}").unwrap_err();
+
+    // Without a rule, the last two hex characters are used
+    assert_eq!(
+        utils::get_spore_level_by_rule(&spore("{\"dna\":\"40F0000000002\"}"), &Default::default())
+            .unwrap(),
+        2
+    );
+}
+
+#[test]
+fn test_smt_buyer_lock() {
+    let mut smt = AccountBook::new_test();
+    let owner: Hash = [0xE1; 32].into();
+    let spore_id: Hash = [0xE2; 32].into();
+    check_leaf_apart(
+        SmtKey::BuyerLock(owner.clone()),
+        SmtKey::Buyer(owner.clone()),
+    );
+
+    smt.update(SmtKey::BuyerLock(owner.clone()), 2);
+    let total_income = smt.get_total_income();
+    let account_balance = smt.get_account_balance();
+    let root_hash_1 = smt.root_hash();
+    let keys = [
+        SmtKey::Buyer(spore_id.clone()),
+        SmtKey::BuyerLock(owner.clone()),
+    ];
+    let proof = smt.proof_keys(&keys);
+
+    smt.update(SmtKey::Buyer(spore_id.clone()), 0);
+    smt.update(SmtKey::BuyerLock(owner.clone()), 3);
+    smt.update(SmtKey::TotalIncome, total_income + 200);
+    smt.update(SmtKey::AccountBalance, account_balance + 200);
+    let root_hash_2 = smt.root_hash();
+
+    let cproof = utils::AccountBookProof::new(proof);
+    assert!(cproof
+        .verify_leaves(
+            root_hash_1,
+            total_income,
+            account_balance,
+            20000,
+            &[(keys[0].clone(), None), (keys[1].clone(), Some(2))],
+        )
+        .unwrap());
+    assert!(cproof
+        .verify_leaves(
+            root_hash_2.clone(),
+            total_income + 200,
+            account_balance + 200,
+            20000,
+            &[(keys[0].clone(), Some(0)), (keys[1].clone(), Some(3))],
+        )
+        .unwrap());
+    assert!(!cproof
+        .verify_leaves(
+            root_hash_2,
+            total_income + 200,
+            account_balance + 200,
+            20000,
+            &[(keys[0].clone(), Some(0)), (keys[1].clone(), Some(2))],
+        )
+        .unwrap());
+}
+
+#[test]
+fn test_smt_approved_dna() {
+    let mut approved = AccountBook::default();
+    let dna_1 = Hash::ckb_hash(b"4000000000002");
+    let dna_2 = Hash::ckb_hash(b"4000000000102");
+    let dna_3 = Hash::ckb_hash(b"4000000000202");
+    approved.update(SmtKey::Dna(dna_1.clone()), utils::SMT_VALUE_APPROVED);
+    approved.update(SmtKey::Dna(dna_2.clone()), utils::SMT_VALUE_APPROVED);
+    let root_hash = approved.root_hash();
+    let dna_proof = |dna: &Hash| {
+        let key = SmtKey::Dna(dna.clone()).get_key();
+        approved
+            .tree
+            .merkle_proof(vec![key])
+            .expect("Merkle proof failed")
+            .compile(vec![key])
+            .expect("Compile proof failed")
+            .0
+    };
+
+    let proof = utils::AccountBookProof::new(dna_proof(&dna_1));
+    assert!(proof
+        .verify_approved_dna(root_hash.clone(), dna_1.clone())
+        .unwrap());
+    assert!(!proof
+        .verify_approved_dna(root_hash.clone(), dna_2.clone())
+        .unwrap_or(false));
+
+    let proof = utils::AccountBookProof::new(dna_proof(&dna_3));
+    assert!(!proof.verify_approved_dna(root_hash, dna_3).unwrap_or(false));
+}
+
+#[test]
+fn test_installment_revocation_refund() {
+    use ckb_testtool::ckb_types::prelude::{Builder, Entity, Pack};
+    use types::BuyIntentData;
+
+    let data = BuyIntentData::new_builder()
+        .price(200u128.pack())
+        .quantity(2u32.pack())
+        .build();
+    assert_eq!(utils::get_full_payment(&data).unwrap(), 400);
+    // Paid in full, all goes back
+    assert_eq!(utils::get_revocation_refund(&data, 400).unwrap(), 400);
+
+    let data = data
+        .as_builder()
+        .deposit(100u128.pack())
+        .refund_ratio(30.into())
+        .build();
+    assert_eq!(utils::get_revocation_refund(&data, 250).unwrap(), 75);
+    assert_eq!(utils::get_revocation_refund(&data, 400).unwrap(), 400);
+
+    let data = data.as_builder().refund_ratio(150.into()).build();
+    assert_eq!(utils::get_revocation_refund(&data, 250).unwrap(), 250);
+}
+
+#[test]
+fn test_coupon_signature() {
+    use ckb_testtool::{
+        ckb_crypto::secp::Generator,
+        ckb_types::{
+            prelude::{Builder, Entity, Pack},
+            H256,
+        },
+    };
+    use types::{error::SilentBerryError, BuyIntentData, Byte65, Coupon};
+
+    let (privkey, pubkey) = Generator::random_keypair();
+    let pubkey_hash = Hash::ckb_hash(&pubkey.serialize());
+    let account_book_hash: Hash = [0x31; 32].into();
+    let nonce: Hash = [0x32; 32].into();
+
+    let sign = |coupon: Coupon| {
+        let message: [u8; 32] = utils::get_coupon_message(&coupon).into();
+        let signature = privkey
+            .sign_recoverable(&H256(message))
+            .expect("Sign coupon failed")
+            .serialize();
+        coupon
+            .as_builder()
+            .signature(Byte65::new_unchecked(signature.into()))
+            .build()
+    };
+    let coupon = sign(
+        Coupon::new_builder()
+            .discount(50u128.pack())
+            .account_book_script_hash(account_book_hash.clone().into())
+            .expire_since(2000u64.pack())
+            .nonce(nonce.clone().into())
+            .build(),
+    );
+    let data = BuyIntentData::new_builder()
+        .price(200u128.pack())
+        .expire_since(1000u64.pack())
+        .coupon(coupon.clone())
+        .build();
+    assert_eq!(utils::get_unit_payment(&data), 150);
+
+    assert_eq!(
+        utils::verify_coupon(&data, &account_book_hash, &pubkey_hash).unwrap(),
+        50
+    );
+    // Not for this AccountBook
+    assert_eq!(
+        utils::verify_coupon(&data, &[0x33; 32].into(), &pubkey_hash).unwrap_err(),
+        SilentBerryError::Coupon
+    );
+    // Not signed by the platform
+    assert_eq!(
+        utils::verify_coupon(&data, &account_book_hash, &[0x34; 32].into()).unwrap_err(),
+        SilentBerryError::Coupon
+    );
+    // The discount is changed after signing
+    let forged = data
+        .clone()
+        .as_builder()
+        .coupon(coupon.as_builder().discount(200u128.pack()).build())
+        .build();
+    assert_eq!(
+        utils::verify_coupon(&forged, &account_book_hash, &pubkey_hash).unwrap_err(),
+        SilentBerryError::Coupon
+    );
+    // The intent outlives the coupon
+    let late = data
+        .clone()
+        .as_builder()
+        .expire_since(3000u64.pack())
+        .build();
+    assert_eq!(
+        utils::verify_coupon(&late, &account_book_hash, &pubkey_hash).unwrap_err(),
+        SilentBerryError::Coupon
+    );
+    // Without a coupon
+    let data = data.as_builder().coupon(Default::default()).build();
+    assert_eq!(
+        utils::verify_coupon(&data, &account_book_hash, &[0u8; 32].into()).unwrap(),
+        0
+    );
+
+    check_leaf_apart(SmtKey::Coupon(nonce.clone()), SmtKey::Buyer(nonce));
+}
+
+#[test]
+fn test_smt_sibling() {
+    let mut smt = AccountBook::new_test();
+    let spore_id: Hash = [0xF1; 32].into();
+    check_leaf_apart(
+        SmtKey::Minted(spore_id.clone()),
+        SmtKey::Buyer(spore_id.clone()),
+    );
+
+    smt.update(SmtKey::Minted(spore_id.clone()), 1);
+    let root_hash = smt.root_hash();
+    let keys = [
+        SmtKey::Buyer(spore_id.clone()),
+        SmtKey::Minted(spore_id.clone()),
+    ];
+    let cproof = utils::AccountBookProof::new(smt.sibling_proof(&keys));
+    assert!(cproof
+        .verify_sibling(
+            root_hash.clone(),
+            &[(keys[0].clone(), None), (keys[1].clone(), Some(1))],
+        )
+        .unwrap());
+    // Minted at another level
+    assert!(!cproof
+        .verify_sibling(
+            root_hash.clone(),
+            &[(keys[0].clone(), None), (keys[1].clone(), Some(2))],
+        )
+        .unwrap());
+
+    // The totals are not part of a sibling proof
+    let cproof = utils::AccountBookProof::new(smt.proof_keys(&keys));
+    assert!(cproof
+        .verify_sibling(
+            root_hash,
+            &[(keys[0].clone(), None), (keys[1].clone(), Some(1))],
+        )
+        .is_err());
+}
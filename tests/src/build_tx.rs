@@ -238,6 +238,29 @@ pub fn build_account_book(
         .build()
 }
 
+// An AccountBook only referenced by CellDeps
+pub fn build_account_book_dep(
+    context: &mut Context,
+    type_id: Hash,
+    cell_data: &AccountBookCellData,
+) -> (Script, CellDep) {
+    let account_book_script = build_account_book_script(context, Some(type_id));
+    let lock_script = build_always_suc_script(context, &[]);
+    let out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(16.pack())
+            .lock(lock_script)
+            .type_(account_book_script.clone().pack())
+            .build(),
+        cell_data.as_bytes(),
+    );
+
+    (
+        account_book_script.unwrap(),
+        CellDep::new_builder().out_point(out_point).build(),
+    )
+}
+
 pub fn build_cluster(
     context: &mut Context,
     cluster: (&str, &str),
@@ -292,6 +315,101 @@ pub fn build_mint_spore(
     tx.as_advanced_builder().cell_dep(cluster_deps).build()
 }
 
+// Burn Spores in Inputs and mint one Spore in Outputs
+fn add_burned_spores(
+    context: &mut Context,
+    tx: TransactionView,
+    spore_out_point: &OutPoint,
+    burned: &[([u8; 32], SporeData)],
+) -> (
+    TransactionView,
+    Vec<(Option<Script>, spore_types::spore::action::SporeActionUnion)>,
+) {
+    let mut actions = Vec::new();
+    let mut tx = tx;
+    for (spore_id, spore_data) in burned {
+        let spore_type = crate::spore::build_spore_type_script(
+            context,
+            spore_out_point,
+            spore_id.to_vec().into(),
+        );
+        let spore_input =
+            crate::spore::build_spore_input(context, spore_type.clone(), spore_data.clone());
+        tx = tx.as_advanced_builder().input(spore_input).build();
+
+        let action = crate::spore::co_build::build_burn_spore_action(context, *spore_id);
+        actions.push((spore_type, action));
+    }
+    (tx, actions)
+}
+
+pub fn build_burn_spores(
+    context: &mut Context,
+    tx: TransactionView,
+    burned: &[([u8; 32], SporeData)],
+) -> TransactionView {
+    let (spore_out_point, spore_script_dep) =
+        crate::spore::build_spore_contract_materials(context, SPORE_NAME);
+    let (tx, actions) = add_burned_spores(context, tx, &spore_out_point, burned);
+
+    let tx = tx.as_advanced_builder().cell_dep(spore_script_dep).build();
+    crate::spore::co_build::complete_co_build_message_with_actions(tx, &actions)
+}
+
+pub fn build_reforge_spores(
+    context: &mut Context,
+    tx: TransactionView,
+    cluster_deps: CellDep,
+    burned: &[([u8; 32], SporeData)],
+    minted: (SporeData, Script),
+) -> TransactionView {
+    let (spore_out_point, spore_script_dep) =
+        crate::spore::build_spore_contract_materials(context, SPORE_NAME);
+    let (tx, mut actions) = add_burned_spores(context, tx, &spore_out_point, burned);
+
+    let (spore_data, spore_lock) = minted;
+    let first_input = tx.inputs().get(0).unwrap();
+    let type_id = crate::spore::build_type_id(&first_input, tx.outputs().len());
+    let spore_type =
+        crate::spore::build_spore_type_script(context, &spore_out_point, type_id.to_vec().into());
+    let spore_output =
+        crate::spore::build_normal_output_cell(context, spore_lock.clone(), spore_type.clone());
+    let action =
+        crate::spore::co_build::build_mint_spore_action(type_id, spore_data.as_slice(), spore_lock);
+    actions.push((spore_type, action));
+
+    let tx = tx
+        .as_advanced_builder()
+        .output(spore_output)
+        .output_data(spore_data.as_slice().pack())
+        .cell_dep(spore_script_dep)
+        .cell_dep(cluster_deps)
+        .build();
+    crate::spore::co_build::complete_co_build_message_with_actions(tx, &actions)
+}
+
+// A Spore only referenced by CellDeps
+pub fn build_spore_dep(
+    context: &mut Context,
+    spore_id: [u8; 32],
+    spore_data: &SporeData,
+) -> CellDep {
+    let (spore_out_point, _) = crate::spore::build_spore_contract_materials(context, SPORE_NAME);
+    let spore_type =
+        crate::spore::build_spore_type_script(context, &spore_out_point, spore_id.to_vec().into());
+    let lock_script = build_always_suc_script(context, &[]);
+    let out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000.pack())
+            .lock(lock_script)
+            .type_(spore_type.pack())
+            .build(),
+        spore_data.as_bytes(),
+    );
+
+    CellDep::new_builder().out_point(out_point).build()
+}
+
 pub fn get_spore_id(tx: &TransactionView) -> [u8; 32] {
     let spore_output = tx.outputs().into_iter().find(|f| {
         if let Some(t) = f.type_().to_opt() {
@@ -313,6 +431,22 @@ pub fn get_spore_id(tx: &TransactionView) -> [u8; 32] {
         .unwrap()
 }
 
+pub fn get_spore_data(tx: &TransactionView) -> SporeData {
+    let spore_index = tx
+        .outputs()
+        .into_iter()
+        .position(|f| {
+            if let Some(t) = f.type_().to_opt() {
+                t.code_hash().as_slice() == *SporeCodeHash
+            } else {
+                false
+            }
+        })
+        .unwrap();
+
+    SporeData::new_unchecked(tx.outputs_data().get(spore_index).unwrap().unpack())
+}
+
 pub fn get_spore_level(tx: &TransactionView) -> u8 {
     let spore_index = tx
         .outputs()
@@ -338,24 +472,80 @@ pub fn update_accountbook(
     context: &mut Context,
     tx: TransactionView,
     price: u128,
+) -> TransactionView {
+    update_accountbook_with_leaves(context, tx, price, &[])
+}
+
+// Selling that also adds leaves of value 0, such as the nonce of a coupon
+pub fn update_accountbook_with_leaves(
+    context: &mut Context,
+    tx: TransactionView,
+    price: u128,
+    leaves: &[utils::SmtKey],
+) -> TransactionView {
+    let leaves: Vec<_> = leaves.iter().map(|key| (key.clone(), 0)).collect();
+    update_accountbook_from(
+        context,
+        tx,
+        (AccountBook::new_test(), Default::default()),
+        price,
+        &leaves,
+    )
+}
+
+// Selling from an SMT that may already hold leaves, such as the Spores bought by the buyer lock.
+// `leaves` are set to their new value, the AccountBookData brings the other fields of the witness
+pub fn update_accountbook_from(
+    context: &mut Context,
+    tx: TransactionView,
+    account_book: (AccountBook, AccountBookData),
+    price: u128,
+    leaves: &[(utils::SmtKey, u128)],
 ) -> TransactionView {
     use utils::{Hash, SmtKey};
 
     // Update SMT
-    let mut smt = AccountBook::new_test();
+    let (mut smt, account_book_data) = account_book;
     let old_smt_hash = smt.root_hash();
 
     let sport_id: Hash = get_spore_id(&tx).into();
+    let dna_hash = utils::get_spore_dna_hash(&get_spore_data(&tx)).unwrap();
 
     let account_balance = smt.get_account_balance();
     let total_income = smt.get_total_income();
+    let total_withdrawn = smt.get_total_withdrawn();
 
     smt.update(SmtKey::AccountBalance, account_balance + price);
     smt.update(SmtKey::TotalIncome, total_income + price);
     smt.update(SmtKey::Buyer(sport_id.clone()), 0);
+    smt.update(SmtKey::Dna(dna_hash.clone()), 0);
+    for (key, value) in leaves {
+        smt.update(key.clone(), *value);
+    }
     let new_smt_hash = smt.root_hash();
-    let smt_proof = smt.proof(SmtKey::Buyer(sport_id));
+    let mut keys = vec![SmtKey::Buyer(sport_id), SmtKey::Dna(dna_hash)];
+    keys.extend(leaves.iter().map(|(key, _)| key.clone()));
+    let smt_proof = smt.proof_keys(&keys);
+
+    let account_book_data = account_book_data
+        .as_builder()
+        .proof(smt_proof.pack())
+        .total_income_udt(total_income.pack())
+        .total_withdrawn_udt(total_withdrawn.pack())
+        .build();
+    update_accountbook_smt(context, tx, account_book_data, (old_smt_hash, new_smt_hash))
+}
+
+// Set the SMT roots of the AccountBook cells and the AccountBookData of its witness
+pub fn update_accountbook_smt(
+    context: &mut Context,
+    tx: TransactionView,
+    account_book_data: AccountBookData,
+    smt_hash: (utils::Hash, utils::Hash),
+) -> TransactionView {
+    use utils::Hash;
 
+    let (old_smt_hash, new_smt_hash) = smt_hash;
     // Update tx
     let input_pos = tx
         .inputs()
@@ -396,14 +586,9 @@ pub fn update_accountbook(
 
     let mut witnesses: Vec<Bytes> = tx.witnesses().into_iter().collect();
     let witness = WitnessArgs::new_unchecked(witnesses.get(input_pos).unwrap().unpack());
-    let abd = AccountBookData::new_unchecked(witness.output_type().to_opt().unwrap().unpack())
-        .as_builder()
-        .proof(smt_proof.pack())
-        .total_income_udt(total_income.pack())
-        .build();
     let witness = witness
         .as_builder()
-        .output_type(Some(abd.as_bytes()).pack())
+        .output_type(Some(account_book_data.as_bytes()).pack())
         .build();
     *witnesses.get_mut(input_pos).unwrap() = witness.as_bytes().pack();
 
@@ -474,3 +659,27 @@ pub fn build_withdrawal_intent_script(
             .expect("WITHDRAWAL_INTENT_NAME"),
     )
 }
+
+// Put the output_type of the witness at index, keeping what the input already has there
+pub fn set_output_type_witness(
+    tx: TransactionView,
+    index: usize,
+    data: ckb_testtool::bytes::Bytes,
+) -> TransactionView {
+    let mut witnesses: Vec<Bytes> = tx.witnesses().into_iter().collect();
+    while witnesses.len() <= index {
+        witnesses.push(Default::default());
+    }
+    let witness = if witnesses[index].is_empty() {
+        WitnessArgs::default()
+    } else {
+        WitnessArgs::new_unchecked(witnesses[index].raw_data())
+    };
+    witnesses[index] = witness
+        .as_builder()
+        .output_type(Some(data).pack())
+        .build()
+        .as_bytes()
+        .pack();
+    tx.as_advanced_builder().set_witnesses(witnesses).build()
+}
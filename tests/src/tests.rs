@@ -2,13 +2,16 @@ use crate::{build_tx::*, *};
 use account_book::AccountBook;
 use ckb_testtool::ckb_types::{
     core::TransactionBuilder,
-    packed::{CellDep, CellInput, CellOutput, Script, WitnessArgs},
+    packed::{Byte32, CellDep, CellInput, CellOutput, Script, WitnessArgs},
     prelude::{Builder, Entity, Pack, PackVec},
 };
 use spore_types::spore::SporeData;
 use types::{
-    blockchain::OutPoint, AccountBookCellData, AccountBookData, BuyIntentData, DobSellingData,
-    Uint128Opt, WithdrawalBuyer, WithdrawalIntentData, WithdrawalSporeInfo,
+    blockchain::OutPoint, AccountBookCellData, AccountBookData, BuyIntentData, CoAuthor,
+    CoAuthorVec, DobSellingData, LevelRange, LevelRangeVec, LevelRule, LevelRuleOpt,
+    MergeSporeInfo, MergeSporeInfoOpt, SiblingProof, SiblingProofVec, Uint128Opt, Uint128OptVec,
+    Uint128Vec, WithdrawalBuyer, WithdrawalIntentData, WithdrawalSporeInfo, WithdrawalSporeInfoOpt,
+    WithdrawalSporeInfoVec,
 };
 use utils::{Hash, SmtKey};
 
@@ -74,6 +77,143 @@ fn def_spore(context: &mut Context, cluster_lock: Script) -> (SporeData, CellDep
     (spore_data, cluster_deps)
 }
 
+fn def_spore_with_dna(cluster_id: [u8; 32], dna: &str) -> SporeData {
+    crate::spore::build_serialized_spore_data(
+        format!("{{\"dna\":\"{}\"}}", dna).as_bytes().to_vec(),
+        "dob/1",
+        Some(cluster_id.to_vec()),
+    )
+}
+
+fn def_spore_info(
+    spore_id: [u8; 32],
+    spore_level: u8,
+    cluster_id: [u8; 32],
+) -> WithdrawalSporeInfo {
+    WithdrawalSporeInfo::new_builder()
+        .spore_code_hash((*SporeCodeHash).pack())
+        .spore_level(spore_level.into())
+        .spore_id(spore_id.pack())
+        .cluster_id(cluster_id.pack())
+        .build()
+}
+
+// Levels by the first byte of the DNA, the DNA of def_spore ("40...") is of `level`
+fn def_level_rule(level: u8) -> LevelRuleOpt {
+    let range = |min: u8, max: u8, level: u8| {
+        LevelRange::new_builder()
+            .min(min.into())
+            .max(max.into())
+            .level(level.into())
+            .build()
+    };
+    LevelRuleOpt::new_builder()
+        .set(Some(
+            LevelRule::new_builder()
+                .dna_byte_index(0.into())
+                .dna_bit_mask(0xFF.into())
+                .ranges(
+                    LevelRangeVec::new_builder()
+                        .push(range(0x00, 0x3F, 0))
+                        .push(range(0x40, 0xFF, level))
+                        .build(),
+                )
+                .build(),
+        ))
+        .build()
+}
+
+fn pack_udt_vec(udts: &[u128]) -> Uint128Vec {
+    Uint128Vec::new_builder()
+        .extend(udts.iter().map(|udt| udt.pack()))
+        .build()
+}
+
+fn pack_udt_opt_vec(udts: &[Option<u128>]) -> Uint128OptVec {
+    Uint128OptVec::new_builder()
+        .extend(
+            udts.iter()
+                .map(|udt| Uint128Opt::new_builder().set(udt.map(|v| v.pack())).build()),
+        )
+        .build()
+}
+
+// Another AccountBook of the cluster in CellDeps, holding the leaves with a value
+fn build_sibling_account_book(
+    context: &mut Context,
+    type_id: [u8; 32],
+    cell_data: AccountBookCellData,
+    leaves: &[(SmtKey, Option<u128>)],
+) -> (SiblingProof, CellDep) {
+    let mut smt = AccountBook::new_empty();
+    for (key, value) in leaves {
+        if let Some(value) = value {
+            smt.update(key.clone(), *value);
+        }
+    }
+    let keys: Vec<SmtKey> = leaves.iter().map(|(key, _)| key.clone()).collect();
+    let cell_data = cell_data
+        .as_builder()
+        .smt_root_hash(smt.root_hash().into())
+        .build();
+    let (script, cell_dep) = build_account_book_dep(context, type_id.into(), &cell_data);
+
+    let sibling_proof = SiblingProof::new_builder()
+        .account_book_script_hash(script.calc_script_hash())
+        .proof(smt.sibling_proof(&keys).pack())
+        .build();
+    (sibling_proof, cell_dep)
+}
+
+// The AccountBooks of `spore_level` that sold the Spores, one per Spore
+fn build_origin_proofs(
+    context: &mut Context,
+    cluster_id: [u8; 32],
+    spore_level: u8,
+    spore_ids: &[[u8; 32]],
+) -> (SiblingProofVec, Vec<CellDep>) {
+    let mut sibling_proofs = SiblingProofVec::new_builder();
+    let mut sibling_deps = Vec::new();
+    for (i, spore_id) in spore_ids.iter().enumerate() {
+        let sibling_cell_data = def_account_book_cell_data(context)
+            .as_builder()
+            .level(spore_level.into())
+            .cluster_id(cluster_id.pack())
+            .build();
+        let (sibling_proof, sibling_dep) = build_sibling_account_book(
+            context,
+            [0x1F - i as u8; 32],
+            sibling_cell_data,
+            &[(SmtKey::Buyer((*spore_id).into()), Some(0))],
+        );
+        sibling_proofs = sibling_proofs.push(sibling_proof);
+        sibling_deps.push(sibling_dep);
+    }
+    (sibling_proofs.build(), sibling_deps)
+}
+
+// Absolute timestamp (seconds) since
+fn timestamp_since(timestamp: u64) -> u64 {
+    0x4000_0000_0000_0000 | timestamp
+}
+
+// The since of the AccountBook input, built by build_account_book
+fn set_account_book_since(tx: TransactionView, since: u64) -> TransactionView {
+    let inputs: Vec<CellInput> = tx
+        .inputs()
+        .into_iter()
+        .enumerate()
+        .map(|(i, input)| {
+            if i == 1 {
+                input.as_builder().since(since.pack()).build()
+            } else {
+                input
+            }
+        })
+        .collect();
+    tx.as_advanced_builder().set_inputs(inputs).build()
+}
+
 fn get_cluster_id(d: &SporeData) -> [u8; 32] {
     d.cluster_id()
         .to_opt()
@@ -84,6 +224,98 @@ fn get_cluster_id(d: &SporeData) -> [u8; 32] {
         .unwrap()
 }
 
+// What a BuyIntent asks for, the defaults pay the price of one Spore in full
+#[derive(Default)]
+struct IntentTerms {
+    quantity: u32,
+    max_price: u128,
+    deposit: u128,
+    fill_fee: u64,
+}
+
+fn buy_intent(terms: IntentTerms) -> Result<Cycle, Error> {
+    let IntentTerms {
+        quantity,
+        max_price,
+        deposit,
+        fill_fee,
+    } = terms;
+    let mut context = new_context();
+
+    let lock_script = build_user1_script(&mut context);
+    let udt_cell = build_xudt_cell(&mut context, lock_script.clone());
+
+    let inputs = vec![
+        build_input(context.create_cell(udt_cell.clone(), 1000u128.to_le_bytes().to_vec().into())),
+        build_input(build_out_point1(&mut context, lock_script.clone())),
+    ];
+
+    let def_cluster_lock = build_always_suc_script(&mut context, &[2u8; 32]);
+    let (spore_data, _) = def_spore(&mut context, def_cluster_lock);
+    let dob_selling_data = def_dob_selling_data(&mut context, &spore_data);
+    let dob_selling = build_dob_selling_script(&mut context, &dob_selling_data);
+    let dob_selling_udt = build_xudt_cell(&mut context, dob_selling.clone());
+
+    let buy_intent_data = def_buy_intent_data(&mut context, &dob_selling_data)
+        .as_builder()
+        .quantity(quantity.pack())
+        .max_price(max_price.pack())
+        .deposit(deposit.pack())
+        .fill_fee(fill_fee.pack())
+        .build();
+    let unit_payment = if max_price == 0 {
+        DATA_ASSET_AMOUNT
+    } else {
+        max_price
+    };
+    // An installment only pays the deposit at creation
+    let paid_udt = if deposit == 0 {
+        unit_payment * quantity.max(1) as u128
+    } else {
+        deposit
+    };
+
+    let buy_intent_script = build_buy_intent_cell(
+        &mut context,
+        1000,
+        lock_script,
+        &[[0u8; 32], ckb_hash(buy_intent_data.as_slice())].concat(),
+    );
+
+    let outputs = vec![
+        udt_cell.clone(),
+        dob_selling_udt.clone(),
+        buy_intent_script.clone(),
+    ];
+
+    let outputs_data: Vec<ckb_testtool::ckb_types::packed::Bytes> = vec![
+        (1000u128 - paid_udt).to_le_bytes().to_vec().pack(),
+        paid_udt.to_le_bytes().to_vec().pack(),
+        Default::default(),
+    ];
+
+    let witnesses = vec![
+        Default::default(),
+        Default::default(),
+        WitnessArgs::new_builder()
+            .output_type(Some(buy_intent_data.as_bytes()).pack())
+            .build()
+            .as_slice()
+            .pack(),
+    ];
+
+    let tx = context.complete_tx(
+        TransactionBuilder::default()
+            .inputs(inputs)
+            .outputs(outputs)
+            .outputs_data(outputs_data.pack())
+            .witnesses(witnesses)
+            .build(),
+    );
+    // print_tx_info(&context, &tx);
+    verify_and_dump_failed_tx(&context, &tx, MAX_CYCLES)
+}
+
 #[test]
 fn test_simple_buy_intent() {
     let mut context = new_context();
@@ -146,7 +378,74 @@ fn test_simple_buy_intent() {
 }
 
 #[test]
-fn test_revocation_buy_intent() {
+fn test_multi_unit_buy_intent() {
+    buy_intent(IntentTerms {
+        quantity: 3,
+        ..Default::default()
+    })
+    .expect("pass");
+}
+
+#[test]
+fn test_max_price_buy_intent() {
+    buy_intent(IntentTerms {
+        quantity: 2,
+        max_price: DATA_ASSET_AMOUNT + 50,
+        ..Default::default()
+    })
+    .expect("pass");
+}
+
+#[test]
+fn test_installment_buy_intent() {
+    buy_intent(IntentTerms {
+        deposit: DATA_ASSET_AMOUNT / 4,
+        ..Default::default()
+    })
+    .expect("pass");
+}
+
+#[test]
+fn test_buy_intent_with_fill_fee() {
+    buy_intent(IntentTerms {
+        fill_fee: 100,
+        ..Default::default()
+    })
+    .expect("pass");
+}
+
+#[test]
+fn test_buy_intent_with_fill_fee_above_capacity() {
+    buy_intent(IntentTerms {
+        fill_fee: 1001,
+        ..Default::default()
+    })
+    .expect_err("The fill fee cannot be more than the capacity of the intent");
+}
+
+// How the owner takes back its intent, the defaults revoke it after expiry with a full refund
+#[derive(Default)]
+struct Revocation {
+    // Cancelled before expiry, authorized by a cell of the owner
+    cancel: bool,
+    // Revoked after an amendment recreated the DobSelling
+    amended: bool,
+    // The xUDT goes to another lock instead of the owner
+    refund_elsewhere: bool,
+    // An installment with only half of the price escrowed, 40% of it is forfeited
+    incomplete_installment: bool,
+    // The owner keeps what the installment forfeits
+    forfeit_kept: bool,
+}
+
+fn revocation_buy_intent(revocation: Revocation) -> Result<Cycle, Error> {
+    let Revocation {
+        cancel,
+        amended,
+        refund_elsewhere,
+        incomplete_installment,
+        forfeit_kept,
+    } = revocation;
     let mut context = new_context();
     let def_lock_script: Script = build_always_suc_script(&mut context, &[]);
     let def_cluster_lock = build_always_suc_script(&mut context, &[2u8; 32]);
@@ -158,7 +457,37 @@ fn test_revocation_buy_intent() {
         .as_builder()
         .owner_script_hash(def_lock_script.calc_script_hash())
         .build();
-    let input_buy_intent_tx_hash = ckb_testtool::context::random_hash();
+    let buy_intent_data = def_buy_intent_data(&mut context, &dob_selling_data)
+        .as_builder()
+        .owner_script_hash(def_lock_script.calc_script_hash())
+        .build();
+    let forfeit_lock_script = build_always_suc_script(&mut context, &[6u8; 32]);
+    let (buy_intent_data, escrowed, refunded) = if incomplete_installment {
+        (
+            buy_intent_data
+                .as_builder()
+                .deposit((DATA_ASSET_AMOUNT / 2).pack())
+                .refund_ratio(60.into())
+                .forfeit_script_hash(forfeit_lock_script.calc_script_hash())
+                .build(),
+            DATA_ASSET_AMOUNT / 2,
+            DATA_ASSET_AMOUNT / 2 * 60 / 100,
+        )
+    } else {
+        (buy_intent_data, DATA_ASSET_AMOUNT, DATA_ASSET_AMOUNT)
+    };
+    // An amended intent is revoked with the DobSelling recreated by the amendment
+    let (input_buy_intent_tx_hash, buy_intent_data) = if amended {
+        amend_intent(
+            &mut context,
+            def_lock_script.clone(),
+            account_book_script_hash,
+            dob_selling_data.clone(),
+            buy_intent_data,
+        )
+    } else {
+        (ckb_testtool::context::random_hash(), buy_intent_data)
+    };
     let cell_input_dob_selling = {
         let dob_selling = build_dob_selling_script(&mut context, &dob_selling_data);
         let dob_selling_udt = build_xudt_cell(&mut context, dob_selling.clone());
@@ -171,16 +500,22 @@ fn test_revocation_buy_intent() {
         context.create_cell_with_out_point(
             dob_selling_outpoint.clone(),
             dob_selling_udt.clone(),
-            DATA_ASSET_AMOUNT.to_le_bytes().to_vec().into(),
+            escrowed.to_le_bytes().to_vec().into(),
         );
         CellInput::new_builder()
             .previous_output(dob_selling_outpoint)
             .build()
     };
+    let refund_lock = if refund_elsewhere {
+        build_always_suc_script(&mut context, &[5u8; 32])
+    } else {
+        def_lock_script.clone()
+    };
+    let refund = if forfeit_kept { escrowed } else { refunded };
     let tx = TransactionBuilder::default()
         .input(cell_input_dob_selling)
-        .output(build_xudt_cell(&mut context, def_lock_script.clone()))
-        .output_data(DATA_ASSET_AMOUNT.to_le_bytes().to_vec().pack())
+        .output(build_xudt_cell(&mut context, refund_lock))
+        .output_data(refund.to_le_bytes().to_vec().pack())
         .witness(
             WitnessArgs::new_builder()
                 .lock(Some(dob_selling_data.as_bytes()).pack())
@@ -191,10 +526,6 @@ fn test_revocation_buy_intent() {
         .build();
 
     // Buy Intent
-    let buy_intent_data = def_buy_intent_data(&mut context, &dob_selling_data)
-        .as_builder()
-        .owner_script_hash(def_lock_script.calc_script_hash())
-        .build();
     let cell_input_buy_intent = {
         let buy_intent_script = build_buy_intent_cell(
             &mut context,
@@ -218,7 +549,7 @@ fn test_revocation_buy_intent() {
         );
         CellInput::new_builder()
             .previous_output(buy_intent_outpoint)
-            .since(10000.pack())
+            .since(if cancel { 0 } else { 10000 }.pack())
             .build()
     };
 
@@ -241,88 +572,106 @@ fn test_revocation_buy_intent() {
         )
         .build();
 
+    // Cancel before expiry, authorized by a cell of the owner
+    let tx = if cancel {
+        let owner_cell = context.create_cell(
+            CellOutput::new_builder()
+                .capacity(100u64.pack())
+                .lock(def_lock_script.clone())
+                .build(),
+            Default::default(),
+        );
+        tx.as_advanced_builder()
+            .input(CellInput::new_builder().previous_output(owner_cell).build())
+            .build()
+    } else {
+        tx
+    };
+
+    // The rest of an incomplete installment goes to the forfeit lock
+    let tx = if refund < escrowed {
+        tx.as_advanced_builder()
+            .output(build_xudt_cell(&mut context, forfeit_lock_script))
+            .output_data((escrowed - refund).to_le_bytes().to_vec().pack())
+            .build()
+    } else {
+        tx
+    };
+
     let tx = context.complete_tx(tx);
     // print_tx_info(&context, &tx);
-    verify_and_dump_failed_tx(&context, &tx, MAX_CYCLES).expect("pass");
+    verify_and_dump_failed_tx(&context, &tx, MAX_CYCLES)
 }
 
-#[test]
-fn test_simple_selling() {
-    let mut context = new_context();
-    let def_lock_script: Script = build_always_suc_script(&mut context, &[]);
-
-    let account_book_type_id = [14u8; 32];
-
-    let account_book_script =
-        build_account_book_script(&mut context, Some(account_book_type_id.into())).unwrap();
-    let lock_proxy_script =
-        build_proxy_lock_script(&mut context, account_book_script.calc_script_hash().into());
-    let (spore_data, cluster_deps) = def_spore(&mut context, lock_proxy_script);
+// The owner amends the intent, the DobSelling is recreated with it.
+// dob_selling: (data, escrowed before, escrowed after)
+fn build_amend_tx(
+    context: &mut Context,
+    owner_lock_script: Script,
+    account_book_script_hash: [u8; 32],
+    dob_selling: (DobSellingData, u128, u128),
+    buy_intent: (BuyIntentData, BuyIntentData),
+) -> TransactionView {
+    let (dob_selling_data, old_escrowed, new_escrowed) = dob_selling;
+    let (buy_intent_data, buy_intent_data_new) = buy_intent;
+    let min_capacity = |data: &BuyIntentData| {
+        u64::from_le_bytes(data.min_capacity().as_slice().try_into().unwrap())
+    };
 
-    let tx = TransactionBuilder::default().build();
+    let buy_intent_cell = build_buy_intent_cell(
+        context,
+        min_capacity(&buy_intent_data),
+        owner_lock_script.clone(),
+        &[
+            account_book_script_hash,
+            ckb_hash(buy_intent_data.as_slice()),
+        ]
+        .concat(),
+    );
+    let buy_intent_cell_new = build_buy_intent_cell(
+        context,
+        min_capacity(&buy_intent_data_new),
+        owner_lock_script.clone(),
+        &[
+            account_book_script_hash,
+            ckb_hash(buy_intent_data_new.as_slice()),
+        ]
+        .concat(),
+    );
+    let dob_selling = build_dob_selling_script(context, &dob_selling_data);
+    let dob_selling_udt = build_xudt_cell(context, dob_selling);
 
-    // Account Book
-    let account_book_data = AccountBookData::new_builder()
-        // .proof(smt_proof.pack())
-        .build();
-    let ab_cell_data = def_account_book_cell_data(&mut context)
-        .as_builder()
-        // .smt_root_hash(old_smt_hash.into())
-        .level(2.into())
-        .cluster_id(get_cluster_id(&spore_data).pack())
-        .buyer_count(15u32.pack())
-        .build();
-    let ab_cell_data_new = ab_cell_data
-        .clone()
-        .as_builder()
-        // .smt_root_hash(new_smt_hash.into())
-        .buyer_count(16u32.pack())
+    let input_tx_hash = ckb_testtool::context::random_hash();
+    let dob_selling_outpoint = OutPoint::new_builder()
+        .tx_hash(input_tx_hash.clone())
+        .index(0u32.pack())
         .build();
-
-    let tx = build_account_book(
-        &mut context,
-        tx,
-        account_book_type_id.into(),
-        account_book_data.clone(),
-        (ab_cell_data, ab_cell_data_new),
-        (10000, 10000 + DATA_ASSET_AMOUNT),
+    context.create_cell_with_out_point(
+        dob_selling_outpoint.clone(),
+        dob_selling_udt.clone(),
+        old_escrowed.to_le_bytes().to_vec().into(),
     );
-
-    let input_buy_intent_tx_hash = ckb_testtool::context::random_hash();
-    // DOB Selling
-    let account_book_script =
-        build_account_book_script(&mut context, Some(account_book_type_id.into())).unwrap();
-    let dob_selling_data = def_dob_selling_data(&mut context, &spore_data)
-        .as_builder()
-        .account_book_script_hash(account_book_script.calc_script_hash())
+    let buy_intent_outpoint = OutPoint::new_builder()
+        .tx_hash(input_tx_hash)
+        .index(1u32.pack())
         .build();
-    let cell_input_dob_selling = {
-        let dob_selling = build_dob_selling_script(&mut context, &dob_selling_data);
-        let dob_selling_udt = build_xudt_cell(&mut context, dob_selling.clone());
-
-        let dob_selling_outpoint = OutPoint::new_builder()
-            .tx_hash(input_buy_intent_tx_hash.clone())
-            .index(0u32.pack())
-            .build();
+    context.create_cell_with_out_point(
+        buy_intent_outpoint.clone(),
+        buy_intent_cell,
+        Default::default(),
+    );
 
-        context.create_cell_with_out_point(
-            dob_selling_outpoint.clone(),
-            dob_selling_udt.clone(),
-            DATA_ASSET_AMOUNT.to_le_bytes().to_vec().into(),
-        );
-        CellInput::new_builder()
-            .previous_output(dob_selling_outpoint)
-            .build()
-    };
-    let tx = tx
-        .as_advanced_builder()
-        .input(cell_input_dob_selling)
-        .output(
-            CellOutput::new_builder()
-                .lock(def_lock_script.clone())
-                .capacity(1000.pack())
-                .build(),
-        )
+    // The owner authorizes the amendment with a cell of its own
+    let tx = TransactionBuilder::default()
+        .input(build_input(dob_selling_outpoint))
+        .input(build_input(buy_intent_outpoint))
+        .input(build_input(build_out_point1(
+            context,
+            owner_lock_script.clone(),
+        )))
+        .output(dob_selling_udt)
+        .output_data(new_escrowed.to_le_bytes().to_vec().pack())
+        .output(buy_intent_cell_new)
         .output_data(Default::default())
         .witness(
             WitnessArgs::new_builder()
@@ -331,23 +680,236 @@ fn test_simple_selling() {
                 .as_bytes()
                 .pack(),
         )
+        .witness(
+            WitnessArgs::new_builder()
+                .input_type(Some(buy_intent_data.as_bytes()).pack())
+                .output_type(Some(buy_intent_data_new.as_bytes()).pack())
+                .build()
+                .as_bytes()
+                .pack(),
+        )
         .build();
 
-    // Buy Intent
-    let buy_intent_data = def_buy_intent_data(&mut context, &dob_selling_data);
-    let cell_input_buy_intent = {
-        let buy_intent_script = build_buy_intent_cell(
-            &mut context,
-            1000,
-            def_lock_script.clone(),
-            &[
-                account_book_script
-                    .calc_script_hash()
-                    .as_slice()
-                    .try_into()
-                    .unwrap(),
-                ckb_hash(buy_intent_data.as_slice()),
-            ]
+    // An installment is topped up by the owner
+    let tx = if new_escrowed > old_escrowed {
+        let owner_udt = build_xudt_cell(context, owner_lock_script);
+        tx.as_advanced_builder()
+            .input(build_input(context.create_cell(
+                owner_udt,
+                (new_escrowed - old_escrowed).to_le_bytes().to_vec().into(),
+            )))
+            .build()
+    } else {
+        tx
+    };
+    context.complete_tx(tx)
+}
+
+// The cells of an intent amended to expire later, to be filled or revoked afterwards.
+// Returns the hash of the amendment and the amended intent
+fn amend_intent(
+    context: &mut Context,
+    owner_lock_script: Script,
+    account_book_script_hash: [u8; 32],
+    dob_selling_data: DobSellingData,
+    buy_intent_data: BuyIntentData,
+) -> (Byte32, BuyIntentData) {
+    let escrowed = utils::get_full_payment(&buy_intent_data).unwrap();
+    let buy_intent_data_new = buy_intent_data
+        .clone()
+        .as_builder()
+        .expire_since(2000u64.pack())
+        .build();
+    let tx = build_amend_tx(
+        context,
+        owner_lock_script,
+        account_book_script_hash,
+        (dob_selling_data, escrowed, escrowed),
+        (buy_intent_data, buy_intent_data_new.clone()),
+    );
+    verify_and_dump_failed_tx(context, &tx, MAX_CYCLES).expect("pass");
+    (tx.hash(), buy_intent_data_new)
+}
+
+// What the owner changes in its open intent, the defaults expire it later with a larger capacity
+#[derive(Default)]
+struct Amendment {
+    // An installment with half of the price escrowed is topped up to the full price
+    top_up: bool,
+    // Only the xUDT is topped up, the intent is recreated with the same args
+    same_terms: bool,
+    // The DobSelling of another intent is recreated in the same transaction
+    foreign_dob_selling: bool,
+}
+
+fn amend_buy_intent(amendment: Amendment) -> Result<Cycle, Error> {
+    let Amendment {
+        top_up,
+        same_terms,
+        foreign_dob_selling,
+    } = amendment;
+    let mut context = new_context();
+    let owner_lock_script = build_user1_script(&mut context);
+
+    let def_cluster_lock = build_always_suc_script(&mut context, &[2u8; 32]);
+    let (spore_data, _) = def_spore(&mut context, def_cluster_lock);
+    let dob_selling_data = def_dob_selling_data(&mut context, &spore_data);
+
+    // An installment escrows the deposit first, and is topped up to the price
+    let deposit = if top_up { DATA_ASSET_AMOUNT / 2 } else { 0 };
+    let buy_intent_data = def_buy_intent_data(&mut context, &dob_selling_data)
+        .as_builder()
+        .deposit(deposit.pack())
+        .owner_script_hash(owner_lock_script.calc_script_hash())
+        .build();
+    let buy_intent_data_new = if same_terms {
+        buy_intent_data.clone()
+    } else {
+        buy_intent_data
+            .clone()
+            .as_builder()
+            .min_capacity(1500u64.pack())
+            .expire_since(2000u64.pack())
+            .build()
+    };
+    let old_escrowed = if top_up { deposit } else { DATA_ASSET_AMOUNT };
+
+    let tx = build_amend_tx(
+        &mut context,
+        owner_lock_script,
+        [0u8; 32],
+        (dob_selling_data.clone(), old_escrowed, DATA_ASSET_AMOUNT),
+        (buy_intent_data, buy_intent_data_new),
+    );
+
+    let tx = if foreign_dob_selling {
+        let other_dob_selling_data = dob_selling_data
+            .as_builder()
+            .owner_script_hash([9u8; 32].pack())
+            .build();
+        let other_dob_selling = build_dob_selling_script(&mut context, &other_dob_selling_data);
+        let other_dob_selling_udt = build_xudt_cell(&mut context, other_dob_selling);
+        let input = build_input(context.create_cell(
+            other_dob_selling_udt.clone(),
+            DATA_ASSET_AMOUNT.to_le_bytes().to_vec().into(),
+        ));
+
+        let mut witnesses: Vec<_> = tx.witnesses().into_iter().collect();
+        witnesses.resize(tx.inputs().len(), Default::default());
+        witnesses.push(
+            WitnessArgs::new_builder()
+                .lock(Some(other_dob_selling_data.as_bytes()).pack())
+                .build()
+                .as_bytes()
+                .pack(),
+        );
+        context.complete_tx(
+            tx.as_advanced_builder()
+                .input(input)
+                .output(other_dob_selling_udt)
+                .output_data(DATA_ASSET_AMOUNT.to_le_bytes().to_vec().pack())
+                .set_witnesses(witnesses)
+                .build(),
+        )
+    } else {
+        tx
+    };
+
+    verify_and_dump_failed_tx(&context, &tx, MAX_CYCLES)
+}
+
+#[test]
+fn test_amend_buy_intent() {
+    amend_buy_intent(Default::default()).expect("pass");
+}
+
+#[test]
+fn test_top_up_installment() {
+    amend_buy_intent(Amendment {
+        top_up: true,
+        ..Default::default()
+    })
+    .expect("pass");
+}
+
+#[test]
+fn test_top_up_installment_with_same_terms() {
+    amend_buy_intent(Amendment {
+        top_up: true,
+        same_terms: true,
+        ..Default::default()
+    })
+    .expect("pass");
+}
+
+#[test]
+fn test_amend_with_foreign_dob_selling() {
+    amend_buy_intent(Amendment {
+        foreign_dob_selling: true,
+        ..Default::default()
+    })
+    .expect_err("Only its own BuyIntent can recreate the DobSelling");
+}
+
+#[test]
+fn test_revocation_buy_intent() {
+    let mut context = new_context();
+    let def_lock_script: Script = build_always_suc_script(&mut context, &[]);
+    let def_cluster_lock = build_always_suc_script(&mut context, &[2u8; 32]);
+    let (spore_data, _cluster_deps) = def_spore(&mut context, def_cluster_lock);
+    let account_book_script_hash = [0u8; 32];
+
+    // DOB Selling
+    let dob_selling_data = def_dob_selling_data(&mut context, &spore_data)
+        .as_builder()
+        .owner_script_hash(def_lock_script.calc_script_hash())
+        .build();
+    let input_buy_intent_tx_hash = ckb_testtool::context::random_hash();
+    let cell_input_dob_selling = {
+        let dob_selling = build_dob_selling_script(&mut context, &dob_selling_data);
+        let dob_selling_udt = build_xudt_cell(&mut context, dob_selling.clone());
+
+        let dob_selling_outpoint = OutPoint::new_builder()
+            .tx_hash(input_buy_intent_tx_hash.clone())
+            .index(0u32.pack())
+            .build();
+
+        context.create_cell_with_out_point(
+            dob_selling_outpoint.clone(),
+            dob_selling_udt.clone(),
+            DATA_ASSET_AMOUNT.to_le_bytes().to_vec().into(),
+        );
+        CellInput::new_builder()
+            .previous_output(dob_selling_outpoint)
+            .build()
+    };
+    let tx = TransactionBuilder::default()
+        .input(cell_input_dob_selling)
+        .output(build_xudt_cell(&mut context, def_lock_script.clone()))
+        .output_data(DATA_ASSET_AMOUNT.to_le_bytes().to_vec().pack())
+        .witness(
+            WitnessArgs::new_builder()
+                .lock(Some(dob_selling_data.as_bytes()).pack())
+                .build()
+                .as_bytes()
+                .pack(),
+        )
+        .build();
+
+    // Buy Intent
+    let buy_intent_data = def_buy_intent_data(&mut context, &dob_selling_data)
+        .as_builder()
+        .owner_script_hash(def_lock_script.calc_script_hash())
+        .build();
+    let cell_input_buy_intent = {
+        let buy_intent_script = build_buy_intent_cell(
+            &mut context,
+            1000,
+            def_lock_script.clone(),
+            &[
+                account_book_script_hash,
+                ckb_hash(buy_intent_data.as_slice()),
+            ]
             .concat(),
         );
 
@@ -362,12 +924,20 @@ fn test_simple_selling() {
         );
         CellInput::new_builder()
             .previous_output(buy_intent_outpoint)
+            .since(10000.pack())
             .build()
     };
 
     let tx = tx
         .as_advanced_builder()
         .input(cell_input_buy_intent)
+        .output(
+            CellOutput::new_builder()
+                .capacity(1000u64.pack())
+                .lock(def_lock_script.clone())
+                .build(),
+        )
+        .output_data(Default::default())
         .witness(
             WitnessArgs::new_builder()
                 .input_type(Some(buy_intent_data.as_bytes()).pack())
@@ -377,291 +947,2899 @@ fn test_simple_selling() {
         )
         .build();
 
-    // Spore
-    let spore_lock = def_spore_lock(&mut context);
-    let tx = build_mint_spore(&mut context, tx, cluster_deps, spore_data, spore_lock);
-
-    let tx = update_accountbook(&mut context, tx, DATA_ASSET_AMOUNT);
     let tx = context.complete_tx(tx);
-    print_tx_info(&context, &tx);
+    // print_tx_info(&context, &tx);
     verify_and_dump_failed_tx(&context, &tx, MAX_CYCLES).expect("pass");
 }
 
 #[test]
-fn test_simple_withdrawal_intent() {
-    // Add Spore
+fn test_cancel_buy_intent() {
+    revocation_buy_intent(Revocation {
+        cancel: true,
+        ..Default::default()
+    })
+    .expect("pass");
+}
+
+#[test]
+fn test_revocation_amended_buy_intent() {
+    revocation_buy_intent(Revocation {
+        amended: true,
+        ..Default::default()
+    })
+    .expect("pass");
+}
+
+#[test]
+fn test_revocation_buy_intent_without_refund() {
+    revocation_buy_intent(Revocation {
+        refund_elsewhere: true,
+        ..Default::default()
+    })
+    .expect_err("xUDT must be refunded to the owner");
+}
+
+#[test]
+fn test_revocation_incomplete_installment() {
+    revocation_buy_intent(Revocation {
+        incomplete_installment: true,
+        ..Default::default()
+    })
+    .expect("pass");
+}
+
+#[test]
+fn test_revocation_incomplete_installment_without_forfeit() {
+    revocation_buy_intent(Revocation {
+        incomplete_installment: true,
+        forfeit_kept: true,
+        ..Default::default()
+    })
+    .expect_err("The forfeited xUDT must go to the forfeit lock");
+}
+
+// How one Spore is sold to an intent, a partial fill leaves the rest of the intent behind.
+// The defaults sell the Spore of the DobSelling to an intent for one Spore at the price
+#[derive(Default)]
+struct Sale {
+    // The intent takes any Spore in the cluster and level of the AccountBook
+    floor_offer: bool,
+    quantity: u32,
+    max_price: u128,
+    // Filled with the DobSelling recreated by an amendment
+    amended: bool,
+    // The rest of a partial fill is redirected to another owner
+    tampered_intent: bool,
+    tampered_dob_selling: bool,
+    fill_fee: u64,
+    // Capacity of the intent kept by the filler on top of the fill fee
+    capacity_taken: u64,
+    // Another Spore with the same DNA was sold before
+    dna_sold: bool,
+    // Only approved DNA is sold, Some(true) when the DNA of the Spore is approved
+    approved_dna: Option<bool>,
+    // The level of the Spore comes from its DNA instead of the content
+    level_rule: LevelRuleOpt,
+    // (Spores bought by the buyer lock so far, max_per_buyer)
+    buyer_quota: Option<(u128, u32)>,
+    // Another BuyIntent is filled in the same transaction
+    second_intent: bool,
+}
+
+fn selling(sale: Sale) -> Result<Cycle, Error> {
+    let Sale {
+        floor_offer,
+        quantity,
+        max_price,
+        amended,
+        tampered_intent,
+        tampered_dob_selling,
+        fill_fee,
+        capacity_taken,
+        dna_sold,
+        approved_dna,
+        level_rule,
+        buyer_quota,
+        second_intent,
+    } = sale;
+    let quantity = quantity.max(1);
     let mut context = new_context();
+    let def_lock_script: Script = build_always_suc_script(&mut context, &[]);
+
+    let account_book_type_id = [14u8; 32];
+
+    let account_book_script =
+        build_account_book_script(&mut context, Some(account_book_type_id.into())).unwrap();
+    let lock_proxy_script =
+        build_proxy_lock_script(&mut context, account_book_script.calc_script_hash().into());
+    let (spore_data, cluster_deps) = def_spore(&mut context, lock_proxy_script);
+
     let tx = TransactionBuilder::default().build();
-    let def_lock_script = build_always_suc_script(&mut context, &[]);
 
-    let def_cluster_lock = build_always_suc_script(&mut context, &[2u8; 32]);
-    let (spore_data, _cluster_dep) = def_spore(&mut context, def_cluster_lock);
-    let tx = build_transfer_spore(&mut context, tx, &spore_data);
-    let tx = context.complete_tx(tx);
+    // SMT before the sale
+    let owner_script_hash: Hash = def_lock_script
+        .calc_script_hash()
+        .as_slice()
+        .try_into()
+        .unwrap();
+    let dna_hash = utils::get_spore_dna_hash(&spore_data).unwrap();
+    let mut smt = AccountBook::new_test();
+    let mut leaves = Vec::new();
+    let mut account_book_data = AccountBookData::new_builder();
+    if dna_sold {
+        smt.update(SmtKey::Dna(dna_hash.clone()), 0);
+    }
+    if let Some((bought, _)) = buyer_quota {
+        if bought != 0 {
+            smt.update(SmtKey::BuyerLock(owner_script_hash.clone()), bought);
+            account_book_data = account_book_data
+                .buyer_lock_bought(Uint128Opt::new_builder().set(Some(bought.pack())).build());
+        }
+        leaves.push((SmtKey::BuyerLock(owner_script_hash), bought + 1));
+    }
+    // The approved set of the creator, with another DNA
+    let approved_dna_root = match approved_dna {
+        Some(approved) => {
+            let mut approved_set = AccountBook::default();
+            approved_set.update(
+                SmtKey::Dna(Hash::ckb_hash(b"4000000000102")),
+                utils::SMT_VALUE_APPROVED,
+            );
+            if approved {
+                approved_set.update(SmtKey::Dna(dna_hash.clone()), utils::SMT_VALUE_APPROVED);
+            }
+            account_book_data = account_book_data.approved_dna_proof(
+                approved_set
+                    .sibling_proof(&[SmtKey::Dna(dna_hash.clone())])
+                    .pack(),
+            );
+            approved_set.root_hash()
+        }
+        None => [0u8; 32].into(),
+    };
 
-    let withdrawal_spore_info = WithdrawalSporeInfo::new_builder()
-        .spore_code_hash((*SporeCodeHash).pack())
-        .spore_level(2.into())
-        .spore_id(get_spore_id(&tx).pack())
+    // Account Book
+    let ab_cell_data = def_account_book_cell_data(&mut context)
+        .as_builder()
+        // .smt_root_hash(old_smt_hash.into())
+        .level(2.into())
         .cluster_id(get_cluster_id(&spore_data).pack())
+        .buyer_count(15u32.pack())
+        .level_rule(level_rule)
+        .max_per_buyer(buyer_quota.map_or(0, |(_, max)| max).pack())
+        .approved_dna_root(approved_dna_root.into())
+        .build();
+    let ab_cell_data_new = ab_cell_data
+        .clone()
+        .as_builder()
+        // .smt_root_hash(new_smt_hash.into())
+        .buyer_count(16u32.pack())
         .build();
 
-    let withdrawal_intent_data = def_withdrawal_intent_data(&mut context)
+    let tx = build_account_book(
+        &mut context,
+        tx,
+        account_book_type_id.into(),
+        AccountBookData::new_builder().build(),
+        (ab_cell_data, ab_cell_data_new),
+        (10000, 10000 + DATA_ASSET_AMOUNT),
+    );
+
+    // DOB Selling
+    let account_book_script =
+        build_account_book_script(&mut context, Some(account_book_type_id.into())).unwrap();
+    let dob_selling_data = def_dob_selling_data(&mut context, &spore_data);
+    // A floor offer takes any Spore in the cluster and level of the AccountBook
+    let spore_data_hash = if floor_offer {
+        [0u8; 32].pack()
+    } else {
+        dob_selling_data.spore_data_hash()
+    };
+    let dob_selling_data = dob_selling_data
         .as_builder()
-        .buyer(
-            WithdrawalBuyer::new_builder()
-                .set(withdrawal_spore_info)
-                .build(),
-        )
+        .account_book_script_hash(account_book_script.calc_script_hash())
+        .spore_data_hash(spore_data_hash)
         .build();
-    let withdrawal_intent_script =
-        build_withdrawal_intent_script(&mut context, &withdrawal_intent_data, [0u8; 32].into());
-    // Inputs: CKB + Spore
-    // Output: Withdrawal intent + Spore
-    let tx = tx
-        .as_advanced_builder()
-        .input(build_input(build_out_point1(
+    let unit_payment = if max_price == 0 {
+        DATA_ASSET_AMOUNT
+    } else {
+        max_price
+    };
+    let escrowed = unit_payment * quantity as u128;
+
+    // The capacity of Buy Intent goes back to its owner
+    let buy_intent_data = def_buy_intent_data(&mut context, &dob_selling_data)
+        .as_builder()
+        .owner_script_hash(def_lock_script.calc_script_hash())
+        .quantity(quantity.pack())
+        .max_price(max_price.pack())
+        .fill_fee(fill_fee.pack())
+        .build();
+    // An amended intent is filled with the DobSelling recreated by the amendment
+    let (input_buy_intent_tx_hash, buy_intent_data) = if amended {
+        amend_intent(
             &mut context,
             def_lock_script.clone(),
-        )))
+            account_book_script
+                .calc_script_hash()
+                .as_slice()
+                .try_into()
+                .unwrap(),
+            dob_selling_data.clone(),
+            buy_intent_data,
+        )
+    } else {
+        (ckb_testtool::context::random_hash(), buy_intent_data)
+    };
+    let cell_input_dob_selling = {
+        let dob_selling = build_dob_selling_script(&mut context, &dob_selling_data);
+        let dob_selling_udt = build_xudt_cell(&mut context, dob_selling.clone());
+
+        let dob_selling_outpoint = OutPoint::new_builder()
+            .tx_hash(input_buy_intent_tx_hash.clone())
+            .index(0u32.pack())
+            .build();
+
+        context.create_cell_with_out_point(
+            dob_selling_outpoint.clone(),
+            dob_selling_udt.clone(),
+            escrowed.to_le_bytes().to_vec().into(),
+        );
+        CellInput::new_builder()
+            .previous_output(dob_selling_outpoint)
+            .build()
+    };
+    let tx = tx
+        .as_advanced_builder()
+        .input(cell_input_dob_selling)
         .output(
             CellOutput::new_builder()
-                .lock(def_lock_script)
-                .type_(withdrawal_intent_script.pack())
-                .capacity(1000.pack())
+                .lock(def_lock_script.clone())
+                .capacity((1000 - fill_fee - capacity_taken).pack())
                 .build(),
         )
         .output_data(Default::default())
         .witness(
             WitnessArgs::new_builder()
-                .output_type(Some(withdrawal_intent_data.as_bytes()).pack())
+                .lock(Some(dob_selling_data.as_bytes()).pack())
                 .build()
-                .as_slice()
+                .as_bytes()
                 .pack(),
         )
         .build();
 
-    let tx = context.complete_tx(tx);
-    // print_tx_info(&context, &tx);
-    verify_and_dump_failed_tx(&context, &tx, MAX_CYCLES).expect("pass");
+    // Buy Intent
+    let cell_input_buy_intent = {
+        let buy_intent_script = build_buy_intent_cell(
+            &mut context,
+            1000,
+            def_lock_script.clone(),
+            &[
+                account_book_script
+                    .calc_script_hash()
+                    .as_slice()
+                    .try_into()
+                    .unwrap(),
+                ckb_hash(buy_intent_data.as_slice()),
+            ]
+            .concat(),
+        );
+
+        let buy_intent_outpoint = OutPoint::new_builder()
+            .tx_hash(input_buy_intent_tx_hash)
+            .index(1u32.pack())
+            .build();
+        context.create_cell_with_out_point(
+            buy_intent_outpoint.clone(),
+            buy_intent_script.clone(),
+            Default::default(),
+        );
+        CellInput::new_builder()
+            .previous_output(buy_intent_outpoint)
+            .build()
+    };
+
+    let tx = tx
+        .as_advanced_builder()
+        .input(cell_input_buy_intent)
+        .witness(
+            WitnessArgs::new_builder()
+                .input_type(Some(buy_intent_data.as_bytes()).pack())
+                .build()
+                .as_bytes()
+                .pack(),
+        )
+        .build();
+
+    // The seller makes up a price above max_price
+    let tx = if unit_payment < DATA_ASSET_AMOUNT {
+        let seller_lock_script = build_user2_script(&mut context);
+        let seller_udt = build_xudt_cell(&mut context, seller_lock_script);
+        tx.as_advanced_builder()
+            .input(build_input(
+                context.create_cell(
+                    seller_udt,
+                    (DATA_ASSET_AMOUNT - unit_payment)
+                        .to_le_bytes()
+                        .to_vec()
+                        .into(),
+                ),
+            ))
+            .build()
+    } else {
+        tx
+    };
+
+    // What is paid above the price goes back to the owner
+    let tx = if unit_payment > DATA_ASSET_AMOUNT {
+        tx.as_advanced_builder()
+            .output(build_xudt_cell(&mut context, def_lock_script.clone()))
+            .output_data(
+                (unit_payment - DATA_ASSET_AMOUNT)
+                    .to_le_bytes()
+                    .to_vec()
+                    .pack(),
+            )
+            .build()
+    } else {
+        tx
+    };
+
+    // The rest of the intent and its xUDT
+    let tx = if quantity > 1 {
+        let other_owner = [9u8; 32].pack();
+        let remaining_dob_selling_data = if tampered_dob_selling {
+            dob_selling_data
+                .clone()
+                .as_builder()
+                .owner_script_hash(other_owner.clone())
+                .build()
+        } else {
+            dob_selling_data.clone()
+        };
+        let remaining_dob_selling =
+            build_dob_selling_script(&mut context, &remaining_dob_selling_data);
+        let remaining_intent_data = buy_intent_data
+            .clone()
+            .as_builder()
+            .quantity((quantity - 1).pack())
+            .dob_selling_script_hash(remaining_dob_selling.calc_script_hash())
+            .build();
+        let remaining_intent_data = if tampered_intent {
+            remaining_intent_data
+                .as_builder()
+                .owner_script_hash(other_owner)
+                .build()
+        } else {
+            remaining_intent_data
+        };
+        let remaining_intent = build_buy_intent_cell(
+            &mut context,
+            1000,
+            def_lock_script.clone(),
+            &[
+                account_book_script
+                    .calc_script_hash()
+                    .as_slice()
+                    .try_into()
+                    .unwrap(),
+                ckb_hash(remaining_intent_data.as_slice()),
+            ]
+            .concat(),
+        );
+
+        let intent_index = tx.outputs().len();
+        let tx = tx
+            .as_advanced_builder()
+            .output(remaining_intent)
+            .output_data(Default::default())
+            .output(build_xudt_cell(&mut context, remaining_dob_selling))
+            .output_data(
+                (unit_payment * (quantity - 1) as u128)
+                    .to_le_bytes()
+                    .to_vec()
+                    .pack(),
+            )
+            .build();
+        let tx = set_output_type_witness(tx, intent_index, remaining_intent_data.as_bytes());
+        set_output_type_witness(tx, intent_index + 1, remaining_dob_selling_data.as_bytes())
+    } else {
+        tx
+    };
+
+    // Another intent of the owner, expiring later
+    let tx = if second_intent {
+        let other_intent_data = buy_intent_data
+            .clone()
+            .as_builder()
+            .expire_since(3000u64.pack())
+            .build();
+        let other_intent = build_buy_intent_cell(
+            &mut context,
+            1000,
+            def_lock_script.clone(),
+            &[
+                account_book_script
+                    .calc_script_hash()
+                    .as_slice()
+                    .try_into()
+                    .unwrap(),
+                ckb_hash(other_intent_data.as_slice()),
+            ]
+            .concat(),
+        );
+        let input = build_input(context.create_cell(other_intent, Default::default()));
+
+        let mut witnesses: Vec<_> = tx.witnesses().into_iter().collect();
+        witnesses.resize(tx.inputs().len(), Default::default());
+        witnesses.push(
+            WitnessArgs::new_builder()
+                .input_type(Some(other_intent_data.as_bytes()).pack())
+                .build()
+                .as_bytes()
+                .pack(),
+        );
+        tx.as_advanced_builder()
+            .input(input)
+            .set_witnesses(witnesses)
+            .build()
+    } else {
+        tx
+    };
+
+    // Spore
+    let spore_lock = def_spore_lock(&mut context);
+    let tx = build_mint_spore(&mut context, tx, cluster_deps, spore_data, spore_lock);
+
+    let tx = update_accountbook_from(
+        &mut context,
+        tx,
+        (smt, account_book_data.build()),
+        DATA_ASSET_AMOUNT,
+        &leaves,
+    );
+    let tx = context.complete_tx(tx);
+    // print_tx_info(&context, &tx);
+    verify_and_dump_failed_tx(&context, &tx, MAX_CYCLES)
+}
+
+#[test]
+fn test_simple_selling() {
+    let mut context = new_context();
+    let def_lock_script: Script = build_always_suc_script(&mut context, &[]);
+
+    let account_book_type_id = [14u8; 32];
+
+    let account_book_script =
+        build_account_book_script(&mut context, Some(account_book_type_id.into())).unwrap();
+    let lock_proxy_script =
+        build_proxy_lock_script(&mut context, account_book_script.calc_script_hash().into());
+    let (spore_data, cluster_deps) = def_spore(&mut context, lock_proxy_script);
+
+    let tx = TransactionBuilder::default().build();
+
+    // Account Book
+    let account_book_data = AccountBookData::new_builder()
+        // .proof(smt_proof.pack())
+        .build();
+    let ab_cell_data = def_account_book_cell_data(&mut context)
+        .as_builder()
+        // .smt_root_hash(old_smt_hash.into())
+        .level(2.into())
+        .cluster_id(get_cluster_id(&spore_data).pack())
+        .buyer_count(15u32.pack())
+        .build();
+    let ab_cell_data_new = ab_cell_data
+        .clone()
+        .as_builder()
+        // .smt_root_hash(new_smt_hash.into())
+        .buyer_count(16u32.pack())
+        .build();
+
+    let tx = build_account_book(
+        &mut context,
+        tx,
+        account_book_type_id.into(),
+        account_book_data.clone(),
+        (ab_cell_data, ab_cell_data_new),
+        (10000, 10000 + DATA_ASSET_AMOUNT),
+    );
+
+    let input_buy_intent_tx_hash = ckb_testtool::context::random_hash();
+    // DOB Selling
+    let account_book_script =
+        build_account_book_script(&mut context, Some(account_book_type_id.into())).unwrap();
+    let dob_selling_data = def_dob_selling_data(&mut context, &spore_data)
+        .as_builder()
+        .account_book_script_hash(account_book_script.calc_script_hash())
+        .build();
+    let cell_input_dob_selling = {
+        let dob_selling = build_dob_selling_script(&mut context, &dob_selling_data);
+        let dob_selling_udt = build_xudt_cell(&mut context, dob_selling.clone());
+
+        let dob_selling_outpoint = OutPoint::new_builder()
+            .tx_hash(input_buy_intent_tx_hash.clone())
+            .index(0u32.pack())
+            .build();
+
+        context.create_cell_with_out_point(
+            dob_selling_outpoint.clone(),
+            dob_selling_udt.clone(),
+            DATA_ASSET_AMOUNT.to_le_bytes().to_vec().into(),
+        );
+        CellInput::new_builder()
+            .previous_output(dob_selling_outpoint)
+            .build()
+    };
+    let tx = tx
+        .as_advanced_builder()
+        .input(cell_input_dob_selling)
+        .output(
+            CellOutput::new_builder()
+                .lock(def_lock_script.clone())
+                .capacity(1000.pack())
+                .build(),
+        )
+        .output_data(Default::default())
+        .witness(
+            WitnessArgs::new_builder()
+                .lock(Some(dob_selling_data.as_bytes()).pack())
+                .build()
+                .as_bytes()
+                .pack(),
+        )
+        .build();
+
+    // Buy Intent
+    let buy_intent_data = def_buy_intent_data(&mut context, &dob_selling_data);
+    let cell_input_buy_intent = {
+        let buy_intent_script = build_buy_intent_cell(
+            &mut context,
+            1000,
+            def_lock_script.clone(),
+            &[
+                account_book_script
+                    .calc_script_hash()
+                    .as_slice()
+                    .try_into()
+                    .unwrap(),
+                ckb_hash(buy_intent_data.as_slice()),
+            ]
+            .concat(),
+        );
+
+        let buy_intent_outpoint = OutPoint::new_builder()
+            .tx_hash(input_buy_intent_tx_hash)
+            .index(1u32.pack())
+            .build();
+        context.create_cell_with_out_point(
+            buy_intent_outpoint.clone(),
+            buy_intent_script.clone(),
+            Default::default(),
+        );
+        CellInput::new_builder()
+            .previous_output(buy_intent_outpoint)
+            .build()
+    };
+
+    let tx = tx
+        .as_advanced_builder()
+        .input(cell_input_buy_intent)
+        .witness(
+            WitnessArgs::new_builder()
+                .input_type(Some(buy_intent_data.as_bytes()).pack())
+                .build()
+                .as_bytes()
+                .pack(),
+        )
+        .build();
+
+    // Spore
+    let spore_lock = def_spore_lock(&mut context);
+    let tx = build_mint_spore(&mut context, tx, cluster_deps, spore_data, spore_lock);
+
+    let tx = update_accountbook(&mut context, tx, DATA_ASSET_AMOUNT);
+    let tx = context.complete_tx(tx);
+    print_tx_info(&context, &tx);
+    verify_and_dump_failed_tx(&context, &tx, MAX_CYCLES).expect("pass");
+}
+
+#[test]
+fn test_floor_offer_selling() {
+    selling(Sale {
+        floor_offer: true,
+        ..Default::default()
+    })
+    .expect("pass");
+}
+
+#[test]
+fn test_max_price_selling() {
+    selling(Sale {
+        max_price: DATA_ASSET_AMOUNT + 50,
+        ..Default::default()
+    })
+    .expect("pass");
+}
+
+#[test]
+fn test_selling_above_max_price() {
+    selling(Sale {
+        max_price: DATA_ASSET_AMOUNT - 50,
+        ..Default::default()
+    })
+    .expect_err("The price of the AccountBook is above max_price");
+}
+
+#[test]
+fn test_amended_selling() {
+    selling(Sale {
+        amended: true,
+        ..Default::default()
+    })
+    .expect("pass");
+}
+
+#[test]
+fn test_partial_selling() {
+    selling(Sale {
+        floor_offer: true,
+        quantity: 3,
+        ..Default::default()
+    })
+    .expect("pass");
+}
+
+#[test]
+fn test_partial_selling_with_tampered_intent() {
+    selling(Sale {
+        floor_offer: true,
+        quantity: 3,
+        tampered_intent: true,
+        ..Default::default()
+    })
+    .expect_err("The rest of the intent must stay with the owner");
+}
+
+#[test]
+fn test_partial_selling_with_tampered_dob_selling() {
+    selling(Sale {
+        floor_offer: true,
+        quantity: 3,
+        tampered_dob_selling: true,
+        ..Default::default()
+    })
+    .expect_err("The rest of the intent must stay with the owner");
+}
+
+#[test]
+fn test_selling_with_fill_fee() {
+    selling(Sale {
+        fill_fee: 100,
+        ..Default::default()
+    })
+    .expect("pass");
+}
+
+#[test]
+fn test_selling_without_returned_capacity() {
+    selling(Sale {
+        fill_fee: 100,
+        capacity_taken: 1,
+        ..Default::default()
+    })
+    .expect_err("The capacity of the intent minus the fill fee goes back to the owner");
+}
+
+#[test]
+fn test_selling_sold_dna() {
+    selling(Sale {
+        dna_sold: true,
+        ..Default::default()
+    })
+    .expect_err("A DNA can only be sold once");
+}
+
+#[test]
+fn test_selling_approved_dna() {
+    selling(Sale {
+        approved_dna: Some(true),
+        ..Default::default()
+    })
+    .expect("pass");
+}
+
+#[test]
+fn test_selling_unapproved_dna() {
+    selling(Sale {
+        approved_dna: Some(false),
+        ..Default::default()
+    })
+    .expect_err("Only approved DNA can be sold");
+}
+
+#[test]
+fn test_selling_by_level_rule() {
+    selling(Sale {
+        level_rule: def_level_rule(2),
+        ..Default::default()
+    })
+    .expect("pass");
+}
+
+#[test]
+fn test_selling_with_mismatched_level_rule() {
+    selling(Sale {
+        level_rule: def_level_rule(1),
+        ..Default::default()
+    })
+    .expect_err("The Spore is of another level by the rule");
+}
+
+#[test]
+fn test_selling_within_buyer_quota() {
+    selling(Sale {
+        buyer_quota: Some((1, 2)),
+        ..Default::default()
+    })
+    .expect("pass");
+}
+
+#[test]
+fn test_selling_above_buyer_quota() {
+    selling(Sale {
+        buyer_quota: Some((2, 2)),
+        ..Default::default()
+    })
+    .expect_err("The buyer lock has bought max_per_buyer Spores");
+}
+
+#[test]
+fn test_selling_two_intents_with_buyer_quota() {
+    selling(Sale {
+        buyer_quota: Some((0, 2)),
+        second_intent: true,
+        ..Default::default()
+    })
+    .expect_err("The buyer lock is only known with one BuyIntent");
+}
+
+// A coupon signed by the platform takes its discount off the price,
+// the intent has to be placed before the coupon expires
+// The coupon expires before the intent does
+#[derive(Default)]
+struct CouponUse {
+    expired: bool,
+}
+
+fn discounted_selling(coupon: CouponUse) -> Result<Cycle, Error> {
+    let CouponUse { expired } = coupon;
+    use ckb_testtool::{
+        ckb_crypto::secp::Generator,
+        ckb_types::{core::HeaderBuilder, H256},
+    };
+    use types::{Byte65, Coupon};
+
+    const DISCOUNT: u128 = 50;
+    const COUPON_EXPIRE: u64 = 2000;
+
+    let mut context = new_context();
+    let def_lock_script: Script = build_always_suc_script(&mut context, &[]);
+    let (privkey, pubkey) = Generator::random_keypair();
+    let account_book_type_id = [17u8; 32];
+
+    let account_book_script =
+        build_account_book_script(&mut context, Some(account_book_type_id.into())).unwrap();
+    let account_book_script_hash: [u8; 32] = account_book_script
+        .calc_script_hash()
+        .as_slice()
+        .try_into()
+        .unwrap();
+    let lock_proxy_script = build_proxy_lock_script(&mut context, account_book_script_hash.into());
+    let (spore_data, cluster_deps) = def_spore(&mut context, lock_proxy_script);
+
+    // Account Book
+    let price = DATA_ASSET_AMOUNT - DISCOUNT;
+    let ab_cell_data = def_account_book_cell_data(&mut context)
+        .as_builder()
+        .level(2.into())
+        .cluster_id(get_cluster_id(&spore_data).pack())
+        .coupon_pubkey_hash(Hash::ckb_hash(&pubkey.serialize()).into())
+        .buyer_count(15u32.pack())
+        .build();
+    let ab_cell_data_new = ab_cell_data
+        .clone()
+        .as_builder()
+        .buyer_count(16u32.pack())
+        .build();
+    let tx = build_account_book(
+        &mut context,
+        TransactionBuilder::default().build(),
+        account_book_type_id.into(),
+        AccountBookData::new_builder().build(),
+        (ab_cell_data, ab_cell_data_new),
+        (10000, 10000 + price),
+    );
+
+    // Coupon
+    let nonce: Hash = [0x32; 32].into();
+    let coupon = Coupon::new_builder()
+        .discount(DISCOUNT.pack())
+        .account_book_script_hash(account_book_script_hash.pack())
+        .expire_since(COUPON_EXPIRE.pack())
+        .nonce(nonce.clone().into())
+        .build();
+    let message: [u8; 32] = utils::get_coupon_message(&coupon).into();
+    let signature = privkey
+        .sign_recoverable(&H256(message))
+        .expect("Sign coupon failed")
+        .serialize();
+    let coupon = coupon
+        .as_builder()
+        .signature(Byte65::new_unchecked(signature.into()))
+        .build();
+
+    // DOB Selling
+    let dob_selling_data = def_dob_selling_data(&mut context, &spore_data)
+        .as_builder()
+        .account_book_script_hash(account_book_script_hash.pack())
+        .build();
+    let buy_intent_data = def_buy_intent_data(&mut context, &dob_selling_data)
+        .as_builder()
+        .owner_script_hash(def_lock_script.calc_script_hash())
+        .coupon(coupon)
+        .build();
+    let input_buy_intent_tx_hash = ckb_testtool::context::random_hash();
+    let dob_selling_outpoint = OutPoint::new_builder()
+        .tx_hash(input_buy_intent_tx_hash.clone())
+        .index(0u32.pack())
+        .build();
+    let dob_selling = build_dob_selling_script(&mut context, &dob_selling_data);
+    let dob_selling_udt = build_xudt_cell(&mut context, dob_selling);
+    context.create_cell_with_out_point(
+        dob_selling_outpoint.clone(),
+        dob_selling_udt,
+        price.to_le_bytes().to_vec().into(),
+    );
+
+    // Buy Intent, placed in a block before or after the coupon expires
+    let buy_intent_outpoint = OutPoint::new_builder()
+        .tx_hash(input_buy_intent_tx_hash)
+        .index(1u32.pack())
+        .build();
+    let buy_intent_cell = build_buy_intent_cell(
+        &mut context,
+        1000,
+        def_lock_script.clone(),
+        &[
+            account_book_script_hash,
+            ckb_hash(buy_intent_data.as_slice()),
+        ]
+        .concat(),
+    );
+    context.create_cell_with_out_point(
+        buy_intent_outpoint.clone(),
+        buy_intent_cell,
+        Default::default(),
+    );
+    let header = HeaderBuilder::default()
+        .number(
+            if expired {
+                COUPON_EXPIRE
+            } else {
+                COUPON_EXPIRE - 500
+            }
+            .pack(),
+        )
+        .build();
+    context.insert_header(header.clone());
+    context.link_cell_with_block(buy_intent_outpoint.clone(), header.hash(), 0);
+
+    let tx = tx
+        .as_advanced_builder()
+        .input(build_input(dob_selling_outpoint))
+        .input(build_input(buy_intent_outpoint))
+        .output(
+            CellOutput::new_builder()
+                .lock(def_lock_script.clone())
+                .capacity(1000.pack())
+                .build(),
+        )
+        .output_data(Default::default())
+        .header_dep(header.hash())
+        .witness(
+            WitnessArgs::new_builder()
+                .lock(Some(dob_selling_data.as_bytes()).pack())
+                .build()
+                .as_bytes()
+                .pack(),
+        )
+        .witness(
+            WitnessArgs::new_builder()
+                .input_type(Some(buy_intent_data.as_bytes()).pack())
+                .build()
+                .as_bytes()
+                .pack(),
+        )
+        .build();
+
+    // Spore
+    let spore_lock = def_spore_lock(&mut context);
+    let tx = build_mint_spore(&mut context, tx, cluster_deps, spore_data, spore_lock);
+
+    // The nonce of the coupon is used up
+    let tx = update_accountbook_with_leaves(&mut context, tx, price, &[SmtKey::Coupon(nonce)]);
+    let tx = context.complete_tx(tx);
+    verify_and_dump_failed_tx(&context, &tx, MAX_CYCLES)
+}
+
+#[test]
+fn test_discounted_selling() {
+    discounted_selling(Default::default()).expect("pass");
+}
+
+#[test]
+fn test_discounted_selling_after_coupon_expiry() {
+    discounted_selling(CouponUse { expired: true })
+        .expect_err("The coupon has expired when the intent is placed");
+}
+
+#[test]
+fn test_reserve_mint() {
+    let mut context = new_context();
+    let auther_lock_script = build_user1_script(&mut context);
+    let account_book_type_id = [16u8; 32];
+
+    let account_book_script =
+        build_account_book_script(&mut context, Some(account_book_type_id.into())).unwrap();
+    let lock_proxy_script =
+        build_proxy_lock_script(&mut context, account_book_script.calc_script_hash().into());
+    let (spore_data, cluster_deps) = def_spore(&mut context, lock_proxy_script);
+
+    let tx = TransactionBuilder::default().build();
+
+    // Account Book
+    let ab_cell_data = def_account_book_cell_data(&mut context)
+        .as_builder()
+        .level(2.into())
+        .cluster_id(get_cluster_id(&spore_data).pack())
+        .auther_id(auther_lock_script.calc_script_hash())
+        .buyer_count(15u32.pack())
+        .reserved_count(3u32.pack())
+        .build();
+    let ab_cell_data_new = ab_cell_data
+        .clone()
+        .as_builder()
+        .buyer_count(16u32.pack())
+        .reserved_count(2u32.pack())
+        .build();
+
+    let tx = build_account_book(
+        &mut context,
+        tx,
+        account_book_type_id.into(),
+        AccountBookData::new_builder().build(),
+        (ab_cell_data, ab_cell_data_new),
+        (10000, 10000),
+    );
+
+    // Auther
+    let tx = tx
+        .as_advanced_builder()
+        .input(build_input(build_out_point1(
+            &mut context,
+            auther_lock_script.clone(),
+        )))
+        .witness(Default::default())
+        .build();
+
+    // Spore
+    let tx = build_mint_spore(
+        &mut context,
+        tx,
+        cluster_deps,
+        spore_data.clone(),
+        auther_lock_script,
+    );
+    let tx = update_accountbook(&mut context, tx, 0);
+
+    let reserved_spore = WithdrawalSporeInfo::new_builder()
+        .spore_code_hash((*SporeCodeHash).pack())
+        .spore_level(2.into())
+        .spore_id(get_spore_id(&tx).pack())
+        .cluster_id(get_cluster_id(&spore_data).pack())
+        .build();
+    let mut witnesses: Vec<_> = tx.witnesses().into_iter().collect();
+    let witness = WitnessArgs::new_unchecked(witnesses[1].raw_data());
+    let account_book_data =
+        AccountBookData::new_unchecked(witness.output_type().to_opt().unwrap().raw_data())
+            .as_builder()
+            .reserved_spore(
+                WithdrawalSporeInfoOpt::new_builder()
+                    .set(Some(reserved_spore))
+                    .build(),
+            )
+            .build();
+    witnesses[1] = witness
+        .as_builder()
+        .output_type(Some(account_book_data.as_bytes()).pack())
+        .build()
+        .as_bytes()
+        .pack();
+    let tx = tx.as_advanced_builder().set_witnesses(witnesses).build();
+
+    let tx = context.complete_tx(tx);
+    // print_tx_info(&context, &tx);
+    verify_and_dump_failed_tx(&context, &tx, MAX_CYCLES).expect("pass");
+}
+
+// The AccountBook in CellDeps that the holder withdraws from
+#[derive(Default)]
+struct WithdrawalSource {
+    // The AccountBook is left out of CellDeps
+    without_account_book: bool,
+    // The level rule of the AccountBook
+    level_rule: LevelRuleOpt,
+}
+
+fn withdrawal_intent(source: WithdrawalSource) -> Result<Cycle, Error> {
+    let WithdrawalSource {
+        without_account_book,
+        level_rule,
+    } = source;
+    // Add Spore
+    let mut context = new_context();
+    let tx = TransactionBuilder::default().build();
+    let def_lock_script = build_always_suc_script(&mut context, &[]);
+
+    let def_cluster_lock = build_always_suc_script(&mut context, &[2u8; 32]);
+    let (spore_data, _cluster_dep) = def_spore(&mut context, def_cluster_lock);
+    let tx = build_transfer_spore(&mut context, tx, &spore_data);
+    let tx = context.complete_tx(tx);
+
+    let withdrawal_spore_info = WithdrawalSporeInfo::new_builder()
+        .spore_code_hash((*SporeCodeHash).pack())
+        .spore_level(2.into())
+        .spore_id(get_spore_id(&tx).pack())
+        .cluster_id(get_cluster_id(&spore_data).pack())
+        .build();
+
+    let withdrawal_intent_data = def_withdrawal_intent_data(&mut context)
+        .as_builder()
+        .buyer(
+            WithdrawalBuyer::new_builder()
+                .set(withdrawal_spore_info)
+                .build(),
+        )
+        .build();
+    // The Spore level is checked by the level rule of the AccountBook
+    let ab_cell_data = def_account_book_cell_data(&mut context)
+        .as_builder()
+        .level(3.into())
+        .cluster_id(get_cluster_id(&spore_data).pack())
+        .level_rule(level_rule)
+        .build();
+    let (account_book_script, account_book_dep) =
+        build_account_book_dep(&mut context, [17u8; 32].into(), &ab_cell_data);
+    let withdrawal_intent_script = build_withdrawal_intent_script(
+        &mut context,
+        &withdrawal_intent_data,
+        account_book_script.calc_script_hash().into(),
+    );
+    let tx = if without_account_book {
+        tx
+    } else {
+        tx.as_advanced_builder().cell_dep(account_book_dep).build()
+    };
+    // Inputs: CKB + Spore
+    // Output: Withdrawal intent + Spore
+    let tx = tx
+        .as_advanced_builder()
+        .input(build_input(build_out_point1(
+            &mut context,
+            def_lock_script.clone(),
+        )))
+        .output(
+            CellOutput::new_builder()
+                .lock(def_lock_script)
+                .type_(withdrawal_intent_script.pack())
+                .capacity(1000.pack())
+                .build(),
+        )
+        .output_data(Default::default())
+        .witness(
+            WitnessArgs::new_builder()
+                .output_type(Some(withdrawal_intent_data.as_bytes()).pack())
+                .build()
+                .as_slice()
+                .pack(),
+        )
+        .build();
+
+    let tx = context.complete_tx(tx);
+    // print_tx_info(&context, &tx);
+    verify_and_dump_failed_tx(&context, &tx, MAX_CYCLES)
+}
+
+#[test]
+fn test_simple_withdrawal_intent() {
+    // Add Spore
+    let mut context = new_context();
+    let tx = TransactionBuilder::default().build();
+    let def_lock_script = build_always_suc_script(&mut context, &[]);
+
+    let def_cluster_lock = build_always_suc_script(&mut context, &[2u8; 32]);
+    let (spore_data, _cluster_dep) = def_spore(&mut context, def_cluster_lock);
+    let tx = build_transfer_spore(&mut context, tx, &spore_data);
+    let tx = context.complete_tx(tx);
+
+    let withdrawal_spore_info = WithdrawalSporeInfo::new_builder()
+        .spore_code_hash((*SporeCodeHash).pack())
+        .spore_level(2.into())
+        .spore_id(get_spore_id(&tx).pack())
+        .cluster_id(get_cluster_id(&spore_data).pack())
+        .build();
+
+    let withdrawal_intent_data = def_withdrawal_intent_data(&mut context)
+        .as_builder()
+        .buyer(
+            WithdrawalBuyer::new_builder()
+                .set(withdrawal_spore_info)
+                .build(),
+        )
+        .build();
+    // The level rule is loaded from the AccountBook in CellDeps
+    let ab_cell_data = def_account_book_cell_data(&mut context)
+        .as_builder()
+        .level(3.into())
+        .cluster_id(get_cluster_id(&spore_data).pack())
+        .build();
+    let (account_book_script, account_book_dep) =
+        build_account_book_dep(&mut context, [17u8; 32].into(), &ab_cell_data);
+    let withdrawal_intent_script = build_withdrawal_intent_script(
+        &mut context,
+        &withdrawal_intent_data,
+        account_book_script.calc_script_hash().into(),
+    );
+    // Inputs: CKB + Spore
+    // Output: Withdrawal intent + Spore
+    let tx = tx
+        .as_advanced_builder()
+        .cell_dep(account_book_dep)
+        .input(build_input(build_out_point1(
+            &mut context,
+            def_lock_script.clone(),
+        )))
+        .output(
+            CellOutput::new_builder()
+                .lock(def_lock_script)
+                .type_(withdrawal_intent_script.pack())
+                .capacity(1000.pack())
+                .build(),
+        )
+        .output_data(Default::default())
+        .witness(
+            WitnessArgs::new_builder()
+                .output_type(Some(withdrawal_intent_data.as_bytes()).pack())
+                .build()
+                .as_slice()
+                .pack(),
+        )
+        .build();
+
+    let tx = context.complete_tx(tx);
+    // print_tx_info(&context, &tx);
+    verify_and_dump_failed_tx(&context, &tx, MAX_CYCLES).expect("pass");
+}
+
+#[test]
+fn test_withdrawal_intent_without_account_book() {
+    withdrawal_intent(WithdrawalSource {
+        without_account_book: true,
+        ..Default::default()
+    })
+    .expect_err("The AccountBook must be in CellDeps");
+}
+
+#[test]
+fn test_withdrawal_intent_by_level_rule() {
+    withdrawal_intent(WithdrawalSource {
+        level_rule: def_level_rule(2),
+        ..Default::default()
+    })
+    .expect("pass");
+}
+
+#[test]
+fn test_withdrawal_intent_with_mismatched_level_rule() {
+    withdrawal_intent(WithdrawalSource {
+        level_rule: def_level_rule(1),
+        ..Default::default()
+    })
+    .expect_err("The Spore is of another level by the rule");
+}
+
+// How a Spore of level 1 withdraws, the defaults withdraw all it is entitled to
+#[derive(Default)]
+struct SporeWithdrawal {
+    // Withdraw less than the entitlement
+    partial_udt: Option<u128>,
+    // (withdrawal_fee_udt, withdrawal_fee_ratio) of the AccountBook
+    fee: (u128, u8),
+    // The Spore has no leaf yet, the AccountBook of level 1 proves it sold the Spore
+    first_withdrawal: bool,
+    // The AccountBook of level 1 has not sold the Spore
+    unsold: bool,
+}
+
+fn withdrawal_spore(withdrawal: SporeWithdrawal) -> Result<Cycle, Error> {
+    let SporeWithdrawal {
+        partial_udt,
+        fee,
+        first_withdrawal,
+        unsold,
+    } = withdrawal;
+    let mut context = new_context();
+    let tx = TransactionBuilder::default().build();
+    let def_lock_script = build_always_suc_script(&mut context, &[0x11; 32]);
+    let out_xudt_lock_script: Script = build_always_suc_script(&mut context, &[1, 2, 3, 4]);
+    let xudt_script = build_xudt_script(&mut context);
+
+    let spore_id: Hash = [0xBB; 32].into();
+    // let spore_level: u8 = 1;
+    let cluster_id: Hash = [0x1A; 32].into();
+
+    // Cal Withdrawal
+    let ratios = [20, 30, 30, 20];
+    let buyers = [7, 15];
+    let spore_level = 1;
+    let total_income = 300000u128;
+    let old_total_udt = 10000u128;
+    let old_total_withdrawal = if first_withdrawal { None } else { Some(10u128) };
+
+    let max_total_withdrawal: u128 =
+        total_income * ratios[spore_level + 2] as u128 / 100 / buyers[spore_level] as u128;
+    let old_withdrawn = old_total_withdrawal.unwrap_or(0);
+    let withdrawal_udt = partial_udt.unwrap_or(max_total_withdrawal - old_withdrawn);
+    let fee_udt = if fee == (0, 0) {
+        0
+    } else {
+        fee.0 + withdrawal_udt * fee.1 as u128 / 100
+    };
+    let new_total_withdrawal = old_withdrawn + withdrawal_udt + fee_udt;
+    let new_total_udt = old_total_udt - withdrawal_udt;
+
+    let mut smt = AccountBook::new_test();
+    if let Some(withdrawn) = old_total_withdrawal {
+        smt.update(SmtKey::Buyer(spore_id.clone()), withdrawn);
+    }
+    smt.update(SmtKey::TotalIncome, total_income);
+    smt.update(SmtKey::AccountBalance, old_total_udt);
+    smt.update(SmtKey::TotalWithdrawn, total_income - old_total_udt);
+    let old_hash = smt.root_hash();
+    let proof = if fee_udt == 0 {
+        smt.proof(SmtKey::Buyer(spore_id.clone()))
+    } else {
+        smt.proof_keys(&[SmtKey::Buyer(spore_id.clone()), SmtKey::PlatformFee])
+    };
+
+    smt.update(SmtKey::AccountBalance, new_total_udt);
+    smt.update(SmtKey::TotalWithdrawn, total_income - new_total_udt);
+    smt.update(SmtKey::Buyer(spore_id.clone()), new_total_withdrawal);
+    if fee_udt != 0 {
+        smt.update(SmtKey::PlatformFee, fee_udt);
+    }
+    let new_hash = smt.root_hash();
+
+    // Account Book
+    let account_book_cell_data = def_account_book_cell_data(&mut context)
+        .as_builder()
+        .level(2.into())
+        .cluster_id(cluster_id.clone().into())
+        .profit_distribution_ratio(ratios.pack())
+        .profit_distribution_number(buyers.pack())
+        .smt_root_hash(old_hash.into())
+        .withdrawal_fee_udt(fee.0.pack())
+        .withdrawal_fee_ratio(fee.1.into())
+        .build();
+    let account_book_data = AccountBookData::new_builder()
+        .total_income_udt(total_income.pack())
+        .total_withdrawn_udt((total_income - old_total_udt).pack())
+        .proof(proof.pack())
+        .withdrawn_udt({
+            Uint128Opt::new_builder()
+                .set(old_total_withdrawal.map(|v| v.pack()))
+                .build()
+        })
+        .build();
+
+    // The AccountBook of level 1 sold the Spore
+    let (account_book_data, sibling_dep) = if first_withdrawal {
+        let sibling_cell_data = def_account_book_cell_data(&mut context)
+            .as_builder()
+            .level((spore_level as u8).into())
+            .cluster_id(cluster_id.clone().into())
+            .build();
+        let (sibling_proof, sibling_dep) = build_sibling_account_book(
+            &mut context,
+            [0x1C; 32],
+            sibling_cell_data,
+            &[(
+                SmtKey::Buyer(spore_id.clone()),
+                if unsold { None } else { Some(0) },
+            )],
+        );
+        (
+            account_book_data
+                .as_builder()
+                .sibling_proofs(SiblingProofVec::new_builder().push(sibling_proof).build())
+                .build(),
+            Some(sibling_dep),
+        )
+    } else {
+        (account_book_data, None)
+    };
+
+    let account_book_script = build_account_book_script(&mut context, None);
+    let input_account_book_tx_hash = ckb_testtool::context::random_hash();
+
+    let tx = {
+        let proxy_lock_script = build_proxy_lock_script(
+            &mut context,
+            account_book_script
+                .as_ref()
+                .unwrap()
+                .calc_script_hash()
+                .into(),
+        );
+
+        let input_cell = {
+            let cell_input_outpoint1 = OutPoint::new_builder()
+                .tx_hash(input_account_book_tx_hash.clone())
+                .index(1u32.pack())
+                .build();
+            let cell = CellOutput::new_builder()
+                .capacity(16.pack())
+                .lock(proxy_lock_script.clone())
+                .type_(xudt_script.clone().pack())
+                .build();
+            context.create_cell_with_out_point(
+                cell_input_outpoint1.clone(),
+                cell,
+                old_total_udt.to_le_bytes().to_vec().into(),
+            );
+            cell_input_outpoint1
+        };
+        let output_cell = {
+            CellOutput::new_builder()
+                .capacity(16.pack())
+                .lock(proxy_lock_script.clone())
+                .type_(xudt_script.pack())
+                .build()
+        };
+        tx.as_advanced_builder()
+            .input(build_input(input_cell))
+            .output(output_cell)
+            .output_data(new_total_udt.to_le_bytes().pack())
+            .witness(Default::default())
+            .build()
+    };
+    let tx = {
+        let input_cell = {
+            let cell_input_outpoint2 = OutPoint::new_builder()
+                .tx_hash(input_account_book_tx_hash)
+                .index(2u32.pack())
+                .build();
+
+            let cell = CellOutput::new_builder()
+                .capacity(1000.pack())
+                .lock(def_lock_script.clone())
+                .type_(account_book_script.clone().pack())
+                .build();
+            context.create_cell_with_out_point(
+                cell_input_outpoint2.clone(),
+                cell,
+                account_book_cell_data.as_bytes(),
+            );
+            cell_input_outpoint2
+        };
+        let output_cell = {
+            CellOutput::new_builder()
+                .capacity(1000.pack())
+                .lock(def_lock_script.clone())
+                .type_(account_book_script.pack())
+                .build()
+        };
+
+        // Update Cell Data
+        let account_book_cell_data = account_book_cell_data
+            .as_builder()
+            .smt_root_hash(new_hash.into())
+            .build();
+
+        tx.as_advanced_builder()
+            .input(build_input(input_cell))
+            .output(output_cell)
+            .output_data(account_book_cell_data.as_slice().pack())
+            .witness(
+                WitnessArgs::new_builder()
+                    .output_type(Some(account_book_data.as_bytes()).pack())
+                    .build()
+                    .as_bytes()
+                    .pack(),
+            )
+            .build()
+    };
+
+    // Withdrawal Intent
+    let tx = {
+        let withdrawal_spore_info = WithdrawalSporeInfo::new_builder()
+            .spore_code_hash((*SporeCodeHash).pack())
+            .spore_id(spore_id.into())
+            .spore_level((spore_level as u8).into())
+            .cluster_id(cluster_id.into())
+            .build();
+
+        let withdrawal_intent_data = def_withdrawal_intent_data(&mut context)
+            .as_builder()
+            .owner_script_hash(out_xudt_lock_script.calc_script_hash())
+            .xudt_lock_script_hash(out_xudt_lock_script.calc_script_hash())
+            .buyer(
+                WithdrawalBuyer::new_builder()
+                    .set(withdrawal_spore_info)
+                    .build(),
+            )
+            .build();
+
+        let withdrawal_intent_script = build_withdrawal_intent_script(
+            &mut context,
+            &withdrawal_intent_data,
+            account_book_script
+                .as_ref()
+                .unwrap()
+                .calc_script_hash()
+                .into(),
+        );
+
+        let input_cell = {
+            context.create_cell(
+                CellOutput::new_builder()
+                    .capacity(16.pack())
+                    .lock(def_lock_script.clone())
+                    .type_(withdrawal_intent_script.pack())
+                    .build(),
+                Default::default(),
+            )
+        };
+        let output_cell = {
+            CellOutput::new_builder()
+                .capacity(16.pack())
+                .lock(out_xudt_lock_script.clone())
+                .type_(xudt_script.pack())
+                .build()
+        };
+
+        tx.as_advanced_builder()
+            .input(build_input(input_cell))
+            .output(output_cell)
+            .output_data(withdrawal_udt.to_le_bytes().pack())
+            .witness(
+                WitnessArgs::new_builder()
+                    .input_type(Some(withdrawal_intent_data.as_bytes()).pack())
+                    .build()
+                    .as_bytes()
+                    .pack(),
+            )
+            .build()
+    };
+
+    let tx = match sibling_dep {
+        Some(cell_dep) => tx.as_advanced_builder().cell_dep(cell_dep).build(),
+        None => tx,
+    };
+
+    let tx = context.complete_tx(tx);
+    // print_tx_info(&context, &tx);
+    verify_and_dump_failed_tx(&context, &tx, MAX_CYCLES)
+}
+
+#[test]
+fn test_simple_withdrawal_suc() {
+    let mut context = new_context();
+    let tx = TransactionBuilder::default().build();
+    let def_lock_script = build_always_suc_script(&mut context, &[0x11; 32]);
+    let out_xudt_lock_script: Script = build_always_suc_script(&mut context, &[1, 2, 3, 4]);
+    let xudt_script = build_xudt_script(&mut context);
+
+    let spore_id: Hash = [0x1B; 32].into();
+    // let spore_level: u8 = 1;
+    let cluster_id: Hash = [0x1A; 32].into();
+
+    // Cal Withdrawal
+    let ratios = [20, 30, 30, 20];
+    let buyers = [7, 15];
+    let spore_level = 1;
+    let total_income = 300000u128;
+    let old_total_udt = 10000u128;
+    let old_total_withdrawal = Some(10u128);
+
+    let new_total_withdrawal: u128 =
+        total_income * ratios[spore_level + 2] as u128 / 100 / buyers[spore_level] as u128;
+    let withdrawal_udt = new_total_withdrawal - old_total_withdrawal.unwrap_or(0);
+    let new_total_udt = old_total_udt - withdrawal_udt;
+
+    let mut smt = AccountBook::new_test();
+    if old_total_withdrawal.is_some() {
+        smt.update(
+            SmtKey::Buyer(spore_id.clone()),
+            *(old_total_withdrawal.as_ref().unwrap()),
+        );
+    }
+    smt.update(SmtKey::TotalIncome, total_income);
+    smt.update(SmtKey::AccountBalance, old_total_udt);
+    smt.update(SmtKey::TotalWithdrawn, total_income - old_total_udt);
+    let old_hash = smt.root_hash();
+    let proof = smt.proof(SmtKey::Buyer(spore_id.clone()));
+
+    smt.update(SmtKey::AccountBalance, new_total_udt);
+    smt.update(SmtKey::TotalWithdrawn, total_income - new_total_udt);
+    smt.update(SmtKey::Buyer(spore_id.clone()), new_total_withdrawal);
+    let new_hash = smt.root_hash();
+
+    // Account Book
+    let account_book_cell_data = def_account_book_cell_data(&mut context)
+        .as_builder()
+        .level(2.into())
+        .cluster_id(cluster_id.clone().into())
+        .profit_distribution_ratio(ratios.pack())
+        .profit_distribution_number(buyers.pack())
+        .smt_root_hash(old_hash.into())
+        .build();
+    let account_book_data = AccountBookData::new_builder()
+        .total_income_udt(total_income.pack())
+        .total_withdrawn_udt((total_income - old_total_udt).pack())
+        .proof(proof.pack())
+        .withdrawn_udt({
+            Uint128Opt::new_builder()
+                .set(old_total_withdrawal.map(|v| v.pack()))
+                .build()
+        })
+        .build();
+
+    let account_book_script = build_account_book_script(&mut context, None);
+    let input_account_book_tx_hash = ckb_testtool::context::random_hash();
+
+    let tx = {
+        let proxy_lock_script = build_proxy_lock_script(
+            &mut context,
+            account_book_script
+                .as_ref()
+                .unwrap()
+                .calc_script_hash()
+                .into(),
+        );
+
+        let input_cell = {
+            let cell_input_outpoint1 = OutPoint::new_builder()
+                .tx_hash(input_account_book_tx_hash.clone())
+                .index(1u32.pack())
+                .build();
+            let cell = CellOutput::new_builder()
+                .capacity(16.pack())
+                .lock(proxy_lock_script.clone())
+                .type_(xudt_script.clone().pack())
+                .build();
+            context.create_cell_with_out_point(
+                cell_input_outpoint1.clone(),
+                cell,
+                old_total_udt.to_le_bytes().to_vec().into(),
+            );
+            cell_input_outpoint1
+        };
+        let output_cell = {
+            CellOutput::new_builder()
+                .capacity(16.pack())
+                .lock(proxy_lock_script.clone())
+                .type_(xudt_script.pack())
+                .build()
+        };
+        tx.as_advanced_builder()
+            .input(build_input(input_cell))
+            .output(output_cell)
+            .output_data(new_total_udt.to_le_bytes().pack())
+            .witness(Default::default())
+            .build()
+    };
+    let tx = {
+        let input_cell = {
+            let cell_input_outpoint2 = OutPoint::new_builder()
+                .tx_hash(input_account_book_tx_hash)
+                .index(2u32.pack())
+                .build();
+
+            let cell = CellOutput::new_builder()
+                .capacity(1000.pack())
+                .lock(def_lock_script.clone())
+                .type_(account_book_script.clone().pack())
+                .build();
+            context.create_cell_with_out_point(
+                cell_input_outpoint2.clone(),
+                cell,
+                account_book_cell_data.as_bytes().into(),
+            );
+            cell_input_outpoint2
+        };
+        let output_cell = {
+            CellOutput::new_builder()
+                .capacity(1000.pack())
+                .lock(def_lock_script.clone())
+                .type_(account_book_script.pack())
+                .build()
+        };
+
+        // Update Cell Data
+        let account_book_cell_data = account_book_cell_data
+            .as_builder()
+            .smt_root_hash(new_hash.into())
+            .build();
+
+        tx.as_advanced_builder()
+            .input(build_input(input_cell))
+            .output(output_cell)
+            .output_data(account_book_cell_data.as_slice().pack())
+            .witness(
+                WitnessArgs::new_builder()
+                    .output_type(Some(account_book_data.as_bytes()).pack())
+                    .build()
+                    .as_bytes()
+                    .pack(),
+            )
+            .build()
+    };
+
+    // Withdrawal Intent
+    let tx = {
+        let withdrawal_spore_info = WithdrawalSporeInfo::new_builder()
+            .spore_code_hash((*SporeCodeHash).pack())
+            .spore_id(spore_id.into())
+            .spore_level((spore_level as u8).into())
+            .cluster_id(cluster_id.into())
+            .build();
+
+        let withdrawal_intent_data = def_withdrawal_intent_data(&mut context)
+            .as_builder()
+            .owner_script_hash(out_xudt_lock_script.calc_script_hash())
+            .xudt_lock_script_hash(out_xudt_lock_script.calc_script_hash())
+            .buyer(
+                WithdrawalBuyer::new_builder()
+                    .set(withdrawal_spore_info)
+                    .build(),
+            )
+            .build();
+
+        let withdrawal_intent_script = build_withdrawal_intent_script(
+            &mut context,
+            &withdrawal_intent_data,
+            account_book_script
+                .as_ref()
+                .unwrap()
+                .calc_script_hash()
+                .into(),
+        );
+
+        let input_cell = {
+            context.create_cell(
+                CellOutput::new_builder()
+                    .capacity(16.pack())
+                    .lock(def_lock_script.clone())
+                    .type_(withdrawal_intent_script.pack())
+                    .build(),
+                Default::default(),
+            )
+        };
+        let output_cell = {
+            CellOutput::new_builder()
+                .capacity(16.pack())
+                .lock(out_xudt_lock_script.clone())
+                .type_(xudt_script.pack())
+                .build()
+        };
+
+        tx.as_advanced_builder()
+            .input(build_input(input_cell))
+            .output(output_cell)
+            .output_data(withdrawal_udt.to_le_bytes().pack())
+            .witness(
+                WitnessArgs::new_builder()
+                    .input_type(Some(withdrawal_intent_data.as_bytes()).pack())
+                    .build()
+                    .as_bytes()
+                    .pack(),
+            )
+            .build()
+    };
+
+    let tx = context.complete_tx(tx);
+    // print_tx_info(&context, &tx);
+    verify_and_dump_failed_tx(&context, &tx, MAX_CYCLES).expect("pass");
+}
+
+#[test]
+fn test_partial_withdrawal() {
+    withdrawal_spore(SporeWithdrawal {
+        partial_udt: Some(100),
+        ..Default::default()
+    })
+    .expect("pass");
+}
+
+#[test]
+fn test_withdrawal_fee() {
+    withdrawal_spore(SporeWithdrawal {
+        partial_udt: Some(100),
+        fee: (5, 10),
+        ..Default::default()
+    })
+    .expect("pass");
+}
+
+#[test]
+fn test_first_withdrawal() {
+    withdrawal_spore(SporeWithdrawal {
+        first_withdrawal: true,
+        ..Default::default()
+    })
+    .expect("pass");
+}
+
+#[test]
+fn test_withdrawal_of_unsold_spore() {
+    withdrawal_spore(SporeWithdrawal {
+        first_withdrawal: true,
+        unsold: true,
+        ..Default::default()
+    })
+    .expect_err("A Spore without a leaf must have been sold");
+}
+
+#[test]
+fn test_deposit() {
+    let mut context = new_context();
+    let tx = TransactionBuilder::default().build();
+    let account_book_type_id = [15u8; 32];
+    let deposited_udt = 500u128;
+
+    let mut smt = AccountBook::new_test();
+    let total_income = smt.get_total_income();
+    let account_balance = smt.get_account_balance();
+    let old_hash = smt.root_hash();
+    smt.update(SmtKey::TotalIncome, total_income + deposited_udt);
+    smt.update(SmtKey::AccountBalance, account_balance + deposited_udt);
+    let new_hash = smt.root_hash();
+    let proof = smt.proof_keys(&[]);
+
+    let account_book_data = AccountBookData::new_builder()
+        .total_income_udt(total_income.pack())
+        .total_withdrawn_udt(smt.get_total_withdrawn().pack())
+        .proof(proof.pack())
+        .deposited_udt(
+            Uint128Opt::new_builder()
+                .set(Some(deposited_udt.pack()))
+                .build(),
+        )
+        .build();
+    let ab_cell_data = def_account_book_cell_data(&mut context)
+        .as_builder()
+        .level(2.into())
+        .smt_root_hash(old_hash.into())
+        .build();
+    let ab_cell_data_new = ab_cell_data
+        .clone()
+        .as_builder()
+        .smt_root_hash(new_hash.into())
+        .build();
+
+    let tx = build_account_book(
+        &mut context,
+        tx,
+        account_book_type_id.into(),
+        account_book_data,
+        (ab_cell_data, ab_cell_data_new),
+        (account_balance, account_balance + deposited_udt),
+    );
+
+    // Depositor
+    let def_lock_script = build_user1_script(&mut context);
+    let udt_cell = build_xudt_cell(&mut context, def_lock_script);
+    let tx = tx
+        .as_advanced_builder()
+        .input(build_input(context.create_cell(
+            udt_cell,
+            deposited_udt.to_le_bytes().to_vec().into(),
+        )))
+        .witness(Default::default())
+        .build();
+
+    let tx = context.complete_tx(tx);
+    // print_tx_info(&context, &tx);
+    verify_and_dump_failed_tx(&context, &tx, MAX_CYCLES).expect("pass");
+}
+
+// The auther share of 300000 * 20% = 60000 vests over 1000 seconds, half of it has vested
+// Withdrawal Intent of a share holder that is not a Spore (auther, co-author or platform)
+fn build_share_withdrawal(
+    context: &mut Context,
+    tx: TransactionView,
+    account_book_script: &Script,
+    holder: Byte32,
+    out_xudt_lock_script: Script,
+    withdrawal_udt: u128,
+) -> TransactionView {
+    let withdrawal_intent_data = def_withdrawal_intent_data(context)
+        .as_builder()
+        .owner_script_hash(out_xudt_lock_script.calc_script_hash())
+        .xudt_lock_script_hash(out_xudt_lock_script.calc_script_hash())
+        .buyer(WithdrawalBuyer::new_builder().set(holder).build())
+        .build();
+    let withdrawal_intent_script = build_withdrawal_intent_script(
+        context,
+        &withdrawal_intent_data,
+        account_book_script.calc_script_hash().into(),
+    );
+    let def_lock_script = build_always_suc_script(context, &[0x11; 32]);
+    let input_cell = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(16.pack())
+            .lock(def_lock_script)
+            .type_(withdrawal_intent_script.pack())
+            .build(),
+        Default::default(),
+    );
+    tx.as_advanced_builder()
+        .input(build_input(input_cell))
+        .output(build_xudt_cell(context, out_xudt_lock_script))
+        .output_data(withdrawal_udt.to_le_bytes().pack())
+        .witness(
+            WitnessArgs::new_builder()
+                .input_type(Some(withdrawal_intent_data.as_bytes()).pack())
+                .build()
+                .as_bytes()
+                .pack(),
+        )
+        .build()
+}
+
+fn auther_withdrawal(withdrawal_udt: u128) -> Result<Cycle, Error> {
+    let mut context = new_context();
+    let tx = TransactionBuilder::default().build();
+    let account_book_type_id = [21u8; 32];
+    let out_xudt_lock_script = build_user1_script(&mut context);
+
+    let total_income = 300000u128;
+    let old_total_udt = 100000u128;
+    let new_total_udt = old_total_udt - withdrawal_udt;
+    let total_withdrawn = total_income - old_total_udt;
+    let vesting_start = 1_000_000u64;
+
+    let ab_cell_data = def_account_book_cell_data(&mut context)
+        .as_builder()
+        .level(2.into())
+        .auther_vesting_start(vesting_start.pack())
+        .auther_vesting_duration(1000u64.pack())
+        .build();
+    let tx = build_account_book(
+        &mut context,
+        tx,
+        account_book_type_id.into(),
+        AccountBookData::new_builder().build(),
+        (ab_cell_data.clone(), ab_cell_data.clone()),
+        (old_total_udt, new_total_udt),
+    );
+    let tx = set_account_book_since(tx, timestamp_since(vesting_start + 500));
+    let account_book_script =
+        build_account_book_script(&mut context, Some(account_book_type_id.into())).unwrap();
+
+    // Withdrawal Intent of the auther
+    let tx = build_share_withdrawal(
+        &mut context,
+        tx,
+        &account_book_script,
+        ab_cell_data.auther_id(),
+        out_xudt_lock_script,
+        withdrawal_udt,
+    );
+
+    // SMT
+    let mut smt = AccountBook::new_empty();
+    smt.update(SmtKey::TotalIncome, total_income);
+    smt.update(SmtKey::AccountBalance, old_total_udt);
+    smt.update(SmtKey::TotalWithdrawn, total_withdrawn);
+    let old_hash = smt.root_hash();
+    let proof = smt.proof(SmtKey::Auther);
+    smt.update(SmtKey::Auther, withdrawal_udt);
+    smt.update(SmtKey::AccountBalance, new_total_udt);
+    smt.update(SmtKey::TotalWithdrawn, total_withdrawn + withdrawal_udt);
+    let new_hash = smt.root_hash();
+
+    let account_book_data = AccountBookData::new_builder()
+        .proof(proof.pack())
+        .total_income_udt(total_income.pack())
+        .total_withdrawn_udt(total_withdrawn.pack())
+        .build();
+    let tx = update_accountbook_smt(&mut context, tx, account_book_data, (old_hash, new_hash));
+
+    let tx = context.complete_tx(tx);
+    // print_tx_info(&context, &tx);
+    verify_and_dump_failed_tx(&context, &tx, MAX_CYCLES)
+}
+
+#[test]
+fn test_auther_vested_withdrawal() {
+    auther_withdrawal(30000).expect("pass");
+}
+
+#[test]
+fn test_auther_unvested_withdrawal() {
+    auther_withdrawal(30001).expect_err("The auther cannot withdraw more than vested");
+}
+
+// Co-author user1 holds 3 of the 4 weights of the auther share
+fn co_author_withdrawal(withdrawal_udt: u128) -> Result<Cycle, Error> {
+    let mut context = new_context();
+    let tx = TransactionBuilder::default().build();
+    let account_book_type_id = [26u8; 32];
+    let out_xudt_lock_script = build_user1_script(&mut context);
+    let co_author_id = out_xudt_lock_script.calc_script_hash();
+
+    // 300000 * 20% * 3 / 4 = 45000
+    let total_income = 300000u128;
+    let old_total_udt = 100000u128;
+    let new_total_udt = old_total_udt - withdrawal_udt;
+    let total_withdrawn = total_income - old_total_udt;
+
+    let ab_cell_data = def_account_book_cell_data(&mut context)
+        .as_builder()
+        .level(2.into())
+        .co_authors(
+            CoAuthorVec::new_builder()
+                .push(
+                    CoAuthor::new_builder()
+                        .lock_script_hash(co_author_id.clone())
+                        .weight(3.into())
+                        .build(),
+                )
+                .push(
+                    CoAuthor::new_builder()
+                        .lock_script_hash([0x33u8; 32].pack())
+                        .weight(1.into())
+                        .build(),
+                )
+                .build(),
+        )
+        .build();
+    let tx = build_account_book(
+        &mut context,
+        tx,
+        account_book_type_id.into(),
+        AccountBookData::new_builder().build(),
+        (ab_cell_data.clone(), ab_cell_data.clone()),
+        (old_total_udt, new_total_udt),
+    );
+    let account_book_script =
+        build_account_book_script(&mut context, Some(account_book_type_id.into())).unwrap();
+    let tx = build_share_withdrawal(
+        &mut context,
+        tx,
+        &account_book_script,
+        co_author_id.clone(),
+        out_xudt_lock_script,
+        withdrawal_udt,
+    );
+
+    // SMT
+    let co_author_key = SmtKey::CoAuthor(co_author_id.into());
+    let mut smt = AccountBook::new_empty();
+    smt.update(SmtKey::TotalIncome, total_income);
+    smt.update(SmtKey::AccountBalance, old_total_udt);
+    smt.update(SmtKey::TotalWithdrawn, total_withdrawn);
+    let old_hash = smt.root_hash();
+    let proof = smt.proof(co_author_key.clone());
+    smt.update(co_author_key, withdrawal_udt);
+    smt.update(SmtKey::AccountBalance, new_total_udt);
+    smt.update(SmtKey::TotalWithdrawn, total_withdrawn + withdrawal_udt);
+    let new_hash = smt.root_hash();
+
+    let account_book_data = AccountBookData::new_builder()
+        .proof(proof.pack())
+        .total_income_udt(total_income.pack())
+        .total_withdrawn_udt(total_withdrawn.pack())
+        .build();
+    let tx = update_accountbook_smt(&mut context, tx, account_book_data, (old_hash, new_hash));
+
+    let tx = context.complete_tx(tx);
+    // print_tx_info(&context, &tx);
+    verify_and_dump_failed_tx(&context, &tx, MAX_CYCLES)
+}
+
+#[test]
+fn test_co_author_withdrawal() {
+    co_author_withdrawal(45000).expect("pass");
+}
+
+#[test]
+fn test_co_author_over_withdrawal() {
+    co_author_withdrawal(45001)
+        .expect_err("The co-author cannot withdraw more than the weighted share");
+}
+
+// The platform withdraws its share of 300000 * 10% = 30000 and the 500 fees paid by holders
+fn platform_fee_withdrawal(withdrawal_udt: u128) -> Result<Cycle, Error> {
+    let mut context = new_context();
+    let tx = TransactionBuilder::default().build();
+    let account_book_type_id = [27u8; 32];
+    let out_xudt_lock_script = build_user2_script(&mut context);
+
+    let total_income = 300000u128;
+    let old_total_udt = 100000u128;
+    let new_total_udt = old_total_udt - withdrawal_udt;
+    let total_withdrawn = total_income - old_total_udt;
+    let platform_fee = 500u128;
+
+    let ab_cell_data = def_account_book_cell_data(&mut context)
+        .as_builder()
+        .level(2.into())
+        .withdrawal_fee_udt(5u128.pack())
+        .withdrawal_fee_ratio(10.into())
+        .build();
+    let tx = build_account_book(
+        &mut context,
+        tx,
+        account_book_type_id.into(),
+        AccountBookData::new_builder().build(),
+        (ab_cell_data.clone(), ab_cell_data.clone()),
+        (old_total_udt, new_total_udt),
+    );
+    let account_book_script =
+        build_account_book_script(&mut context, Some(account_book_type_id.into())).unwrap();
+    let tx = build_share_withdrawal(
+        &mut context,
+        tx,
+        &account_book_script,
+        ab_cell_data.platform_id(),
+        out_xudt_lock_script,
+        withdrawal_udt,
+    );
+
+    // SMT, the collected fees are unchanged
+    let mut smt = AccountBook::new_empty();
+    smt.update(SmtKey::TotalIncome, total_income);
+    smt.update(SmtKey::AccountBalance, old_total_udt);
+    smt.update(SmtKey::TotalWithdrawn, total_withdrawn);
+    smt.update(SmtKey::PlatformFee, platform_fee);
+    let old_hash = smt.root_hash();
+    let proof = smt.proof_keys(&[SmtKey::Platform, SmtKey::PlatformFee]);
+    smt.update(SmtKey::Platform, withdrawal_udt);
+    smt.update(SmtKey::AccountBalance, new_total_udt);
+    smt.update(SmtKey::TotalWithdrawn, total_withdrawn + withdrawal_udt);
+    let new_hash = smt.root_hash();
+
+    let account_book_data = AccountBookData::new_builder()
+        .proof(proof.pack())
+        .total_income_udt(total_income.pack())
+        .total_withdrawn_udt(total_withdrawn.pack())
+        .platform_fee_udt(
+            Uint128Opt::new_builder()
+                .set(Some(platform_fee.pack()))
+                .build(),
+        )
+        .build();
+    let tx = update_accountbook_smt(&mut context, tx, account_book_data, (old_hash, new_hash));
+
+    let tx = context.complete_tx(tx);
+    // print_tx_info(&context, &tx);
+    verify_and_dump_failed_tx(&context, &tx, MAX_CYCLES)
+}
+
+#[test]
+fn test_platform_fee_withdrawal() {
+    platform_fee_withdrawal(30500).expect("pass");
+}
+
+#[test]
+fn test_platform_over_withdrawal() {
+    platform_fee_withdrawal(30501)
+        .expect_err("The platform cannot withdraw more than its share and the fees");
+}
+
+// Sweep the unclaimed share of a Spore of level 1 to the platform
+// The platform sweeps before the claim deadline
+#[derive(Default)]
+struct Sweep {
+    before_deadline: bool,
+}
+
+fn sweep_spore(sweep: Sweep) -> Result<Cycle, Error> {
+    let Sweep { before_deadline } = sweep;
+    let mut context = new_context();
+    let tx = TransactionBuilder::default().build();
+    let account_book_type_id = [22u8; 32];
+    let platform_lock_script = build_user2_script(&mut context);
+    let cluster_id = [0x3E; 32];
+    let spore_id = [0xE3; 32];
+
+    // Each Spore of level 1 can withdraw 300000 * 40% / 15 = 8000
+    let total_income = 300000u128;
+    let old_total_udt = 100000u128;
+    let total_withdrawn = total_income - old_total_udt;
+    let spore_withdrawn = 10u128;
+    let swept_udt = 8000 - spore_withdrawn;
+    let claim_deadline = 2_000_000u64;
+
+    let ab_cell_data = def_account_book_cell_data(&mut context)
+        .as_builder()
+        .level(2.into())
+        .cluster_id(cluster_id.pack())
+        .platform_id(platform_lock_script.calc_script_hash())
+        .claim_deadline_since(timestamp_since(claim_deadline).pack())
+        .build();
+    let tx = build_account_book(
+        &mut context,
+        tx,
+        account_book_type_id.into(),
+        AccountBookData::new_builder().build(),
+        (ab_cell_data.clone(), ab_cell_data),
+        (old_total_udt, old_total_udt - swept_udt),
+    );
+    let tx = set_account_book_since(
+        tx,
+        timestamp_since(if !before_deadline {
+            claim_deadline
+        } else {
+            claim_deadline - 1
+        }),
+    );
+
+    let spore_dep = build_spore_dep(
+        &mut context,
+        spore_id,
+        &def_spore_with_dna(cluster_id, "4000000000001"),
+    );
+    let tx = tx
+        .as_advanced_builder()
+        .cell_dep(spore_dep)
+        .output(build_xudt_cell(&mut context, platform_lock_script))
+        .output_data(swept_udt.to_le_bytes().pack())
+        .build();
+
+    // SMT
+    let mut smt = AccountBook::new_empty();
+    smt.update(SmtKey::TotalIncome, total_income);
+    smt.update(SmtKey::AccountBalance, old_total_udt);
+    smt.update(SmtKey::TotalWithdrawn, total_withdrawn);
+    smt.update(SmtKey::Buyer(spore_id.into()), spore_withdrawn);
+    let old_hash = smt.root_hash();
+    let proof = smt.proof(SmtKey::Buyer(spore_id.into()));
+    smt.update(SmtKey::Buyer(spore_id.into()), spore_withdrawn + swept_udt);
+    smt.update(SmtKey::AccountBalance, old_total_udt - swept_udt);
+    smt.update(SmtKey::TotalWithdrawn, total_withdrawn + swept_udt);
+    let new_hash = smt.root_hash();
+
+    let account_book_data = AccountBookData::new_builder()
+        .proof(proof.pack())
+        .total_income_udt(total_income.pack())
+        .total_withdrawn_udt(total_withdrawn.pack())
+        .withdrawn_udt(
+            Uint128Opt::new_builder()
+                .set(Some(spore_withdrawn.pack()))
+                .build(),
+        )
+        .swept_spore(
+            WithdrawalSporeInfoOpt::new_builder()
+                .set(Some(def_spore_info(spore_id, 1, cluster_id)))
+                .build(),
+        )
+        .build();
+    let tx = update_accountbook_smt(&mut context, tx, account_book_data, (old_hash, new_hash));
+
+    let tx = context.complete_tx(tx);
+    // print_tx_info(&context, &tx);
+    verify_and_dump_failed_tx(&context, &tx, MAX_CYCLES)
 }
 
 #[test]
-fn test_simple_withdrawal_suc() {
+fn test_sweep_spore() {
+    sweep_spore(Default::default()).expect("pass");
+}
+
+#[test]
+fn test_sweep_spore_before_deadline() {
+    sweep_spore(Sweep {
+        before_deadline: true,
+    })
+    .expect_err("Shares cannot be swept before the claim deadline");
+}
+
+// Upgrade a Spore of level 2 to level 1, paying the difference 2000 - 1000
+// How the holder upgrades, the defaults upgrade a Spore that has withdrawn 5000
+#[derive(Default)]
+struct Upgrade {
+    // The Spore has no leaf, the AccountBook of level 2 proves it sold the Spore
+    never_withdrew: bool,
+    // The difference is not paid into the AccountBook
+    unpaid: bool,
+}
+
+fn upgrade_spore(upgrade: Upgrade) -> Result<Cycle, Error> {
+    let Upgrade {
+        never_withdrew,
+        unpaid,
+    } = upgrade;
+    let burned_withdrawn = if never_withdrew { None } else { Some(5000u128) };
     let mut context = new_context();
     let tx = TransactionBuilder::default().build();
-    let def_lock_script = build_always_suc_script(&mut context, &[0x11; 32]);
-    let out_xudt_lock_script: Script = build_always_suc_script(&mut context, &[1, 2, 3, 4]);
-    let xudt_script = build_xudt_script(&mut context);
+    let account_book_type_id = [23u8; 32];
+    let payout_lock_script = build_user2_script(&mut context);
 
-    let spore_id: Hash = [0x1B; 32].into();
-    // let spore_level: u8 = 1;
-    let cluster_id: Hash = [0x1A; 32].into();
+    let account_book_script =
+        build_account_book_script(&mut context, Some(account_book_type_id.into())).unwrap();
+    let lock_proxy_script =
+        build_proxy_lock_script(&mut context, account_book_script.calc_script_hash().into());
+    let (spore_data, cluster_deps) = def_spore(&mut context, lock_proxy_script.clone());
+    let cluster_id = get_cluster_id(&spore_data);
 
-    // Cal Withdrawal
-    let ratios = [20, 30, 30, 20];
-    let buyers = [7, 15];
-    let spore_level = 1;
+    // The burned Spore could withdraw 300000 * 20% / 3 = 20000,
+    // each Spore of level 1 can withdraw 301000 * 20% / 4 = 15050 after the payment
+    let price = if unpaid { 0 } else { 1000u128 };
     let total_income = 300000u128;
-    let old_total_udt = 10000u128;
-    let old_total_withdrawal = Some(10u128);
+    let new_total_income = total_income + 1000;
+    let old_total_udt = 100000u128;
+    let old_total_withdrawn = total_income - old_total_udt;
+    let burned_id = [0xB3u8; 32];
+    let settled_udt = 20000 - burned_withdrawn.unwrap_or(0);
+    let credited_udt = 15050u128;
+    let new_total_udt = old_total_udt + price - settled_udt;
 
-    let new_total_withdrawal: u128 =
-        total_income * ratios[spore_level + 2] as u128 / 100 / buyers[spore_level] as u128;
-    let withdrawal_udt = new_total_withdrawal - old_total_withdrawal.unwrap_or(0);
-    let new_total_udt = old_total_udt - withdrawal_udt;
+    let ab_cell_data = def_account_book_cell_data(&mut context)
+        .as_builder()
+        .level(3.into())
+        .cluster_id(cluster_id.pack())
+        .profit_distribution_ratio([10, 20, 30, 20, 20].pack())
+        .profit_distribution_number([2, 4, 3].pack())
+        .level_prices(pack_udt_vec(&[3000, 2000, 1000]))
+        .build();
+    let ab_cell_data_new = ab_cell_data
+        .clone()
+        .as_builder()
+        .profit_distribution_number([2, 5, 2].pack())
+        .retired_withdrawn_udt(pack_udt_vec(&[0, 0, 20000]))
+        .minted_withdrawn_udt(pack_udt_vec(&[0, credited_udt, 0]))
+        .build();
+    let tx = build_account_book(
+        &mut context,
+        tx,
+        account_book_type_id.into(),
+        AccountBookData::new_builder().build(),
+        (ab_cell_data, ab_cell_data_new),
+        (old_total_udt, new_total_udt),
+    );
 
-    let mut smt = AccountBook::new_test();
-    if old_total_withdrawal.is_some() {
-        smt.update(
-            SmtKey::Buyer(spore_id.clone()),
-            *(old_total_withdrawal.as_ref().unwrap()),
-        );
-    }
+    // The holder pays the difference, and receives the pending earnings of the burned Spore
+    let holder_lock_script = build_user1_script(&mut context);
+    let udt_cell = build_xudt_cell(&mut context, holder_lock_script.clone());
+    let tx = tx
+        .as_advanced_builder()
+        .input(build_input(
+            context.create_cell(udt_cell, 1000u128.to_le_bytes().to_vec().into()),
+        ))
+        .witness(Default::default())
+        .output(build_xudt_cell(&mut context, holder_lock_script.clone()))
+        .output_data((1000 - price).to_le_bytes().pack())
+        .output(build_xudt_cell(&mut context, payout_lock_script.clone()))
+        .output_data(settled_udt.to_le_bytes().pack())
+        .build();
+    let tx = build_reforge_spores(
+        &mut context,
+        tx,
+        cluster_deps,
+        &[(burned_id, def_spore_with_dna(cluster_id, "4000000000002"))],
+        (
+            def_spore_with_dna(cluster_id, "4000000000001"),
+            holder_lock_script,
+        ),
+    );
+    let minted_id = get_spore_id(&tx);
+    let minted_dna_hash =
+        utils::get_spore_dna_hash(&def_spore_with_dna(cluster_id, "4000000000001")).unwrap();
+
+    // SMT, the AccountBook books the full difference
+    let mut smt = AccountBook::new_empty();
     smt.update(SmtKey::TotalIncome, total_income);
     smt.update(SmtKey::AccountBalance, old_total_udt);
+    smt.update(SmtKey::TotalWithdrawn, old_total_withdrawn);
+    if let Some(withdrawn) = burned_withdrawn {
+        smt.update(SmtKey::Buyer(burned_id.into()), withdrawn);
+    }
     let old_hash = smt.root_hash();
-    let proof = smt.proof(SmtKey::Buyer(spore_id.clone()));
-
+    let keys = [
+        SmtKey::Buyer(burned_id.into()),
+        SmtKey::Buyer(minted_id.into()),
+        SmtKey::Minted(minted_id.into()),
+        SmtKey::Burned(burned_id.into()),
+        SmtKey::Dna(minted_dna_hash.clone()),
+    ];
+    let proof = smt.proof_keys(&keys);
+    smt.update(SmtKey::Buyer(burned_id.into()), utils::SMT_VALUE_RETIRED);
+    smt.update(SmtKey::Burned(burned_id.into()), 2);
+    smt.update(
+        SmtKey::Buyer(minted_id.into()),
+        (new_total_income * 20 / 100 + credited_udt) / 5,
+    );
+    smt.update(SmtKey::Minted(minted_id.into()), 1);
+    smt.update(SmtKey::Dna(minted_dna_hash), 0);
+    smt.update(SmtKey::TotalIncome, new_total_income);
     smt.update(SmtKey::AccountBalance, new_total_udt);
-    smt.update(SmtKey::Buyer(spore_id.clone()), new_total_withdrawal);
+    smt.update(SmtKey::TotalWithdrawn, old_total_withdrawn + settled_udt);
     let new_hash = smt.root_hash();
 
-    // Account Book
-    let account_book_cell_data = def_account_book_cell_data(&mut context)
-        .as_builder()
-        .level(2.into())
-        .cluster_id(cluster_id.clone().into())
-        .profit_distribution_ratio(ratios.pack())
-        .profit_distribution_number(buyers.pack())
-        .smt_root_hash(old_hash.into())
+    let upgrade_info = MergeSporeInfo::new_builder()
+        .burned_spores(
+            WithdrawalSporeInfoVec::new_builder()
+                .push(def_spore_info(burned_id, 2, cluster_id))
+                .build(),
+        )
+        .burned_withdrawn_udt(pack_udt_opt_vec(&[burned_withdrawn]))
+        .minted_spore(def_spore_info(minted_id, 1, cluster_id))
+        .xudt_lock_script_hash(payout_lock_script.calc_script_hash())
         .build();
     let account_book_data = AccountBookData::new_builder()
-        .total_income_udt(total_income.pack())
         .proof(proof.pack())
-        .withdrawn_udt({
-            Uint128Opt::new_builder()
-                .set(old_total_withdrawal.map(|v| v.pack()))
-                .build()
+        .total_income_udt(total_income.pack())
+        .total_withdrawn_udt(old_total_withdrawn.pack())
+        .upgraded_spore(
+            MergeSporeInfoOpt::new_builder()
+                .set(Some(upgrade_info))
+                .build(),
+        )
+        .build();
+
+    // Without a leaf here, the AccountBook of level 2 sold the burned Spore
+    let unsold_here: Vec<[u8; 32]> = burned_withdrawn.map_or(vec![burned_id], |_| Vec::new());
+    let (sibling_proofs, sibling_deps) =
+        build_origin_proofs(&mut context, cluster_id, 2, &unsold_here);
+    let account_book_data = account_book_data
+        .as_builder()
+        .sibling_proofs(sibling_proofs)
+        .build();
+    let tx = tx.as_advanced_builder().cell_deps(sibling_deps).build();
+    let tx = update_accountbook_smt(&mut context, tx, account_book_data, (old_hash, new_hash));
+
+    let tx = context.complete_tx(tx);
+    // print_tx_info(&context, &tx);
+    verify_and_dump_failed_tx(&context, &tx, MAX_CYCLES)
+}
+
+#[test]
+fn test_upgrade_spore() {
+    upgrade_spore(Default::default()).expect("pass");
+}
+
+#[test]
+fn test_upgrade_spore_never_withdrawn() {
+    upgrade_spore(Upgrade {
+        never_withdrew: true,
+        ..Default::default()
+    })
+    .expect("pass");
+}
+
+#[test]
+fn test_upgrade_spore_without_payment() {
+    upgrade_spore(Upgrade {
+        unpaid: true,
+        ..Default::default()
+    })
+    .expect_err("The difference must be paid into the AccountBook");
+}
+
+// Merge two Spores of level 2 into one of level 1,
+// a burned Spore without a leaf here was sold by the AccountBook of level 2
+// How the holder merges, the defaults merge Spores that have withdrawn 5000 and 20000
+#[derive(Default)]
+struct Merge {
+    // The Spores have no leaf, the AccountBook of level 2 proves it sold them
+    never_withdrew: bool,
+    // The minted Spore is not credited with the income before it
+    minted_uncredited: bool,
+}
+
+fn merge_spores(merge: Merge) -> Result<Cycle, Error> {
+    let Merge {
+        never_withdrew,
+        minted_uncredited,
+    } = merge;
+    let burned_withdrawn = if never_withdrew {
+        [None, None]
+    } else {
+        [Some(5000u128), Some(20000)]
+    };
+    let credit_minted = !minted_uncredited;
+    let mut context = new_context();
+    let tx = TransactionBuilder::default().build();
+    let account_book_type_id = [18u8; 32];
+    let payout_lock_script = build_user2_script(&mut context);
+
+    let account_book_script =
+        build_account_book_script(&mut context, Some(account_book_type_id.into())).unwrap();
+    let lock_proxy_script =
+        build_proxy_lock_script(&mut context, account_book_script.calc_script_hash().into());
+    let (spore_data, cluster_deps) = def_spore(&mut context, lock_proxy_script.clone());
+    let cluster_id = get_cluster_id(&spore_data);
+
+    // Each Spore of level 2 can withdraw 300000 * 20% / 3 = 20000,
+    // each Spore of level 1 can withdraw 300000 * 20% / 4 = 15000
+    let ratios = [10, 20, 30, 20, 20];
+    let total_income = 300000u128;
+    let old_total_udt = 100000u128;
+    let old_total_withdrawn = total_income - old_total_udt;
+    let burned_ids = [[0xB1u8; 32], [0xB2u8; 32]];
+    let settled_udt: u128 = burned_withdrawn
+        .iter()
+        .map(|withdrawn| 20000 - withdrawn.unwrap_or(0))
+        .sum();
+    let credited_udt = 15000u128;
+
+    let ab_cell_data = def_account_book_cell_data(&mut context)
+        .as_builder()
+        .level(3.into())
+        .cluster_id(cluster_id.pack())
+        .profit_distribution_ratio(ratios.pack())
+        .profit_distribution_number([2, 4, 3].pack())
+        .merge_spore_count(2.into())
+        .build();
+    let ab_cell_data_new = ab_cell_data
+        .clone()
+        .as_builder()
+        .profit_distribution_number([2, 5, 1].pack())
+        .retired_withdrawn_udt(pack_udt_vec(&[0, 0, 40000]))
+        .minted_withdrawn_udt(if credit_minted {
+            pack_udt_vec(&[0, credited_udt, 0])
+        } else {
+            Default::default()
         })
         .build();
 
-    let account_book_script = build_account_book_script(&mut context, None);
-    let input_account_book_tx_hash = ckb_testtool::context::random_hash();
+    let tx = build_account_book(
+        &mut context,
+        tx,
+        account_book_type_id.into(),
+        AccountBookData::new_builder().build(),
+        (ab_cell_data, ab_cell_data_new),
+        (old_total_udt, old_total_udt - settled_udt),
+    );
 
-    let tx = {
-        let proxy_lock_script = build_proxy_lock_script(
-            &mut context,
-            account_book_script
-                .as_ref()
-                .unwrap()
-                .calc_script_hash()
-                .into(),
-        );
+    // Pending earnings of the burned Spores
+    let tx = tx
+        .as_advanced_builder()
+        .output(build_xudt_cell(&mut context, payout_lock_script.clone()))
+        .output_data(settled_udt.to_le_bytes().pack())
+        .build();
 
-        let input_cell = {
-            let cell_input_outpoint1 = OutPoint::new_builder()
-                .tx_hash(input_account_book_tx_hash.clone())
-                .index(1u32.pack())
-                .build();
-            let cell = CellOutput::new_builder()
-                .capacity(16.pack())
-                .lock(proxy_lock_script.clone())
-                .type_(xudt_script.clone().pack())
-                .build();
-            context.create_cell_with_out_point(
-                cell_input_outpoint1.clone(),
-                cell,
-                old_total_udt.to_le_bytes().to_vec().into(),
-            );
-            cell_input_outpoint1
-        };
-        let output_cell = {
-            CellOutput::new_builder()
-                .capacity(16.pack())
-                .lock(proxy_lock_script.clone())
-                .type_(xudt_script.pack())
-                .build()
-        };
-        tx.as_advanced_builder()
-            .input(build_input(input_cell))
-            .output(output_cell)
-            .output_data(new_total_udt.to_le_bytes().pack())
-            .witness(Default::default())
-            .build()
+    let burned: Vec<([u8; 32], SporeData)> = burned_ids
+        .iter()
+        .map(|id| (*id, def_spore_with_dna(cluster_id, "4000000000002")))
+        .collect();
+    let minted_lock_script = build_user1_script(&mut context);
+    let tx = build_reforge_spores(
+        &mut context,
+        tx,
+        cluster_deps,
+        &burned,
+        (
+            def_spore_with_dna(cluster_id, "4000000000001"),
+            minted_lock_script,
+        ),
+    );
+    let minted_id = get_spore_id(&tx);
+    let minted_dna_hash =
+        utils::get_spore_dna_hash(&def_spore_with_dna(cluster_id, "4000000000001")).unwrap();
+
+    // SMT
+    let mut smt = AccountBook::new_empty();
+    smt.update(SmtKey::TotalIncome, total_income);
+    smt.update(SmtKey::AccountBalance, old_total_udt);
+    smt.update(SmtKey::TotalWithdrawn, old_total_withdrawn);
+    for (id, withdrawn) in burned_ids.iter().zip(burned_withdrawn) {
+        if let Some(withdrawn) = withdrawn {
+            smt.update(SmtKey::Buyer((*id).into()), withdrawn);
+        }
+    }
+    let old_hash = smt.root_hash();
+    let mut keys: Vec<SmtKey> = burned_ids
+        .iter()
+        .chain([&minted_id])
+        .map(|id| SmtKey::Buyer((*id).into()))
+        .collect();
+    keys.push(SmtKey::Minted(minted_id.into()));
+    keys.extend(burned_ids.iter().map(|id| SmtKey::Burned((*id).into())));
+    keys.push(SmtKey::Dna(minted_dna_hash.clone()));
+    let proof = smt.proof_keys(&keys);
+
+    // Without the credit, the minted Spore would be given its share of the old income
+    let minted_withdrawn = if credit_minted {
+        (total_income * 20 / 100 + credited_udt) / 5
+    } else {
+        total_income * 20 / 100 / 5
     };
-    let tx = {
-        let input_cell = {
-            let cell_input_outpoint2 = OutPoint::new_builder()
-                .tx_hash(input_account_book_tx_hash)
-                .index(2u32.pack())
-                .build();
+    for id in &burned_ids {
+        smt.update(SmtKey::Buyer((*id).into()), utils::SMT_VALUE_RETIRED);
+        smt.update(SmtKey::Burned((*id).into()), 2);
+    }
+    smt.update(SmtKey::Buyer(minted_id.into()), minted_withdrawn);
+    smt.update(SmtKey::Minted(minted_id.into()), 1);
+    smt.update(SmtKey::Dna(minted_dna_hash), 0);
+    smt.update(SmtKey::AccountBalance, old_total_udt - settled_udt);
+    smt.update(SmtKey::TotalWithdrawn, old_total_withdrawn + settled_udt);
+    let new_hash = smt.root_hash();
+
+    let merge_info = MergeSporeInfo::new_builder()
+        .burned_spores(
+            WithdrawalSporeInfoVec::new_builder()
+                .extend(
+                    burned_ids
+                        .iter()
+                        .map(|id| def_spore_info(*id, 2, cluster_id)),
+                )
+                .build(),
+        )
+        .burned_withdrawn_udt(pack_udt_opt_vec(&burned_withdrawn))
+        .minted_spore(def_spore_info(minted_id, 1, cluster_id))
+        .xudt_lock_script_hash(payout_lock_script.calc_script_hash())
+        .build();
+    let account_book_data = AccountBookData::new_builder()
+        .proof(proof.pack())
+        .total_income_udt(total_income.pack())
+        .total_withdrawn_udt(old_total_withdrawn.pack())
+        .merged_spores(
+            MergeSporeInfoOpt::new_builder()
+                .set(Some(merge_info))
+                .build(),
+        )
+        .build();
+
+    let unsold_here: Vec<[u8; 32]> = burned_ids
+        .iter()
+        .zip(burned_withdrawn)
+        .filter(|(_, withdrawn)| withdrawn.is_none())
+        .map(|(id, _)| *id)
+        .collect();
+    let (sibling_proofs, sibling_deps) =
+        build_origin_proofs(&mut context, cluster_id, 2, &unsold_here);
+    let account_book_data = account_book_data
+        .as_builder()
+        .sibling_proofs(sibling_proofs)
+        .build();
+    let tx = tx.as_advanced_builder().cell_deps(sibling_deps).build();
+    let tx = update_accountbook_smt(&mut context, tx, account_book_data, (old_hash, new_hash));
+
+    let tx = context.complete_tx(tx);
+    // print_tx_info(&context, &tx);
+    verify_and_dump_failed_tx(&context, &tx, MAX_CYCLES)
+}
+
+#[test]
+fn test_merge_spores() {
+    merge_spores(Default::default()).expect("pass");
+}
+
+#[test]
+fn test_merge_spores_never_withdrawn() {
+    merge_spores(Merge {
+        never_withdrew: true,
+        ..Default::default()
+    })
+    .expect("pass");
+}
+
+#[test]
+fn test_merge_spores_without_credit() {
+    merge_spores(Merge {
+        minted_uncredited: true,
+        ..Default::default()
+    })
+    .expect_err("The minted Spore must be credited with the old income");
+}
+
+// A Spore of level 1 minted by another AccountBook joins this one
+// The AccountBook of level 1 has no record of minting the Spore
+#[derive(Default)]
+struct Registration {
+    unminted: bool,
+}
+
+fn register_spore(registration: Registration) -> Result<Cycle, Error> {
+    let Registration { unminted } = registration;
+    let mut context = new_context();
+    let tx = TransactionBuilder::default().build();
+    let account_book_type_id = [19u8; 32];
+    let cluster_id = [0x3C; 32];
+    let spore_id = [0xC1; 32];
+
+    // Each Spore of level 1 can withdraw 300000 * 20% / 4 = 15000 so far
+    let total_income = 300000u128;
+    let total_udt = 100000u128;
+    let total_withdrawn = total_income - total_udt;
+    let credited_udt = 15000u128;
+
+    let ab_cell_data = def_account_book_cell_data(&mut context)
+        .as_builder()
+        .level(3.into())
+        .cluster_id(cluster_id.pack())
+        .profit_distribution_ratio([10, 20, 30, 20, 20].pack())
+        .profit_distribution_number([2, 4, 3].pack())
+        .build();
+    let ab_cell_data_new = ab_cell_data
+        .clone()
+        .as_builder()
+        .profit_distribution_number([2, 5, 3].pack())
+        .retired_withdrawn_udt(pack_udt_vec(&[0, 0, 0]))
+        .minted_withdrawn_udt(pack_udt_vec(&[0, credited_udt, 0]))
+        .build();
+    let tx = build_account_book(
+        &mut context,
+        tx,
+        account_book_type_id.into(),
+        AccountBookData::new_builder().build(),
+        (ab_cell_data, ab_cell_data_new),
+        (total_udt, total_udt),
+    );
+
+    // The Spore is minted by the AccountBook of level 4
+    let sibling_cell_data = def_account_book_cell_data(&mut context)
+        .as_builder()
+        .level(4.into())
+        .cluster_id(cluster_id.pack())
+        .build();
+    let (sibling_proof, sibling_dep) = build_sibling_account_book(
+        &mut context,
+        [0x1D; 32],
+        sibling_cell_data,
+        &[(
+            SmtKey::Minted(spore_id.into()),
+            if unminted { None } else { Some(1) },
+        )],
+    );
+    let spore_dep = build_spore_dep(
+        &mut context,
+        spore_id,
+        &def_spore_with_dna(cluster_id, "4000000000001"),
+    );
+    let tx = tx
+        .as_advanced_builder()
+        .cell_dep(sibling_dep)
+        .cell_dep(spore_dep)
+        .build();
 
-            let cell = CellOutput::new_builder()
-                .capacity(1000.pack())
-                .lock(def_lock_script.clone())
-                .type_(account_book_script.clone().pack())
-                .build();
-            context.create_cell_with_out_point(
-                cell_input_outpoint2.clone(),
-                cell,
-                account_book_cell_data.as_bytes().into(),
-            );
-            cell_input_outpoint2
-        };
-        let output_cell = {
-            CellOutput::new_builder()
-                .capacity(1000.pack())
-                .lock(def_lock_script.clone())
-                .type_(account_book_script.pack())
-                .build()
-        };
+    // SMT
+    let mut smt = AccountBook::new_empty();
+    smt.update(SmtKey::TotalIncome, total_income);
+    smt.update(SmtKey::AccountBalance, total_udt);
+    smt.update(SmtKey::TotalWithdrawn, total_withdrawn);
+    let old_hash = smt.root_hash();
+    let proof = smt.proof(SmtKey::Buyer(spore_id.into()));
+    smt.update(
+        SmtKey::Buyer(spore_id.into()),
+        (total_income * 20 / 100 + credited_udt) / 5,
+    );
+    let new_hash = smt.root_hash();
 
-        // Update Cell Data
-        let account_book_cell_data = account_book_cell_data
-            .as_builder()
-            .smt_root_hash(new_hash.into())
-            .build();
+    let account_book_data = AccountBookData::new_builder()
+        .proof(proof.pack())
+        .total_income_udt(total_income.pack())
+        .total_withdrawn_udt(total_withdrawn.pack())
+        .registered_spore(
+            WithdrawalSporeInfoOpt::new_builder()
+                .set(Some(def_spore_info(spore_id, 1, cluster_id)))
+                .build(),
+        )
+        .sibling_proofs(SiblingProofVec::new_builder().push(sibling_proof).build())
+        .build();
+    let tx = update_accountbook_smt(&mut context, tx, account_book_data, (old_hash, new_hash));
 
-        tx.as_advanced_builder()
-            .input(build_input(input_cell))
-            .output(output_cell)
-            .output_data(account_book_cell_data.as_slice().pack())
-            .witness(
-                WitnessArgs::new_builder()
-                    .output_type(Some(account_book_data.as_bytes()).pack())
-                    .build()
-                    .as_bytes()
-                    .pack(),
-            )
-            .build()
-    };
+    let tx = context.complete_tx(tx);
+    // print_tx_info(&context, &tx);
+    verify_and_dump_failed_tx(&context, &tx, MAX_CYCLES)
+}
 
-    // Withdrawal Intent
-    let tx = {
-        let withdrawal_spore_info = WithdrawalSporeInfo::new_builder()
-            .spore_code_hash((*SporeCodeHash).pack())
-            .spore_id(spore_id.into())
-            .spore_level((spore_level as u8).into())
-            .cluster_id(cluster_id.into())
-            .build();
+#[test]
+fn test_register_spore() {
+    register_spore(Default::default()).expect("pass");
+}
 
-        let withdrawal_intent_data = def_withdrawal_intent_data(&mut context)
+#[test]
+fn test_register_unminted_spore() {
+    register_spore(Registration { unminted: true })
+        .expect_err("Only a Spore minted by another AccountBook can register");
+}
+
+// How a Spore of level 2 is retired, the defaults retire a Spore burned in this transaction
+// that has withdrawn 5000
+#[derive(Default)]
+struct Retirement {
+    // Burned before, the AccountBook of level 4 recorded the burn
+    burned_elsewhere: bool,
+    // ... or it has no record of it
+    burn_unproven: bool,
+    // The Spore has no leaf, the AccountBook of level 2 proves it sold the Spore
+    never_withdrew: bool,
+}
+
+fn retire_spore(retirement: Retirement) -> Result<Cycle, Error> {
+    let Retirement {
+        burned_elsewhere,
+        burn_unproven,
+        never_withdrew,
+    } = retirement;
+    let burned_here = !burned_elsewhere;
+    let withdrawn = if never_withdrew { None } else { Some(5000u128) };
+    let mut context = new_context();
+    let tx = TransactionBuilder::default().build();
+    let account_book_type_id = [20u8; 32];
+    let cluster_id = [0x3D; 32];
+    let spore_id = [0xD2; 32];
+    let spore_level = 2u8;
+
+    let total_income = 300000u128;
+    let total_udt = 100000u128;
+    let total_withdrawn = total_income - total_udt;
+
+    let ab_cell_data = def_account_book_cell_data(&mut context)
+        .as_builder()
+        .level(3.into())
+        .cluster_id(cluster_id.pack())
+        .profit_distribution_ratio([10, 20, 30, 20, 20].pack())
+        .profit_distribution_number([2, 4, 3].pack())
+        .build();
+    let ab_cell_data_new = ab_cell_data
+        .clone()
+        .as_builder()
+        .profit_distribution_number([2, 4, 2].pack())
+        .retired_withdrawn_udt(pack_udt_vec(&[0, 0, withdrawn.unwrap_or(0)]))
+        .build();
+    let tx = build_account_book(
+        &mut context,
+        tx,
+        account_book_type_id.into(),
+        AccountBookData::new_builder().build(),
+        (ab_cell_data, ab_cell_data_new),
+        (total_udt, total_udt),
+    );
+
+    let mut sibling_proofs = Vec::new();
+    let tx = if burned_here {
+        build_burn_spores(
+            &mut context,
+            tx,
+            &[(spore_id, def_spore_with_dna(cluster_id, "4000000000002"))],
+        )
+    } else {
+        let sibling_cell_data = def_account_book_cell_data(&mut context)
             .as_builder()
-            .owner_script_hash(out_xudt_lock_script.calc_script_hash())
-            .xudt_lock_script_hash(out_xudt_lock_script.calc_script_hash())
-            .buyer(
-                WithdrawalBuyer::new_builder()
-                    .set(withdrawal_spore_info)
-                    .build(),
-            )
+            .level(4.into())
+            .cluster_id(cluster_id.pack())
             .build();
-
-        let withdrawal_intent_script = build_withdrawal_intent_script(
+        let (sibling_proof, sibling_dep) = build_sibling_account_book(
             &mut context,
-            &withdrawal_intent_data,
-            account_book_script
-                .as_ref()
-                .unwrap()
-                .calc_script_hash()
-                .into(),
+            [0x1E; 32],
+            sibling_cell_data,
+            &[(
+                SmtKey::Burned(spore_id.into()),
+                if burn_unproven {
+                    None
+                } else {
+                    Some(spore_level as u128)
+                },
+            )],
+        );
+        sibling_proofs.push(sibling_proof);
+        tx.as_advanced_builder().cell_dep(sibling_dep).build()
+    };
+    let tx = if withdrawn.is_none() {
+        let sibling_cell_data = def_account_book_cell_data(&mut context)
+            .as_builder()
+            .level(spore_level.into())
+            .cluster_id(cluster_id.pack())
+            .build();
+        let (sibling_proof, sibling_dep) = build_sibling_account_book(
+            &mut context,
+            [0x1F; 32],
+            sibling_cell_data,
+            &[(SmtKey::Buyer(spore_id.into()), Some(0))],
         );
+        sibling_proofs.push(sibling_proof);
+        tx.as_advanced_builder().cell_dep(sibling_dep).build()
+    } else {
+        tx
+    };
 
-        let input_cell = {
-            context.create_cell(
-                CellOutput::new_builder()
-                    .capacity(16.pack())
-                    .lock(def_lock_script.clone())
-                    .type_(withdrawal_intent_script.pack())
-                    .build(),
-                Default::default(),
-            )
-        };
-        let output_cell = {
-            CellOutput::new_builder()
-                .capacity(16.pack())
-                .lock(out_xudt_lock_script.clone())
-                .type_(xudt_script.pack())
-                .build()
-        };
+    // SMT
+    let mut smt = AccountBook::new_empty();
+    smt.update(SmtKey::TotalIncome, total_income);
+    smt.update(SmtKey::AccountBalance, total_udt);
+    smt.update(SmtKey::TotalWithdrawn, total_withdrawn);
+    if let Some(withdrawn) = withdrawn {
+        smt.update(SmtKey::Buyer(spore_id.into()), withdrawn);
+    }
+    let old_hash = smt.root_hash();
+    let mut keys = vec![SmtKey::Buyer(spore_id.into())];
+    if burned_here {
+        keys.push(SmtKey::Burned(spore_id.into()));
+    }
+    let proof = smt.proof_keys(&keys);
+    smt.update(SmtKey::Buyer(spore_id.into()), utils::SMT_VALUE_RETIRED);
+    if burned_here {
+        smt.update(SmtKey::Burned(spore_id.into()), spore_level as u128);
+    }
+    let new_hash = smt.root_hash();
 
-        tx.as_advanced_builder()
-            .input(build_input(input_cell))
-            .output(output_cell)
-            .output_data(withdrawal_udt.to_le_bytes().pack())
-            .witness(
-                WitnessArgs::new_builder()
-                    .input_type(Some(withdrawal_intent_data.as_bytes()).pack())
-                    .build()
-                    .as_bytes()
-                    .pack(),
-            )
-            .build()
-    };
+    let account_book_data = AccountBookData::new_builder()
+        .proof(proof.pack())
+        .total_income_udt(total_income.pack())
+        .total_withdrawn_udt(total_withdrawn.pack())
+        .withdrawn_udt(
+            Uint128Opt::new_builder()
+                .set(withdrawn.map(|v| v.pack()))
+                .build(),
+        )
+        .retired_spore(
+            WithdrawalSporeInfoOpt::new_builder()
+                .set(Some(def_spore_info(spore_id, spore_level, cluster_id)))
+                .build(),
+        )
+        .sibling_proofs(
+            SiblingProofVec::new_builder()
+                .extend(sibling_proofs)
+                .build(),
+        )
+        .build();
+    let tx = update_accountbook_smt(&mut context, tx, account_book_data, (old_hash, new_hash));
 
     let tx = context.complete_tx(tx);
     // print_tx_info(&context, &tx);
-    verify_and_dump_failed_tx(&context, &tx, MAX_CYCLES).expect("pass");
+    verify_and_dump_failed_tx(&context, &tx, MAX_CYCLES)
+}
+
+#[test]
+fn test_retire_spore() {
+    retire_spore(Default::default()).expect("pass");
+}
+
+#[test]
+fn test_retire_spore_burned_elsewhere() {
+    retire_spore(Retirement {
+        burned_elsewhere: true,
+        ..Default::default()
+    })
+    .expect("pass");
+}
+
+#[test]
+fn test_retire_unwithdrawn_spore_burned_elsewhere() {
+    retire_spore(Retirement {
+        burned_elsewhere: true,
+        never_withdrew: true,
+        ..Default::default()
+    })
+    .expect("pass");
+}
+
+#[test]
+fn test_retire_live_spore() {
+    retire_spore(Retirement {
+        burned_elsewhere: true,
+        burn_unproven: true,
+        ..Default::default()
+    })
+    .expect_err("A retired Spore must have been burned");
 }
 
 #[test]
@@ -711,6 +3889,90 @@ fn test_revocation_withdrawal() {
     verify_and_dump_failed_tx(&context, &tx, MAX_CYCLES).expect("pass");
 }
 
+// Only the auther creates AccountBooks of the cluster, by locking an input
+fn account_book_creation(auther_id: Byte32) -> Result<Cycle, Error> {
+    let mut context = new_context();
+    let def_lock_script1 = build_always_suc_script(&mut context, &[]);
+
+    let ckb_cell = CellOutput::new_builder()
+        .capacity(1000u64.pack())
+        .lock(def_lock_script1.clone())
+        .build();
+
+    let smt = AccountBook::new_empty();
+
+    let account_book_data = AccountBookData::new_builder()
+        .proof(smt.proof(SmtKey::Auther).pack())
+        .build();
+    let account_book_cell_data = def_account_book_cell_data(&mut context)
+        .as_builder()
+        .level(5u8.into())
+        .buyer_count(0u32.pack())
+        .profit_distribution_ratio([20, 20, 20, 10, 10, 10, 10].pack())
+        .profit_distribution_number([10, 20, 30, 40, 50].pack())
+        .smt_root_hash(smt.root_hash().into())
+        .auther_id(auther_id)
+        .build();
+    let tx = TransactionBuilder::default()
+        .input(
+            CellInput::new_builder()
+                .previous_output(context.create_cell(ckb_cell, Default::default()))
+                .build(),
+        )
+        .build();
+    let inputcell = tx.inputs().get(0).unwrap();
+
+    let mut hasher = ckb_testtool::ckb_hash::new_blake2b();
+    hasher.update(inputcell.as_slice());
+    hasher.update(&1u64.to_le_bytes());
+    let mut account_book_type_id = [0u8; 32];
+    hasher.finalize(&mut account_book_type_id);
+
+    let account_book_script =
+        build_account_book_script(&mut context, Some(account_book_type_id.into())).unwrap();
+
+    let accout_book_cell = CellOutput::new_builder()
+        .capacity(16u64.pack())
+        .lock(def_lock_script1.clone())
+        .type_(Some(account_book_script.clone()).pack())
+        .build();
+
+    let xudt_cell = {
+        let lock_script =
+            build_proxy_lock_script(&mut context, account_book_script.calc_script_hash().into());
+        build_xudt_cell(&mut context, lock_script)
+    };
+
+    let ckb_cell_change = CellOutput::new_builder()
+        .capacity(20u64.pack())
+        .lock(def_lock_script1.clone())
+        .build();
+
+    let tx = tx
+        .as_advanced_builder()
+        .output(xudt_cell)
+        .output_data(0u128.to_le_bytes().to_vec().pack())
+        .witness(Default::default())
+        .output(accout_book_cell)
+        .output_data(account_book_cell_data.as_bytes().pack())
+        .witness(
+            WitnessArgs::new_builder()
+                .output_type(Some(account_book_data.as_bytes()).pack())
+                .build()
+                .as_bytes()
+                .pack(),
+        )
+        .output(ckb_cell_change)
+        .output_data(Default::default())
+        .witness(Default::default())
+        .build();
+    // Create Account book
+
+    let tx = context.complete_tx(tx);
+    // print_tx_info(&context, &tx);
+    verify_and_dump_failed_tx(&context, &tx, MAX_CYCLES)
+}
+
 #[test]
 fn create_account_book() {
     let mut context = new_context();
@@ -733,6 +3995,7 @@ fn create_account_book() {
         .profit_distribution_ratio([20, 20, 20, 10, 10, 10, 10].pack())
         .profit_distribution_number([10, 20, 30, 40, 50].pack())
         .smt_root_hash(smt.root_hash().into())
+        .auther_id(def_lock_script1.calc_script_hash())
         .build();
     let tx = TransactionBuilder::default()
         .input(
@@ -793,3 +4056,9 @@ fn create_account_book() {
     // print_tx_info(&context, &tx);
     verify_and_dump_failed_tx(&context, &tx, MAX_CYCLES).expect("pass");
 }
+
+#[test]
+fn test_create_account_book_without_auther() {
+    // No input is locked by the auther
+    account_book_creation([1u8; 32].pack()).expect_err("Only the auther creates an AccountBook");
+}